@@ -0,0 +1,2 @@
+//! Integration tests for `ordered-float` features whose test dependencies need a newer Rust than
+//! the crate's MSRV. The tests are in `tests/`.
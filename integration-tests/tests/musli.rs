@@ -0,0 +1,26 @@
+use ordered_float::{NotNan, OrderedFloat};
+
+#[test]
+fn test_ordered_float() {
+    let float = OrderedFloat(1.0f64);
+    let buffer = musli_storage::to_vec(&float).expect("failed to encode value");
+    let deser_float: OrderedFloat<f64> =
+        musli_storage::from_slice(&buffer).expect("failed to decode value");
+    assert_eq!(deser_float, float);
+}
+
+#[test]
+fn test_not_nan() {
+    let float = NotNan::new(1.0f64).unwrap();
+    let buffer = musli_storage::to_vec(&float).expect("failed to encode value");
+    let deser_float: NotNan<f64> =
+        musli_storage::from_slice(&buffer).expect("failed to decode value");
+    assert_eq!(deser_float, float);
+}
+
+#[test]
+fn test_not_nan_with_nan() {
+    let nan_buf = musli_storage::to_vec(&f64::NAN).expect("failed to encode value");
+    let nan_err: Result<NotNan<f64>, _> = musli_storage::from_slice(&nan_buf);
+    assert!(nan_err.is_err());
+}
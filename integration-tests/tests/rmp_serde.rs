@@ -0,0 +1,82 @@
+use ordered_float::{NotNan, OrderedFloat};
+use serde::{Deserialize, Serialize};
+
+fn to_vec<T: Serialize>(value: &T, human_readable: bool) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut serializer = rmp_serde::Serializer::new(&mut bytes);
+    if human_readable {
+        value.serialize(&mut serializer.with_human_readable())
+    } else {
+        value.serialize(&mut serializer)
+    }
+    .unwrap();
+    bytes
+}
+
+fn from_slice<'de, T: Deserialize<'de>>(
+    bytes: &'de [u8],
+    human_readable: bool,
+) -> Result<T, rmp_serde::decode::Error> {
+    let mut deserializer = rmp_serde::Deserializer::from_read_ref(bytes);
+    if human_readable {
+        T::deserialize(&mut deserializer.with_human_readable())
+    } else {
+        T::deserialize(&mut deserializer)
+    }
+}
+
+// MessagePack is a binary format with native floats, so NaN and the infinities survive bit for
+// bit, including the NaN payload and sign. The wrappers forward to the inner float whether or not
+// the format is human-readable, so both modes encode the same native float.
+#[test]
+fn test_msgpack_round_trip_is_exact() {
+    let values = [
+        f64::NAN,
+        -f64::NAN,
+        f64::from_bits(0x7ff8_0000_dead_beef),
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        -0.0,
+        f64::MAX,
+        5e-324,
+    ];
+    for human_readable in [false, true] {
+        for &x in &values {
+            let bytes = to_vec(&OrderedFloat(x), human_readable);
+            assert_eq!(
+                bytes,
+                to_vec(&x, false),
+                "{:?} is not encoded as a float",
+                x
+            );
+            assert_eq!(bytes.len(), 9, "{:?} is not encoded as a float 64", x);
+            let back: OrderedFloat<f64> = from_slice(&bytes, human_readable).unwrap();
+            assert_eq!(back.0.to_bits(), x.to_bits());
+
+            let back: Result<NotNan<f64>, _> = from_slice(&bytes, human_readable);
+            match back {
+                Ok(back) => assert_eq!(back.into_inner().to_bits(), x.to_bits()),
+                Err(e) => {
+                    assert!(x.is_nan());
+                    assert!(
+                        e.to_string().contains("expected float (but not NaN)"),
+                        "{}",
+                        e
+                    );
+                }
+            }
+        }
+
+        for &x in &[f32::NAN, f32::INFINITY, f32::NEG_INFINITY, 1e-45] {
+            let bytes = to_vec(&OrderedFloat(x), human_readable);
+            assert_eq!(bytes.len(), 5, "{:?} is not encoded as a float 32", x);
+            let back: OrderedFloat<f32> = from_slice(&bytes, human_readable).unwrap();
+            assert_eq!(back.0.to_bits(), x.to_bits());
+        }
+
+        let inf = NotNan::new(f64::INFINITY).unwrap();
+        let bytes = to_vec(&inf, human_readable);
+        let back: NotNan<f64> = from_slice(&bytes, human_readable).unwrap();
+        assert_eq!(back, inf);
+    }
+}
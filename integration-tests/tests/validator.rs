@@ -0,0 +1,32 @@
+use ordered_float::{NotNan, OrderedFloat};
+use validator::Validate;
+
+#[derive(Validate)]
+struct Request {
+    #[validate(range(min = 0.0, max = 1.0))]
+    probability: NotNan<f64>,
+    #[validate(range(exclusive_min = 0.0))]
+    weight: OrderedFloat<f64>,
+}
+
+fn request(probability: f64, weight: f64) -> Request {
+    Request {
+        probability: NotNan::new(probability).unwrap(),
+        weight: OrderedFloat(weight),
+    }
+}
+
+#[test]
+fn test_derive_validate_range() {
+    assert!(request(0.0, 1.0).validate().is_ok());
+    assert!(request(1.0, 1.0).validate().is_ok());
+    assert!(request(0.5, 1e-300).validate().is_ok());
+
+    let errors = request(1.5, 1.0).validate().unwrap_err();
+    assert!(errors.field_errors().contains_key("probability"));
+    assert!(request(-0.1, 1.0).validate().is_err());
+
+    let errors = request(0.5, 0.0).validate().unwrap_err();
+    assert!(errors.field_errors().contains_key("weight"));
+    assert!(request(0.5, f64::NAN).validate().is_err());
+}
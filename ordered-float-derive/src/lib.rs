@@ -0,0 +1,158 @@
+#![deny(missing_docs)]
+
+//! Derive macro for the `ordered-float` crate. Enable the `derive` feature of `ordered-float` and
+//! use it as `ordered_float::OrdFloat` rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, Index, Type};
+
+/// Derives `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash` for a struct, comparing and hashing
+/// every `f32` or `f64` field as if it were wrapped in `OrderedFloat`.
+///
+/// Fields are compared in declaration order, like the standard derives. Fields of any other type
+/// use their own `Ord` and `Hash` impls. Only fields whose type is written as plain `f32` or `f64`
+/// get float semantics; wrap floats nested in other types (such as `Option<f64>` or `[f64; 2]`)
+/// in `OrderedFloat` explicitly.
+///
+/// Do not also derive `PartialEq` or `PartialOrd` from the standard library: this derive provides
+/// them, consistent with `Ord`.
+///
+/// ```
+/// use ordered_float::OrdFloat;
+///
+/// #[derive(OrdFloat, Debug)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// let mut points = vec![Point { x: 1.0, y: f64::NAN }, Point { x: 1.0, y: 0.0 }];
+/// points.sort();
+/// assert_eq!(points[0].y, 0.0);
+/// ```
+#[proc_macro_derive(OrdFloat)]
+pub fn derive_ord_float(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "OrdFloat can only be derived for structs",
+            ))
+        }
+    };
+
+    // (accessor, is the field a bare float)
+    let fields: Vec<(TokenStream2, bool)> = match fields {
+        Fields::Named(named) => named
+            .named
+            .iter()
+            .map(|f| {
+                let ident = f.ident.as_ref().unwrap();
+                (quote!(#ident), is_float(&f.ty))
+            })
+            .collect(),
+        Fields::Unnamed(unnamed) => unnamed
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                let index = Index::from(i);
+                (quote!(#index), is_float(&f.ty))
+            })
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+
+    let wrap = |value: TokenStream2, float: bool| {
+        if float {
+            quote!(::ordered_float::OrderedFloat(#value))
+        } else {
+            quote!(#value)
+        }
+    };
+
+    let eqs = fields.iter().map(|(field, float)| {
+        let a = wrap(quote!(self.#field), *float);
+        let b = wrap(quote!(other.#field), *float);
+        quote!(::core::cmp::PartialEq::eq(&#a, &#b))
+    });
+    let cmps = fields.iter().map(|(field, float)| {
+        let a = wrap(quote!(self.#field), *float);
+        let b = wrap(quote!(other.#field), *float);
+        quote! {
+            match ::core::cmp::Ord::cmp(&#a, &#b) {
+                ::core::cmp::Ordering::Equal => {}
+                ordering => return ordering,
+            }
+        }
+    });
+    let hashes = fields.iter().map(|(field, float)| {
+        let value = wrap(quote!(self.#field), *float);
+        quote!(::core::hash::Hash::hash(&#value, state);)
+    });
+
+    let name = &input.ident;
+    let generics = &mut input.generics;
+    let type_params: Vec<_> = generics.type_params().map(|p| p.ident.clone()).collect();
+    let where_clause = generics.make_where_clause();
+    for param in &type_params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#param: ::core::cmp::Ord + ::core::hash::Hash));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let hasher = format_ident!("__H");
+
+    Ok(quote! {
+        impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics #where_clause {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                true #(&& #eqs)*
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Eq for #name #ty_generics #where_clause {}
+
+        impl #impl_generics ::core::cmp::PartialOrd for #name #ty_generics #where_clause {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Ord for #name #ty_generics #where_clause {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                #(#cmps)*
+                ::core::cmp::Ordering::Equal
+            }
+        }
+
+        impl #impl_generics ::core::hash::Hash for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn hash<#hasher: ::core::hash::Hasher>(&self, state: &mut #hasher) {
+                #(#hashes)*
+            }
+        }
+    })
+}
+
+fn is_float(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => {
+            path.path.is_ident("f32") || path.path.is_ident("f64")
+        }
+        Type::Group(group) => is_float(&group.elem),
+        Type::Paren(paren) => is_float(&paren.elem),
+        _ => false,
+    }
+}
@@ -18,21 +18,29 @@ use core::hash::{Hash, Hasher};
 use core::iter::{Product, Sum};
 use core::num::FpCategory;
 use core::ops::{
-    Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
-    SubAssign,
+    Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, RangeInclusive, Rem,
+    RemAssign, Sub, SubAssign,
 };
 use core::str::FromStr;
 
 pub use num_traits::float::FloatCore;
 use num_traits::{
-    AsPrimitive, Bounded, FloatConst, FromPrimitive, Num, NumCast, One, Signed, ToPrimitive, Zero,
+    AsPrimitive, Bounded, FloatConst, FromPrimitive, Num, NumCast, One, Saturating, Signed,
+    ToPrimitive, Zero,
 };
 #[cfg(any(feature = "std", feature = "libm"))]
 pub use num_traits::{Float, Pow};
 
+#[cfg(all(feature = "std", target_has_atomic = "32"))]
+pub use impl_atomic::AtomicOrderedF32;
+#[cfg(all(feature = "std", target_has_atomic = "64"))]
+pub use impl_atomic::AtomicOrderedF64;
 #[cfg(feature = "rand")]
 pub use impl_rand::{UniformNotNan, UniformOrdered};
 
+#[cfg(feature = "derive")]
+pub use ordered_float_derive::OrdFloat;
+
 // masks for the parts of the IEEE 754 float
 const SIGN_MASK: u64 = 0x8000000000000000u64;
 const EXP_MASK: u64 = 0x7ff0000000000000u64;
@@ -49,6 +57,123 @@ fn canonicalize_signed_zero<T: FloatCore>(x: T) -> T {
     x + T::zero()
 }
 
+#[inline]
+fn is_close<T: FloatCore>(a: T, b: T, rel_tol: T, abs_tol: T) -> bool {
+    if a == b {
+        return true;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+    let diff = (a - b).abs();
+    diff <= (rel_tol * a.abs().max(b.abs())).max(abs_tol)
+}
+
+// NaN and `-0.0` (as well as negative values) become `+0.0`.
+#[inline]
+fn saturate<T: FloatCore>(x: T) -> T {
+    if x > T::one() {
+        T::one()
+    } else if x > T::zero() {
+        x
+    } else {
+        T::zero()
+    }
+}
+
+// Rounds to `decimals` decimal places. For negative `decimals` the value is divided by an exact
+// power of ten rather than multiplied by an inexact one such as `0.01`. Never returns NaN for a
+// non-NaN input: when the scaled value overflows the input already has no more precision to
+// lose, and when the power of ten overflows the result rounds to zero.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn round_to<T: Float>(x: T, decimals: i32) -> T {
+    if !x.is_finite() {
+        return x;
+    }
+    let ten = T::one() + T::one() + T::one() + T::one() + T::one();
+    let ten = ten + ten;
+    let factor = ten.powi(decimals.saturating_abs());
+    if decimals >= 0 {
+        let scaled = x * factor;
+        if scaled.is_finite() {
+            scaled.round() / factor
+        } else {
+            x
+        }
+    } else if factor.is_finite() {
+        (x / factor).round() * factor
+    } else {
+        x * T::zero()
+    }
+}
+
+// Euclidean remainder of two integer-valued floats, or `None` for a non-integer operand, a zero
+// divisor, or a result that isn't exactly representable (possible only above 2^53 for `f64`).
+fn rem_euclid_int<T: FloatCore>(a: T, b: T) -> Option<T> {
+    // `fract` is NaN for NaN and infinities, so those are rejected here too.
+    if a.fract() != T::zero() || b.fract() != T::zero() || b == T::zero() {
+        return None;
+    }
+    // `%` is exact for finite operands.
+    let r = a % b;
+    if r == T::zero() {
+        Some(T::zero())
+    } else if r > T::zero() {
+        Some(r)
+    } else {
+        let m = r + b.abs();
+        if m - b.abs() == r {
+            Some(m)
+        } else {
+            None
+        }
+    }
+}
+
+// `signum`, except that both zeros map to `+0.0`. NaN is returned unchanged.
+#[inline]
+fn signum_zero<T: FloatCore>(x: T) -> T {
+    if x > T::zero() {
+        T::one()
+    } else if x < T::zero() {
+        -T::one()
+    } else if x.is_nan() {
+        x
+    } else {
+        T::zero()
+    }
+}
+
+// IEEE 754-2019 `minimum`: NaN propagates (the first NaN operand is returned as is), and `-0.0`
+// is less than `+0.0`.
+#[inline]
+fn ieee_minimum<T: FloatCore>(a: T, b: T) -> T {
+    if a.is_nan() || a < b || (a == b && a.is_sign_negative()) {
+        a
+    } else {
+        b
+    }
+}
+
+// IEEE 754-2019 `maximum`, with the same NaN and signed zero handling as `ieee_minimum`.
+#[inline]
+fn ieee_maximum<T: FloatCore>(a: T, b: T) -> T {
+    if a.is_nan() || a > b || (a == b && a.is_sign_positive()) {
+        a
+    } else {
+        b
+    }
+}
+
+#[inline(always)]
+fn canonicalize<T: FloatCore>(x: T) -> T {
+    if x.is_nan() {
+        T::nan()
+    } else {
+        canonicalize_signed_zero(x)
+    }
+}
+
 /// A wrapper around floats providing implementations of `Eq`, `Ord`, and `Hash`.
 ///
 /// NaN is sorted as *greater* than all other values and *equal*
@@ -90,6 +215,49 @@ fn canonicalize_signed_zero<T: FloatCore>(x: T) -> T {
 /// applications which require equal results from equal inputs, unless these cases make no
 /// difference or are canonicalized before insertion.
 ///
+/// In a `BinaryHeap`, which is a max-heap, NaN is popped first. Wrapping the keys in
+/// [`Reverse`](core::cmp::Reverse) to get a min-heap also reverses the position of NaN, so it is
+/// popped *last*:
+///
+/// ```
+/// # use ordered_float::OrderedFloat;
+/// use std::cmp::Reverse;
+/// use std::collections::BinaryHeap;
+///
+/// let mut heap: BinaryHeap<_> = [1.0, f64::NAN, 2.0]
+///     .iter()
+///     .map(|&x| Reverse(OrderedFloat(x)))
+///     .collect();
+/// assert_eq!(heap.pop(), Some(Reverse(OrderedFloat(1.0))));
+/// assert_eq!(heap.pop(), Some(Reverse(OrderedFloat(2.0))));
+/// assert!(heap.pop().unwrap().0.is_nan());
+/// ```
+///
+/// The [`Default`] value is `OrderedFloat(T::default())`, which is `+0.0` for the primitive
+/// floats. For `f32` and `f64`, [`default_const`](OrderedFloat::default_const) gives the same
+/// value in `const` contexts.
+///
+/// # Arithmetic and type inference
+///
+/// The arithmetic operators are implemented for `OrderedFloat<T>` and `T` operands, each by value
+/// and by reference. With that many candidate impls, the compiler sometimes can't infer an
+/// operand type on its own, for example for an unannotated closure parameter or a float literal
+/// whose type is otherwise unconstrained. Annotating the type fixes this:
+///
+/// ```
+/// # use ordered_float::OrderedFloat;
+/// let offset = |x: OrderedFloat<f64>| x + 1.0;
+/// assert_eq!(offset(OrderedFloat(1.0)), OrderedFloat(2.0));
+/// ```
+///
+/// There is deliberately no feature to remove the reference forms: Cargo features are additive,
+/// so turning impls off in one crate would break every other crate in the build that uses them.
+///
+/// These operators, along with `Neg`, `Sum`, `Product`, the formatting traits, and the `Deref`,
+/// `AsRef` and `From` conversions, only require the corresponding impls on `T`, so they also work
+/// for non-float `T` such as fixed-point types. Comparison, hashing and the other float-specific
+/// functionality require `T: FloatCore`.
+///
 /// # Representation
 ///
 /// `OrderedFloat` has `#[repr(transparent)]` and permits any value, so it is sound to use
@@ -102,6 +270,7 @@ fn canonicalize_signed_zero<T: FloatCore>(x: T) -> T {
     doc = "[`bytemuck`]: https://docs.rs/bytemuck/1/"
 )]
 #[derive(Default, Clone, Copy)]
+#[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
 #[repr(transparent)]
 pub struct OrderedFloat<T>(pub T);
 
@@ -236,2655 +405,7847 @@ impl<T: FloatCore> OrderedFloat<T> {
     pub fn into_inner(self) -> T {
         self.0
     }
-}
 
-impl<T: FloatCore> AsRef<T> for OrderedFloat<T> {
+    /// Compares with a raw float using the same equality as `OrderedFloat` itself: all NaNs are
+    /// equal to each other, and `-0.0` equals `+0.0`.
+    ///
+    /// This differs from `self == other`, which uses IEEE 754 equality for raw floats.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// assert!(OrderedFloat(f64::NAN).eq_total(&f64::NAN));
+    /// assert!(OrderedFloat(-0.0).eq_total(&0.0));
+    /// assert!(!OrderedFloat(1.0).eq_total(&f64::NAN));
+    /// ```
     #[inline]
-    fn as_ref(&self) -> &T {
-        &self.0
+    pub fn eq_total(&self, other: &T) -> bool {
+        *self == OrderedFloat(*other)
     }
-}
 
-impl<T: FloatCore> AsMut<T> for OrderedFloat<T> {
+    /// Compares using the IEEE 754 partial order of the inner floats.
+    ///
+    /// Unlike `partial_cmp`, which follows `OrderedFloat`'s total order and never returns `None`,
+    /// this returns `None` if either side is NaN, and treats `-0.0` and `+0.0` as equal.
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// let nan = OrderedFloat(f64::NAN);
+    /// assert_eq!(nan.partial_cmp_ieee(&OrderedFloat(1.0)), None);
+    /// assert_eq!(nan.partial_cmp(&OrderedFloat(1.0)), Some(Ordering::Greater));
+    /// ```
     #[inline]
-    fn as_mut(&mut self) -> &mut T {
-        &mut self.0
+    pub fn partial_cmp_ieee(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
     }
-}
 
-impl<'a, T: FloatCore> From<&'a T> for &'a OrderedFloat<T> {
+    /// Views this value as a `NotNan` without copying, or returns `None` if it is NaN.
+    ///
+    /// There is no `&mut` counterpart, since writing NaN through it would break `NotNan`'s
+    /// invariant.
+    ///
+    /// ```
+    /// use ordered_float::{NotNan, OrderedFloat};
+    ///
+    /// let x = OrderedFloat(1.5);
+    /// let y: &NotNan<f64> = x.as_not_nan().unwrap();
+    /// assert_eq!(*y, 1.5);
+    /// assert!(OrderedFloat(f64::NAN).as_not_nan().is_none());
+    /// ```
     #[inline]
-    fn from(t: &'a T) -> &'a OrderedFloat<T> {
-        // Safety: OrderedFloat is #[repr(transparent)] and has no invalid values.
-        unsafe { &*(t as *const T as *const OrderedFloat<T>) }
+    pub fn as_not_nan(&self) -> Option<&NotNan<T>> {
+        if self.0.is_nan() {
+            None
+        } else {
+            // Safety: OrderedFloat and NotNan are both #[repr(transparent)] over T, and the
+            // value was checked above. The shared borrow keeps it from changing.
+            Some(unsafe { &*(self as *const Self as *const NotNan<T>) })
+        }
     }
-}
 
-impl<'a, T: FloatCore> From<&'a mut T> for &'a mut OrderedFloat<T> {
+    /// Converts to a `NotNan`, panicking with `msg` if the value is NaN.
+    ///
+    /// ```
+    /// use ordered_float::{NotNan, OrderedFloat};
+    ///
+    /// let x: NotNan<f64> = OrderedFloat(1.5).expect_not_nan("x is never NaN");
+    /// assert_eq!(x, 1.5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the value is NaN.
     #[inline]
-    fn from(t: &'a mut T) -> &'a mut OrderedFloat<T> {
-        // Safety: OrderedFloat is #[repr(transparent)] and has no invalid values.
-        unsafe { &mut *(t as *mut T as *mut OrderedFloat<T>) }
+    #[track_caller]
+    pub fn expect_not_nan(self, msg: &str) -> NotNan<T> {
+        match self.as_not_nan() {
+            Some(x) => *x,
+            None => panic!("{}", msg),
+        }
     }
-}
 
-impl<T: FloatCore> PartialOrd for OrderedFloat<T> {
+    /// Converts to a `NotNan`, panicking if the value is NaN.
+    ///
+    /// Prefer [`expect_not_nan`](Self::expect_not_nan) when there is something useful to say
+    /// about why the value can't be NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is NaN.
     #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    #[track_caller]
+    pub fn unwrap_not_nan(self) -> NotNan<T> {
+        match self.as_not_nan() {
+            Some(x) => *x,
+            None => panic!("called `OrderedFloat::unwrap_not_nan()` on a NaN value"),
+        }
     }
 
+    /// Returns `true` if `self` and `other` are close, using the same rule as Python's
+    /// `math.isclose`:
+    ///
+    /// `|a - b| <= max(rel_tol * max(|a|, |b|), abs_tol)`
+    ///
+    /// Equal values are always close, so two infinities of the same sign are close, while an
+    /// infinity is never close to any other value. NaN is not close to anything, including NaN.
+    /// `rel_tol` and `abs_tol` should be nonnegative.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// let a = OrderedFloat(1.0);
+    /// assert!(a.is_close(OrderedFloat(1.0 + 1e-10), 1e-9, 0.0));
+    /// assert!(!a.is_close(OrderedFloat(1.1), 1e-9, 0.0));
+    /// assert!(OrderedFloat(0.0).is_close(OrderedFloat(1e-12), 1e-9, 1e-10));
+    /// ```
     #[inline]
-    fn lt(&self, other: &Self) -> bool {
-        !self.ge(other)
+    pub fn is_close(self, other: Self, rel_tol: T, abs_tol: T) -> bool {
+        is_close(self.0, other.0, rel_tol, abs_tol)
     }
 
+    /// Returns the floating point category of the number. See [`f64::classify`].
+    ///
+    /// This and the predicates below are inherent so that they don't need `FloatCore` in scope.
     #[inline]
-    fn le(&self, other: &Self) -> bool {
-        other.ge(self)
+    pub fn classify(self) -> FpCategory {
+        self.0.classify()
     }
 
+    /// Returns `true` if the number is neither infinite nor NaN.
     #[inline]
-    fn gt(&self, other: &Self) -> bool {
-        !other.ge(self)
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
     }
 
+    /// Returns `true` if the number is positive or negative infinity.
     #[inline]
-    fn ge(&self, other: &Self) -> bool {
-        // We consider all NaNs equal, and NaN is the largest possible
-        // value. Thus if self is NaN we always return true. Otherwise
-        // self >= other is correct. If other is also not NaN it is trivially
-        // correct, and if it is we note that nothing can be greater or
-        // equal to NaN except NaN itself, which we already handled earlier.
-        self.0.is_nan() | (self.0 >= other.0)
+    pub fn is_infinite(self) -> bool {
+        self.0.is_infinite()
     }
-}
 
-impl<T: FloatCore> Ord for OrderedFloat<T> {
+    /// Returns `true` if the number is neither zero, infinite, subnormal, nor NaN.
     #[inline]
-    fn cmp(&self, other: &Self) -> Ordering {
-        #[allow(clippy::comparison_chain)]
-        if self < other {
-            Ordering::Less
-        } else if self > other {
-            Ordering::Greater
-        } else {
-            Ordering::Equal
-        }
+    pub fn is_normal(self) -> bool {
+        self.0.is_normal()
     }
-}
 
-impl<T: FloatCore> PartialEq for OrderedFloat<T> {
+    /// Returns `true` if the number is [subnormal].
+    ///
+    /// [subnormal]: https://en.wikipedia.org/wiki/Denormal_number
     #[inline]
-    fn eq(&self, other: &OrderedFloat<T>) -> bool {
-        if self.0.is_nan() {
-            other.0.is_nan()
-        } else {
-            self.0 == other.0
-        }
+    pub fn is_subnormal(self) -> bool {
+        self.0.classify() == FpCategory::Subnormal
     }
-}
 
-impl<T: FloatCore> PartialEq<T> for OrderedFloat<T> {
+    /// Returns `true` if the sign bit is clear, including for `+0.0` and NaNs with a positive sign.
     #[inline]
-    fn eq(&self, other: &T) -> bool {
-        self.0 == *other
+    pub fn is_sign_positive(self) -> bool {
+        self.0.is_sign_positive()
     }
-}
 
-impl<T: FloatCore> Hash for OrderedFloat<T> {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        let bits = if self.is_nan() {
-            CANONICAL_NAN_BITS
-        } else {
-            raw_double_bits(&canonicalize_signed_zero(self.0))
-        };
+    /// Returns `true` if the sign bit is set, including for `-0.0` and NaNs with a negative sign.
+    #[inline]
+    pub fn is_sign_negative(self) -> bool {
+        self.0.is_sign_negative()
+    }
 
-        bits.hash(state)
+    /// Returns the canonical representative of this value: `-0.0` becomes `+0.0` and every NaN
+    /// becomes `T::nan()`.
+    ///
+    /// Values that compare equal are bit-identical after canonicalization, which makes the result
+    /// suitable as a key for interning or memoization where bit equality matters.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// let a = OrderedFloat(-0.0f64).to_canonical();
+    /// let b = OrderedFloat(0.0f64).to_canonical();
+    /// assert_eq!(a.0.to_bits(), b.0.to_bits());
+    /// ```
+    #[inline]
+    pub fn to_canonical(self) -> Self {
+        OrderedFloat(canonicalize(self.0))
     }
-}
 
-impl<T: fmt::Debug> fmt::Debug for OrderedFloat<T> {
+    /// Converts to a `NotNan`, replacing NaN with zero. Other values, including infinities, are
+    /// passed through unchanged.
+    ///
+    /// ```
+    /// use ordered_float::{NotNan, OrderedFloat};
+    ///
+    /// assert_eq!(OrderedFloat(f64::NAN).nan_to_zero(), NotNan::new(0.0).unwrap());
+    /// assert_eq!(OrderedFloat(1.5).nan_to_zero(), NotNan::new(1.5).unwrap());
+    /// ```
     #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.0.fmt(f)
+    pub fn nan_to_zero(self) -> NotNan<T> {
+        if self.0.is_nan() {
+            NotNan(T::zero())
+        } else {
+            NotNan(self.0)
+        }
     }
-}
 
-impl<T: FloatCore + fmt::Display> fmt::Display for OrderedFloat<T> {
+    /// Converts to a `NotNan`, replacing NaN with `default`. Other values, including infinities,
+    /// are passed through unchanged.
+    ///
+    /// Returns an error if `default` is itself NaN, even when `self` is not NaN.
+    ///
+    /// ```
+    /// use ordered_float::{NotNan, OrderedFloat};
+    ///
+    /// assert_eq!(OrderedFloat(f64::NAN).nan_to(-1.0), Ok(NotNan::new(-1.0).unwrap()));
+    /// assert_eq!(OrderedFloat(2.0).nan_to(-1.0), Ok(NotNan::new(2.0).unwrap()));
+    /// assert!(OrderedFloat(2.0).nan_to(f64::NAN).is_err());
+    /// ```
     #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.0.fmt(f)
+    pub fn nan_to(self, default: T) -> Result<NotNan<T>, FloatIsNan> {
+        let default = NotNan::new(default)?;
+        if self.0.is_nan() {
+            Ok(default)
+        } else {
+            Ok(NotNan(self.0))
+        }
     }
-}
 
-impl<T: FloatCore + fmt::LowerExp> fmt::LowerExp for OrderedFloat<T> {
+    /// Multiplies by `factor`, returning `Err` if the product is NaN.
+    ///
+    /// Unlike `self * factor`, which produces `OrderedFloat(NaN)`, this reports the NaN from
+    /// `0.0 * inf`, a NaN `factor`, or a NaN `self` as an error.
+    ///
+    /// ```
+    /// # use ordered_float::{FloatIsNan, OrderedFloat};
+    /// assert_eq!(OrderedFloat(0.5).scale(3.0), Ok(OrderedFloat(1.5)));
+    /// assert_eq!(OrderedFloat(0.0).scale(f64::INFINITY), Err(FloatIsNan));
+    /// ```
     #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
+    pub fn scale(self, factor: T) -> Result<Self, FloatIsNan> {
+        let product = self.0 * factor;
+        if product.is_nan() {
+            Err(FloatIsNan)
+        } else {
+            Ok(OrderedFloat(product))
+        }
     }
-}
 
-impl<T: FloatCore + fmt::UpperExp> fmt::UpperExp for OrderedFloat<T> {
+    /// Multiplies by `factor` without checking the product for NaN; the same as `self * factor`.
+    ///
+    /// `OrderedFloat` may hold NaN, so unlike [`NotNan::scale_unchecked`] this is safe.
     #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
+    pub fn scale_unchecked(self, factor: T) -> Self {
+        OrderedFloat(self.0 * factor)
     }
-}
 
-impl From<OrderedFloat<f32>> for f32 {
+    /// Raises the value to a non-negative integer power by repeated squaring, using only
+    /// multiplication, so it is available without `std` or `libm`.
+    ///
+    /// The result can differ from `powi` in the last bits, because the rounding of the
+    /// intermediate products happens in a different order. Results that are exactly
+    /// representable, like powers of two, are exact.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// assert_eq!(OrderedFloat(2.0).powi_nostd(10), OrderedFloat(1024.0));
+    /// assert_eq!(OrderedFloat(5.0).powi_nostd(0), OrderedFloat(1.0));
+    /// ```
     #[inline]
-    fn from(f: OrderedFloat<f32>) -> f32 {
-        f.0
+    pub fn powi_nostd(self, n: u32) -> Self {
+        OrderedFloat(num_traits::pow(self.0, n as usize))
     }
-}
 
-impl From<OrderedFloat<f64>> for f64 {
+    /// Linearly interpolates between `self` and `other`, computing `self + (other - self) * t`.
+    ///
+    /// For finite endpoints, `t = 0` gives `self`. Values of `t` outside `[0, 1]` extrapolate
+    /// along the same line. The result may be NaN, e.g. when interpolating between two infinities,
+    /// or with `t = 0` when either endpoint is infinite, since `(other - self) * 0` is then NaN.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// let (a, b) = (OrderedFloat(1.0), OrderedFloat(3.0));
+    /// assert_eq!(a.lerp(b, 0.5), OrderedFloat(2.0));
+    /// assert_eq!(a.lerp(b, 2.0), OrderedFloat(5.0));
+    /// ```
     #[inline]
-    fn from(f: OrderedFloat<f64>) -> f64 {
-        f.0
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        OrderedFloat(self.0 + (other.0 - self.0) * t)
     }
-}
 
-impl<T: FloatCore> From<T> for OrderedFloat<T> {
+    /// Clamps the value into `[0.0, 1.0]`, as the `saturate` function in shading languages does.
+    ///
+    /// NaN maps to `0.0`, as does `-0.0`.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// assert_eq!(OrderedFloat(1.5).saturate(), OrderedFloat(1.0));
+    /// assert_eq!(OrderedFloat(-0.5).saturate(), OrderedFloat(0.0));
+    /// assert_eq!(OrderedFloat(0.25).saturate(), OrderedFloat(0.25));
+    /// assert_eq!(OrderedFloat(f64::NAN).saturate(), OrderedFloat(0.0));
+    /// ```
     #[inline]
-    fn from(val: T) -> Self {
-        OrderedFloat(val)
+    pub fn saturate(self) -> Self {
+        OrderedFloat(saturate(self.0))
     }
-}
 
-impl From<bool> for OrderedFloat<f32> {
-    fn from(val: bool) -> Self {
-        OrderedFloat(val as u8 as f32)
+    /// Returns `1.0` for positive values, `-1.0` for negative values, and `0.0` for both zeros,
+    /// unlike [`signum`](FloatCore::signum), which returns `±1.0` for `±0.0`. NaN is returned
+    /// unchanged.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// assert_eq!(OrderedFloat(-0.0).signum_zero(), OrderedFloat(0.0));
+    /// assert_eq!(OrderedFloat(-3.5).signum_zero(), OrderedFloat(-1.0));
+    /// assert_eq!(OrderedFloat(f64::NAN).signum_zero(), OrderedFloat(f64::NAN));
+    /// ```
+    #[inline]
+    pub fn signum_zero(self) -> Self {
+        OrderedFloat(signum_zero(self.0))
     }
-}
 
-impl From<bool> for OrderedFloat<f64> {
-    fn from(val: bool) -> Self {
-        OrderedFloat(val as u8 as f64)
+    /// Returns the lesser of two values using IEEE 754-2019 `minimum` semantics: if either
+    /// value is NaN the result is NaN, and `-0.0` is less than `+0.0`.
+    ///
+    /// This differs from [`Ord::min`], which treats NaN as the greatest value and so only
+    /// returns NaN if both values are NaN, and which considers the two zeros equal.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// let nan = OrderedFloat(f64::NAN);
+    /// assert!(OrderedFloat(1.0).minimum(nan).is_nan());
+    /// assert_eq!(Ord::min(OrderedFloat(1.0), nan), OrderedFloat(1.0));
+    /// assert!(OrderedFloat(0.0).minimum(OrderedFloat(-0.0)).is_sign_negative());
+    /// ```
+    #[inline]
+    pub fn minimum(self, other: Self) -> Self {
+        OrderedFloat(ieee_minimum(self.0, other.0))
     }
-}
-
-macro_rules! impl_ordered_float_from {
-    ($dst:ty, $src:ty) => {
-        impl From<$src> for OrderedFloat<$dst> {
-            fn from(val: $src) -> Self {
-                OrderedFloat(val.into())
-            }
-        }
-    };
-}
-impl_ordered_float_from! {f64, i8}
-impl_ordered_float_from! {f64, i16}
-impl_ordered_float_from! {f64, i32}
-impl_ordered_float_from! {f64, u8}
-impl_ordered_float_from! {f64, u16}
-impl_ordered_float_from! {f64, u32}
-impl_ordered_float_from! {f32, i8}
-impl_ordered_float_from! {f32, i16}
-impl_ordered_float_from! {f32, u8}
-impl_ordered_float_from! {f32, u16}
-
-impl<T: FloatCore> Deref for OrderedFloat<T> {
-    type Target = T;
 
+    /// Returns the greater of two values using IEEE 754-2019 `maximum` semantics: if either
+    /// value is NaN the result is NaN, and `+0.0` is greater than `-0.0`.
+    ///
+    /// [`Ord::max`] also returns NaN whenever either value is NaN, since NaN is the greatest
+    /// value in this crate's ordering, but it considers the two zeros equal.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// assert!(OrderedFloat(1.0).maximum(OrderedFloat(f64::NAN)).is_nan());
+    /// assert!(OrderedFloat(-0.0).maximum(OrderedFloat(0.0)).is_sign_positive());
+    /// assert_eq!(OrderedFloat(-1.0).maximum(OrderedFloat(2.0)), OrderedFloat(2.0));
+    /// ```
     #[inline]
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    pub fn maximum(self, other: Self) -> Self {
+        OrderedFloat(ieee_maximum(self.0, other.0))
     }
-}
 
-impl<T: FloatCore> DerefMut for OrderedFloat<T> {
+    /// Calculates the least nonnegative remainder of `self (mod rhs)` for integer-valued
+    /// operands, such as lattice coordinates. The result is exact, and zero is always `+0.0`.
+    ///
+    /// Returns `None` if either operand is not an integer (including NaN and infinities), if
+    /// `rhs` is zero, or if the remainder can't be represented exactly, which can only happen
+    /// for a negative `self` and a `rhs` of magnitude beyond 2<sup>53</sup> (2<sup>24</sup>
+    /// for `f32`).
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// assert_eq!(OrderedFloat(-7.0).rem_euclid_int(OrderedFloat(4.0)), Some(OrderedFloat(1.0)));
+    /// assert_eq!(OrderedFloat(7.0).rem_euclid_int(OrderedFloat(-4.0)), Some(OrderedFloat(3.0)));
+    /// assert_eq!(OrderedFloat(7.5).rem_euclid_int(OrderedFloat(4.0)), None);
+    /// assert_eq!(OrderedFloat(7.0).rem_euclid_int(OrderedFloat(0.0)), None);
+    /// ```
     #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+    pub fn rem_euclid_int(self, rhs: Self) -> Option<Self> {
+        rem_euclid_int(self.0, rhs.0).map(OrderedFloat)
     }
-}
 
-impl<T: FloatCore> Eq for OrderedFloat<T> {}
+    /// Converts a boxed float into a boxed `OrderedFloat` without reallocating.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// let b: Box<OrderedFloat<f64>> = OrderedFloat::from_boxed(Box::new(1.5));
+    /// assert_eq!(*b, OrderedFloat(1.5));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_boxed(b: std::boxed::Box<T>) -> std::boxed::Box<Self> {
+        // Safety: OrderedFloat is #[repr(transparent)] and has no invalid values.
+        unsafe { std::boxed::Box::from_raw(std::boxed::Box::into_raw(b) as *mut Self) }
+    }
 
-macro_rules! impl_ordered_float_binop {
-    ($imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident) => {
-        impl<T: $imp> $imp for OrderedFloat<T> {
-            type Output = OrderedFloat<T::Output>;
+    /// Converts a boxed `OrderedFloat` into a boxed float without reallocating.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn into_boxed_inner(b: std::boxed::Box<Self>) -> std::boxed::Box<T> {
+        // Safety: OrderedFloat is #[repr(transparent)].
+        unsafe { std::boxed::Box::from_raw(std::boxed::Box::into_raw(b) as *mut T) }
+    }
 
-            #[inline]
-            fn $method(self, other: Self) -> Self::Output {
-                OrderedFloat((self.0).$method(other.0))
-            }
-        }
+    /// Converts a shared slice of floats into a shared slice of `OrderedFloat` without copying.
+    /// The returned `Arc` shares the allocation and reference count of the original.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    /// use std::sync::Arc;
+    ///
+    /// let a: Arc<[f64]> = Arc::from([2.0, 1.0]);
+    /// let b: Arc<[OrderedFloat<f64>]> = OrderedFloat::from_arc_slice(a);
+    /// assert_eq!(b.iter().min(), Some(&OrderedFloat(1.0)));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_arc_slice(a: std::sync::Arc<[T]>) -> std::sync::Arc<[Self]> {
+        // Safety: OrderedFloat is #[repr(transparent)] and has no invalid values, so the slice
+        // has the same layout and the pointer came from `Arc::into_raw`.
+        unsafe { std::sync::Arc::from_raw(std::sync::Arc::into_raw(a) as *const [Self]) }
+    }
 
-        // Work around for: https://github.com/reem/rust-ordered-float/issues/91
-        impl<'a, T: $imp + Copy> $imp<Self> for &'a OrderedFloat<T> {
-            type Output = OrderedFloat<T::Output>;
+    /// Converts a shared slice of `OrderedFloat` into a shared slice of floats without copying.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn into_arc_slice_inner(a: std::sync::Arc<[Self]>) -> std::sync::Arc<[T]> {
+        // Safety: OrderedFloat is #[repr(transparent)].
+        unsafe { std::sync::Arc::from_raw(std::sync::Arc::into_raw(a) as *const [T]) }
+    }
 
-            #[inline]
-            fn $method(self, other: Self) -> Self::Output {
-                OrderedFloat((self.0).$method(other.0))
-            }
-        }
+    /// Formats the inner value with a caller-supplied function, e.g. one that applies digit
+    /// grouping or locale conventions.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// let s = OrderedFloat(0.5f64).format_with(|x| format!("{:.2}", x));
+    /// assert_eq!(s, "0.50");
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn format_with(&self, f: impl Fn(T) -> std::string::String) -> std::string::String {
+        f(self.0)
+    }
 
-        impl<T: $imp> $imp<T> for OrderedFloat<T> {
-            type Output = OrderedFloat<T::Output>;
+    /// Formats the value in the given radix, using lowercase letters for digits above 9, in the
+    /// format that [`Num::from_str_radix`] parses.
+    ///
+    /// There is no exponent, so the integer part is written out in full and exactly. The
+    /// fractional part has the fewest digits that identify the value among its neighbouring
+    /// floats, like the decimal `Display` output, so `0.1` in radix 10 is written as `0.1`.
+    /// Infinities are written as `inf` and `-inf`, and NaN as `NaN`. The sign of `-0.0` is kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in `2..=36`.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// assert_eq!(OrderedFloat(255.0).to_string_radix(16), "ff");
+    /// assert_eq!(OrderedFloat(-10.75).to_string_radix(2), "-1010.11");
+    /// assert_eq!(OrderedFloat(0.1).to_string_radix(10), "0.1");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_string_radix(self, radix: u32) -> std::string::String {
+        to_string_radix(self.0, radix)
+    }
+}
 
+macro_rules! impl_write_shortest {
+    ($($wrapper:ident<$f:ident>),*) => {$(
+        impl $wrapper<$f> {
+            /// Writes the shortest decimal representation that parses back to the same value.
+            ///
+            /// With the `ryu` feature enabled this uses the `ryu` crate, which is faster than going
+            /// through `Display` and may use exponent notation (like `1e300`). Otherwise it falls
+            /// back to `Display`. `Display` itself is unaffected by the feature.
             #[inline]
-            fn $method(self, other: T) -> Self::Output {
-                OrderedFloat((self.0).$method(other))
+            pub fn write_shortest<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+                #[cfg(feature = "ryu")]
+                {
+                    w.write_str(ryu::Buffer::new().format(self.0))
+                }
+                #[cfg(not(feature = "ryu"))]
+                {
+                    write!(w, "{}", self.0)
+                }
             }
         }
+    )*};
+}
 
-        impl<'a, T> $imp<&'a T> for OrderedFloat<T>
-        where
-            T: $imp<&'a T>,
-        {
-            type Output = OrderedFloat<<T as $imp<&'a T>>::Output>;
-
+impl_write_shortest! {OrderedFloat<f32>, OrderedFloat<f64>, NotNan<f32>, NotNan<f64>}
+
+macro_rules! impl_next_up_down {
+    ($($wrapper:ident<$f:ident>),*) => {$(
+        impl $wrapper<$f> {
+            /// Returns the least representable value greater than `self`, following IEEE 754
+            /// `nextUp`.
+            ///
+            /// Both zeros step to the smallest positive subnormal, the largest finite value steps
+            /// to infinity, and infinity stays infinity. For `OrderedFloat`, NaN is returned
+            /// unchanged.
             #[inline]
-            fn $method(self, other: &'a T) -> Self::Output {
-                OrderedFloat((self.0).$method(other))
+            pub fn next_up(self) -> Self {
+                let x = self.0;
+                if x.is_nan() || x == <$f>::INFINITY {
+                    return self;
+                }
+                let bits = x.to_bits();
+                let next = if x == 0.0 {
+                    1
+                } else if x > 0.0 {
+                    bits + 1
+                } else {
+                    bits - 1
+                };
+                // Stepping a non-NaN value never produces NaN, so this keeps `NotNan` valid.
+                $wrapper(<$f>::from_bits(next))
             }
-        }
-
-        impl<'a, T> $imp<&'a Self> for OrderedFloat<T>
-        where
-            T: $imp<&'a T>,
-        {
-            type Output = OrderedFloat<<T as $imp<&'a T>>::Output>;
 
+            /// Returns the greatest representable value less than `self`, following IEEE 754
+            /// `nextDown`.
+            ///
+            /// This mirrors [`next_up`](Self::next_up): `next_down(x) == -next_up(-x)`.
             #[inline]
-            fn $method(self, other: &'a Self) -> Self::Output {
-                OrderedFloat((self.0).$method(&other.0))
+            pub fn next_down(self) -> Self {
+                $wrapper(-$wrapper(-self.0).next_up().0)
             }
         }
+    )*};
+}
 
-        impl<'a, T> $imp<OrderedFloat<T>> for &'a OrderedFloat<T>
-        where
-            &'a T: $imp<T>,
-        {
-            type Output = OrderedFloat<<&'a T as $imp<T>>::Output>;
+impl_next_up_down! {OrderedFloat<f32>, OrderedFloat<f64>, NotNan<f32>, NotNan<f64>}
 
+macro_rules! impl_ldexp {
+    ($f:ident, $bits:ident, $($wrapper:ident),*) => {$(
+        impl $wrapper<$f> {
+            /// Multiplies by 2<sup>`exp`</sup>, like C's `ldexp` or `scalbn`.
+            ///
+            /// The result is exact unless it overflows to infinity or falls into the subnormal
+            /// range, where it is rounded once. Zeros, infinities (and, for `OrderedFloat`, NaN)
+            /// are returned unchanged, so this never produces NaN.
             #[inline]
-            fn $method(self, other: OrderedFloat<T>) -> Self::Output {
-                OrderedFloat((self.0).$method(other.0))
+            pub fn ldexp(self, exp: i32) -> Self {
+                // This follows musl's `scalbn`: scale in at most three steps by powers of two
+                // that are normal numbers, so each step is exact except possibly the last.
+                const MAX: i32 = <$f>::MAX_EXP - 1;
+                const MIN: i32 = <$f>::MIN_EXP - 1;
+                const MANTISSA: i32 = <$f>::MANTISSA_DIGITS as i32 - 1;
+                let pow2 = |e: i32| <$f>::from_bits(((e + MAX) as $bits) << MANTISSA);
+
+                let (mut y, mut n) = (self.0, exp);
+                if n > MAX {
+                    y *= pow2(MAX);
+                    n -= MAX;
+                    if n > MAX {
+                        y *= pow2(MAX);
+                        n -= MAX;
+                        n = n.min(MAX);
+                    }
+                } else if n < MIN {
+                    // Step down to just above the subnormal range first, so that the final
+                    // multiplication is the only one that can round.
+                    let step = MIN + MANTISSA + 1;
+                    y *= pow2(step);
+                    n -= step;
+                    if n < MIN {
+                        y *= pow2(step);
+                        n -= step;
+                        n = n.max(MIN);
+                    }
+                }
+                // Scaling a non-NaN value by a positive finite factor never produces NaN, so this
+                // keeps `NotNan` valid.
+                $wrapper(y * pow2(n))
             }
         }
+    )*};
+}
 
-        impl<'a, T> $imp<T> for &'a OrderedFloat<T>
-        where
-            &'a T: $imp<T>,
-        {
-            type Output = OrderedFloat<<&'a T as $imp<T>>::Output>;
-
-            #[inline]
-            fn $method(self, other: T) -> Self::Output {
-                OrderedFloat((self.0).$method(other))
+impl_ldexp! {f32, u32, OrderedFloat, NotNan}
+impl_ldexp! {f64, u64, OrderedFloat, NotNan}
+
+macro_rules! impl_const_cmp {
+    ($f:ident, $bits:ident, $signed:ident, $inf_bits:expr) => {
+        impl OrderedFloat<$f> {
+            /// Compares like [`Ord::cmp`], but can be used in `const` contexts, e.g. to check that
+            /// a `const` lookup table is sorted.
+            ///
+            /// ```
+            /// use core::cmp::Ordering;
+            /// use ordered_float::OrderedFloat;
+            ///
+            #[doc = concat!("const ORDER: Ordering = OrderedFloat(1.0", stringify!($f), ").const_cmp(OrderedFloat(", stringify!($f), "::NAN));")]
+            /// assert_eq!(ORDER, Ordering::Less);
+            /// ```
+            pub const fn const_cmp(self, other: Self) -> Ordering {
+                // Float comparisons and `to_bits` are not `const` on the minimum supported Rust
+                // version, so compare keys computed from the bits, as `total_cmp` does, after
+                // mapping every NaN to one value and `-0.0` to `+0.0`.
+                #[allow(unknown_lints, unnecessary_transmutes)]
+                const fn key(x: $f) -> $signed {
+                    // Safety: every bit pattern is a valid integer.
+                    let bits: $bits = unsafe { core::mem::transmute::<$f, $bits>(x) };
+                    let magnitude = bits & !(1 << ($bits::BITS - 1));
+                    if magnitude > $inf_bits {
+                        // NaN sorts above everything, including `+inf`.
+                        $signed::MAX
+                    } else if magnitude == 0 {
+                        0
+                    } else {
+                        let bits = bits as $signed;
+                        bits ^ ((((bits >> ($bits::BITS - 1)) as $bits) >> 1) as $signed)
+                    }
+                }
+                let (a, b) = (key(self.0), key(other.0));
+                if a < b {
+                    Ordering::Less
+                } else if a > b {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
             }
         }
+    };
+}
 
-        impl<'a, T> $imp<&'a T> for &'a OrderedFloat<T>
-        where
-            &'a T: $imp,
-        {
-            type Output = OrderedFloat<<&'a T as $imp>::Output>;
-
+impl_const_cmp! {f32, u32, i32, 0x7f80_0000}
+impl_const_cmp! {f64, u64, i64, EXP_MASK}
+
+macro_rules! impl_not_nan_cmp_total {
+    ($($f:ident),*) => {$(
+        impl NotNan<$f> {
+            /// Compares using the IEEE 754 `totalOrder` predicate, like
+            #[doc = concat!("[`", stringify!($f), "::total_cmp`],")]
+            /// so `-0.0` sorts before `+0.0`. The [`Ord`] impl treats the two zeros as equal.
+            ///
+            /// ```
+            /// use core::cmp::Ordering;
+            /// use ordered_float::NotNan;
+            ///
+            #[doc = concat!("let (neg, pos) = (NotNan::new(-0.0", stringify!($f), ").unwrap(), NotNan::new(0.0).unwrap());")]
+            /// assert_eq!(neg.cmp_total(&pos), Ordering::Less);
+            /// assert_eq!(neg.cmp(&pos), Ordering::Equal);
+            ///
+            /// let mut v = [pos, neg, pos];
+            /// v.sort_by(|a, b| a.cmp_total(b));
+            /// assert!(v[0].is_sign_negative());
+            /// ```
             #[inline]
-            fn $method(self, other: &'a T) -> Self::Output {
-                OrderedFloat((self.0).$method(other))
+            pub fn cmp_total(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
             }
         }
+    )*};
+}
 
-        impl<T: $assign_imp> $assign_imp<T> for OrderedFloat<T> {
+impl_not_nan_cmp_total! {f32, f64}
+
+macro_rules! impl_default_const {
+    ($($wrapper:ident<$f:ident>),*) => {$(
+        impl $wrapper<$f> {
+            /// Returns `+0.0`, the same value as `Default::default()`, in a `const fn`.
+            ///
+            /// ```
+            #[doc = concat!("use ordered_float::", stringify!($wrapper), ";")]
+            ///
+            #[doc = concat!("const ZEROS: [", stringify!($wrapper), "<", stringify!($f), ">; 4] = [", stringify!($wrapper), "::<", stringify!($f), ">::default_const(); 4];")]
+            #[doc = concat!("assert_eq!(ZEROS[0], ", stringify!($wrapper), "::<", stringify!($f), ">::default());")]
+            /// ```
             #[inline]
-            fn $assign_method(&mut self, other: T) {
-                (self.0).$assign_method(other);
+            pub const fn default_const() -> Self {
+                $wrapper(0.0)
             }
         }
+    )*};
+}
 
-        impl<'a, T: $assign_imp<&'a T>> $assign_imp<&'a T> for OrderedFloat<T> {
-            #[inline]
-            fn $assign_method(&mut self, other: &'a T) {
-                (self.0).$assign_method(other);
-            }
-        }
+impl_default_const! {OrderedFloat<f32>, OrderedFloat<f64>, NotNan<f32>, NotNan<f64>}
 
-        impl<T: $assign_imp> $assign_imp for OrderedFloat<T> {
+macro_rules! impl_ordered_float_bits {
+    ($f:ident, $bits:ty) => {
+        impl OrderedFloat<$f> {
+            /// Raw transmutation to the underlying bit representation, as with the primitive
+            /// `to_bits` method.
             #[inline]
-            fn $assign_method(&mut self, other: Self) {
-                (self.0).$assign_method(other.0);
+            pub fn to_bits(self) -> $bits {
+                self.0.to_bits()
             }
-        }
 
-        impl<'a, T: $assign_imp<&'a T>> $assign_imp<&'a Self> for OrderedFloat<T> {
+            /// Raw transmutation from a bit representation, as with the primitive `from_bits`
+            /// function. Any bit pattern, including NaN patterns, is accepted.
             #[inline]
-            fn $assign_method(&mut self, other: &'a Self) {
-                (self.0).$assign_method(&other.0);
+            pub fn from_bits(bits: $bits) -> Self {
+                OrderedFloat(<$f>::from_bits(bits))
             }
         }
     };
 }
 
-impl_ordered_float_binop! {Add, add, AddAssign, add_assign}
-impl_ordered_float_binop! {Sub, sub, SubAssign, sub_assign}
-impl_ordered_float_binop! {Mul, mul, MulAssign, mul_assign}
-impl_ordered_float_binop! {Div, div, DivAssign, div_assign}
-impl_ordered_float_binop! {Rem, rem, RemAssign, rem_assign}
-
-macro_rules! impl_ordered_float_pow {
-    ($inner:ty, $rhs:ty) => {
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl Pow<$rhs> for OrderedFloat<$inner> {
-            type Output = OrderedFloat<$inner>;
-            #[inline]
-            fn pow(self, rhs: $rhs) -> OrderedFloat<$inner> {
-                OrderedFloat(<$inner>::pow(self.0, rhs))
+impl_ordered_float_bits! {f32, u32}
+impl_ordered_float_bits! {f64, u64}
+
+macro_rules! impl_ulps_between {
+    ($($wrapper:ident<$f:ident>, $bits:ty;)*) => {$(
+        impl $wrapper<$f> {
+            /// Returns the distance between `self` and `other` in units in the last place
+            /// (ULPs): `0` for equal values, `1` for adjacent representable values, and so on.
+            /// `MAX` and infinity are 1 ULP apart.
+            ///
+            /// Returns `None` if either value is NaN, or if one is negative and the other
+            /// positive. Zeros of either sign count as both, so `-0.0` and `+0.0` are 0 ULPs
+            /// apart and the distance from zero to any value is defined.
+            ///
+            /// ```
+            #[doc = concat!("use ordered_float::", stringify!($wrapper), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($wrapper), "::<", stringify!($f), ">::try_from(1.0).unwrap();")]
+            #[doc = concat!("let next = ", stringify!($wrapper), "::try_from(", stringify!($f), "::from_bits(1.0", stringify!($f), ".to_bits() + 1)).unwrap();")]
+            /// assert_eq!(one.ulps_between(next), Some(1));
+            /// assert_eq!(one.ulps_between(one), Some(0));
+            /// assert_eq!(one.ulps_between(-one), None);
+            /// ```
+            pub fn ulps_between(self, other: Self) -> Option<u64> {
+                const SIGN: $bits = 1 << (<$bits>::BITS - 1);
+                if self.0.is_nan() || other.0.is_nan() {
+                    return None;
+                }
+                let (a, b) = (self.0.to_bits(), other.0.to_bits());
+                let (a_mag, b_mag) = (a & !SIGN, b & !SIGN);
+                if (a ^ b) & SIGN != 0 && a_mag != 0 && b_mag != 0 {
+                    return None;
+                }
+                Some(a_mag.abs_diff(b_mag) as u64)
             }
         }
+    )*};
+}
 
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl<'a> Pow<&'a $rhs> for OrderedFloat<$inner> {
-            type Output = OrderedFloat<$inner>;
-            #[inline]
-            fn pow(self, rhs: &'a $rhs) -> OrderedFloat<$inner> {
-                OrderedFloat(<$inner>::pow(self.0, *rhs))
-            }
-        }
+impl_ulps_between! {
+    OrderedFloat<f32>, u32;
+    OrderedFloat<f64>, u64;
+    NotNan<f32>, u32;
+    NotNan<f64>, u64;
+}
 
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl<'a> Pow<$rhs> for &'a OrderedFloat<$inner> {
-            type Output = OrderedFloat<$inner>;
-            #[inline]
-            fn pow(self, rhs: $rhs) -> OrderedFloat<$inner> {
-                OrderedFloat(<$inner>::pow(self.0, rhs))
-            }
-        }
+/// Extension methods for vectors of [`OrderedFloat`].
+#[cfg(feature = "std")]
+pub trait OrderedFloatVecExt {
+    /// Removes consecutive equal elements, like
+    /// [`Vec::dedup`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.dedup), and
+    /// canonicalizes the remaining elements.
+    ///
+    /// Signed zeros are normalized to `+0.0` and NaNs to a single canonical NaN, so values that
+    /// are equal are also bit-identical afterwards. This makes the result suitable for interning.
+    ///
+    /// ```
+    /// use ordered_float::{OrderedFloat, OrderedFloatVecExt};
+    ///
+    /// let mut v = vec![OrderedFloat(-0.0f64), OrderedFloat(0.0), OrderedFloat(1.0)];
+    /// v.dedup_canonical();
+    /// assert_eq!(v, [OrderedFloat(0.0), OrderedFloat(1.0)]);
+    /// assert!(v[0].0.is_sign_positive());
+    /// ```
+    fn dedup_canonical(&mut self);
+}
 
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl<'a, 'b> Pow<&'a $rhs> for &'b OrderedFloat<$inner> {
-            type Output = OrderedFloat<$inner>;
-            #[inline]
-            fn pow(self, rhs: &'a $rhs) -> OrderedFloat<$inner> {
-                OrderedFloat(<$inner>::pow(self.0, *rhs))
-            }
+#[cfg(feature = "std")]
+impl<T: FloatCore> OrderedFloatVecExt for std::vec::Vec<OrderedFloat<T>> {
+    fn dedup_canonical(&mut self) {
+        self.dedup();
+        for x in self.iter_mut() {
+            x.0 = canonicalize(x.0);
         }
-    };
+    }
 }
 
-impl_ordered_float_pow! {f32, i8}
-impl_ordered_float_pow! {f32, i16}
-impl_ordered_float_pow! {f32, u8}
-impl_ordered_float_pow! {f32, u16}
-impl_ordered_float_pow! {f32, i32}
-impl_ordered_float_pow! {f64, i8}
-impl_ordered_float_pow! {f64, i16}
-impl_ordered_float_pow! {f64, u8}
-impl_ordered_float_pow! {f64, u16}
-impl_ordered_float_pow! {f64, i32}
-impl_ordered_float_pow! {f32, f32}
-impl_ordered_float_pow! {f64, f32}
-impl_ordered_float_pow! {f64, f64}
-
-macro_rules! impl_ordered_float_self_pow {
-    ($base:ty, $exp:ty) => {
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl Pow<OrderedFloat<$exp>> for OrderedFloat<$base> {
-            type Output = OrderedFloat<$base>;
-            #[inline]
-            fn pow(self, rhs: OrderedFloat<$exp>) -> OrderedFloat<$base> {
-                OrderedFloat(<$base>::pow(self.0, rhs.0))
-            }
-        }
+/// Extension methods for iterators of floats or [`OrderedFloat`]s.
+pub trait OrderedFloatIterExt: Iterator + Sized {
+    /// Collects the items into a sorted vector of [`OrderedFloat`]s with duplicates removed.
+    ///
+    /// Items are canonicalized as with [`OrderedFloatVecExt::dedup_canonical`], so `-0.0` and
+    /// `+0.0` produce a single `+0.0`, and all NaNs produce a single NaN, sorted last.
+    ///
+    /// ```
+    /// use ordered_float::{OrderedFloat, OrderedFloatIterExt};
+    ///
+    /// let keys: Vec<OrderedFloat<f64>> = vec![2.0, -0.0, 1.0, 0.0, 2.0]
+    ///     .into_iter()
+    ///     .collect_sorted_unique();
+    /// assert_eq!(keys, [0.0, 1.0, 2.0]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn collect_sorted_unique<T>(self) -> std::vec::Vec<OrderedFloat<T>>
+    where
+        T: FloatCore,
+        Self::Item: Into<OrderedFloat<T>>,
+    {
+        let mut v: std::vec::Vec<_> = self
+            .map(|x| OrderedFloat(canonicalize(x.into().0)))
+            .collect();
+        v.sort_unstable();
+        v.dedup();
+        v
+    }
 
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl<'a> Pow<&'a OrderedFloat<$exp>> for OrderedFloat<$base> {
-            type Output = OrderedFloat<$base>;
-            #[inline]
-            fn pow(self, rhs: &'a OrderedFloat<$exp>) -> OrderedFloat<$base> {
-                OrderedFloat(<$base>::pow(self.0, rhs.0))
-            }
-        }
+    /// Sums the items, returning `Err` if the running total overflows from a finite value to
+    /// infinity.
+    ///
+    /// Infinite inputs are allowed and pass through as they would with [`Sum`]; only a finite
+    /// total becoming infinite after adding a finite item is flagged.
+    ///
+    /// ```
+    /// use ordered_float::{FloatOverflow, OrderedFloat, OrderedFloatIterExt};
+    ///
+    /// assert_eq!([1.0, 2.0].iter().copied().checked_sum(), Ok(OrderedFloat(3.0)));
+    /// assert_eq!([f64::MAX, f64::MAX].iter().copied().checked_sum::<f64>(), Err(FloatOverflow));
+    /// assert_eq!(
+    ///     [f64::INFINITY, 1.0].iter().copied().checked_sum(),
+    ///     Ok(OrderedFloat(f64::INFINITY))
+    /// );
+    /// ```
+    fn checked_sum<T>(self) -> Result<OrderedFloat<T>, FloatOverflow>
+    where
+        T: FloatCore,
+        Self::Item: Into<OrderedFloat<T>>,
+    {
+        self.map(|x| x.into().0)
+            .try_fold(T::zero(), |acc, x| checked_finite(acc, x, acc + x))
+            .map(OrderedFloat)
+    }
 
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl<'a> Pow<OrderedFloat<$exp>> for &'a OrderedFloat<$base> {
-            type Output = OrderedFloat<$base>;
-            #[inline]
-            fn pow(self, rhs: OrderedFloat<$exp>) -> OrderedFloat<$base> {
-                OrderedFloat(<$base>::pow(self.0, rhs.0))
-            }
+    /// Sums the items with Neumaier's variant of Kahan compensated summation, which keeps the
+    /// rounding error of a long sum much smaller than plain [`Sum`].
+    ///
+    /// Only `FloatCore` operations are used, so this works without `std` or `libm`. If the total
+    /// is NaN or infinite, the result is the same as with [`Sum`].
+    ///
+    /// ```
+    /// use ordered_float::{OrderedFloat, OrderedFloatIterExt};
+    ///
+    /// let data = [1.0, 1e100, 1.0, -1e100];
+    /// assert_eq!(data.iter().copied().compensated_sum(), OrderedFloat(2.0));
+    /// assert_eq!(data.iter().sum::<f64>(), 0.0);
+    /// ```
+    fn compensated_sum<T>(self) -> OrderedFloat<T>
+    where
+        T: FloatCore,
+        Self::Item: Into<OrderedFloat<T>>,
+    {
+        let (sum, compensation) =
+            self.map(|x| x.into().0)
+                .fold((T::zero(), T::zero()), |(sum, c), x| {
+                    let t = sum + x;
+                    let c = if sum.abs() >= x.abs() {
+                        c + ((sum - t) + x)
+                    } else {
+                        c + ((x - t) + sum)
+                    };
+                    (t, c)
+                });
+        // Once the total is infinite or NaN, the compensation term is meaningless (often NaN).
+        if sum.is_finite() {
+            OrderedFloat(sum + compensation)
+        } else {
+            OrderedFloat(sum)
         }
+    }
 
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl<'a, 'b> Pow<&'a OrderedFloat<$exp>> for &'b OrderedFloat<$base> {
-            type Output = OrderedFloat<$base>;
-            #[inline]
-            fn pow(self, rhs: &'a OrderedFloat<$exp>) -> OrderedFloat<$base> {
-                OrderedFloat(<$base>::pow(self.0, rhs.0))
+    /// Multiplies the items, returning `Err` if the running product overflows from a finite value
+    /// to infinity.
+    ///
+    /// Infinite inputs are allowed and pass through as they would with [`Product`]; only a finite
+    /// product becoming infinite after multiplying by a finite item is flagged.
+    ///
+    /// ```
+    /// use ordered_float::{FloatOverflow, OrderedFloat, OrderedFloatIterExt};
+    ///
+    /// assert_eq!([2.0, 3.0].iter().copied().checked_product(), Ok(OrderedFloat(6.0)));
+    /// assert_eq!([1e200, 1e200].iter().copied().checked_product::<f64>(), Err(FloatOverflow));
+    /// ```
+    fn checked_product<T>(self) -> Result<OrderedFloat<T>, FloatOverflow>
+    where
+        T: FloatCore,
+        Self::Item: Into<OrderedFloat<T>>,
+    {
+        self.map(|x| x.into().0)
+            .try_fold(T::one(), |acc, x| checked_finite(acc, x, acc * x))
+            .map(OrderedFloat)
+    }
+
+    /// Returns the minimum and maximum items in a single pass, or `None` if the iterator is
+    /// empty.
+    ///
+    /// This works for iterators of [`NotNan`] or [`OrderedFloat`] (or any other [`Ord`] type).
+    /// Ties are resolved like [`Iterator::min`] and [`Iterator::max`]: the first of several
+    /// minimal items and the last of several maximal items are returned, which matters for
+    /// `-0.0` and `+0.0`. For `OrderedFloat`, NaN is the greatest value.
+    ///
+    /// ```
+    /// use ordered_float::{NotNan, OrderedFloatIterExt};
+    ///
+    /// let v = [3.0, -1.0, 2.5].map(|x| NotNan::new(x).unwrap());
+    /// let (min, max) = v.iter().copied().min_max_collect().unwrap();
+    /// assert_eq!(min, -1.0);
+    /// assert_eq!(max, 3.0);
+    /// assert_eq!(std::iter::empty::<NotNan<f64>>().min_max_collect(), None);
+    /// ```
+    fn min_max_collect(mut self) -> Option<(Self::Item, Self::Item)>
+    where
+        Self::Item: Ord + Clone,
+    {
+        let first = self.next()?;
+        Some(self.fold((first.clone(), first), |(min, max), x| {
+            if x < min {
+                (x, max)
+            } else if x >= max {
+                (min, x)
+            } else {
+                (min, max)
             }
-        }
-    };
+        }))
+    }
 }
 
-impl_ordered_float_self_pow! {f32, f32}
-impl_ordered_float_self_pow! {f64, f32}
-impl_ordered_float_self_pow! {f64, f64}
+impl<I: Iterator> OrderedFloatIterExt for I {}
 
-/// Adds a float directly.
-impl<T: FloatCore + Sum> Sum for OrderedFloat<T> {
-    fn sum<I: Iterator<Item = OrderedFloat<T>>>(iter: I) -> Self {
-        OrderedFloat(iter.map(|v| v.0).sum())
+#[inline]
+fn checked_finite<T: FloatCore>(a: T, b: T, result: T) -> Result<T, FloatOverflow> {
+    if result.is_infinite() && a.is_finite() && b.is_finite() {
+        Err(FloatOverflow)
+    } else {
+        Ok(result)
     }
 }
 
-impl<'a, T: FloatCore + Sum + 'a> Sum<&'a OrderedFloat<T>> for OrderedFloat<T> {
+/// Iterator adapters that wrap each float in [`OrderedFloat`] or [`NotNan`].
+///
+/// ```
+/// use ordered_float::{NotNan, OrderedFloat, WrapFloatsExt};
+///
+/// let v: Vec<OrderedFloat<f64>> = vec![1.0, f64::NAN].into_iter().wrap_ordered().collect();
+/// assert_eq!(v, [OrderedFloat(1.0), OrderedFloat(f64::NAN)]);
+///
+/// let v: Result<Vec<NotNan<f64>>, _> = vec![1.0, 2.0].into_iter().try_wrap_notnan().collect();
+/// assert_eq!(v.unwrap(), [1.0, 2.0]);
+/// ```
+pub trait WrapFloatsExt<T: FloatCore>: Iterator<Item = T> + Sized {
+    /// Wraps each item in an [`OrderedFloat`].
     #[inline]
-    fn sum<I: Iterator<Item = &'a OrderedFloat<T>>>(iter: I) -> Self {
-        iter.cloned().sum()
+    fn wrap_ordered(self) -> WrapOrdered<Self, T> {
+        self.map(OrderedFloat as fn(T) -> OrderedFloat<T>)
     }
-}
 
-impl<T: FloatCore + Product> Product for OrderedFloat<T> {
-    fn product<I: Iterator<Item = OrderedFloat<T>>>(iter: I) -> Self {
-        OrderedFloat(iter.map(|v| v.0).product())
+    /// Wraps each item in a [`NotNan`], yielding `Err(FloatIsNan)` for NaN items.
+    #[inline]
+    fn try_wrap_notnan(self) -> TryWrapNotNan<Self, T> {
+        self.map(NotNan::new as fn(T) -> Result<NotNan<T>, FloatIsNan>)
     }
-}
 
-impl<'a, T: FloatCore + Product + 'a> Product<&'a OrderedFloat<T>> for OrderedFloat<T> {
+    /// Wraps each finite item in a [`NotNan`], silently dropping NaN and infinite items.
+    ///
+    /// Use [`try_wrap_notnan`](Self::try_wrap_notnan) instead to treat NaN as an error.
+    ///
+    /// ```
+    /// use ordered_float::{NotNan, WrapFloatsExt};
+    ///
+    /// let data = [1.0, f64::NAN, -2.0, f64::INFINITY];
+    /// let v: Vec<NotNan<f64>> = data.iter().copied().finite_not_nan().collect();
+    /// assert_eq!(v, [1.0, -2.0]);
+    /// ```
     #[inline]
-    fn product<I: Iterator<Item = &'a OrderedFloat<T>>>(iter: I) -> Self {
-        iter.cloned().product()
+    fn finite_not_nan(self) -> FiniteNotNan<Self, T> {
+        fn finite<T: FloatCore>(x: T) -> Option<NotNan<T>> {
+            if x.is_finite() {
+                Some(NotNan(x))
+            } else {
+                None
+            }
+        }
+        self.filter_map(finite as fn(T) -> Option<NotNan<T>>)
     }
 }
 
-impl<T: FloatCore + Signed> Signed for OrderedFloat<T> {
-    #[inline]
-    fn abs(&self) -> Self {
-        OrderedFloat(self.0.abs())
-    }
+impl<T: FloatCore, I: Iterator<Item = T>> WrapFloatsExt<T> for I {}
 
-    fn abs_sub(&self, other: &Self) -> Self {
-        OrderedFloat(Signed::abs_sub(&self.0, &other.0))
-    }
+/// Iterator returned by [`WrapFloatsExt::wrap_ordered`].
+pub type WrapOrdered<I, T> = core::iter::Map<I, fn(T) -> OrderedFloat<T>>;
+
+/// Iterator returned by [`WrapFloatsExt::try_wrap_notnan`].
+pub type TryWrapNotNan<I, T> = core::iter::Map<I, fn(T) -> Result<NotNan<T>, FloatIsNan>>;
+
+/// Iterator returned by [`WrapFloatsExt::finite_not_nan`].
+pub type FiniteNotNan<I, T> = core::iter::FilterMap<I, fn(T) -> Option<NotNan<T>>>;
+
+/// Extension methods for slices of [`OrderedFloat`] or [`NotNan`].
+pub trait OrderedFloatSliceExt {
+    /// The element type of the slice.
+    type Elem;
+
+    /// Sorts the slice in descending order, without preserving the order of equal elements.
+    ///
+    /// NaN is the greatest `OrderedFloat` value, so NaNs end up at the *front*.
+    ///
+    /// ```
+    /// use ordered_float::{OrderedFloat, OrderedFloatSliceExt};
+    ///
+    /// let mut v = [OrderedFloat(1.0), OrderedFloat(f64::NAN), OrderedFloat(3.0)];
+    /// v.sort_descending();
+    /// assert_eq!(v, [OrderedFloat(f64::NAN), OrderedFloat(3.0), OrderedFloat(1.0)]);
+    /// ```
+    fn sort_descending(&mut self);
+
+    /// Returns the greatest element, or `None` for an empty slice, together with whether any
+    /// element was NaN.
+    ///
+    /// NaN is the greatest `OrderedFloat` value, so if the flag is set the maximum is NaN. The
+    /// flag reports this in the same pass, e.g. to surface data-quality problems. It is always
+    /// `false` for slices of `NotNan`.
+    ///
+    /// ```
+    /// use ordered_float::{OrderedFloat, OrderedFloatSliceExt};
+    ///
+    /// let v = [OrderedFloat(1.0), OrderedFloat(3.0)];
+    /// assert_eq!(v.max_with_nan_flag(), (Some(OrderedFloat(3.0)), false));
+    ///
+    /// let v = [OrderedFloat(1.0), OrderedFloat(f64::NAN)];
+    /// assert_eq!(v.max_with_nan_flag(), (Some(OrderedFloat(f64::NAN)), true));
+    /// ```
+    fn max_with_nan_flag(&self) -> (Option<Self::Elem>, bool);
+}
+
+impl<T: FloatCore> OrderedFloatSliceExt for [OrderedFloat<T>] {
+    type Elem = OrderedFloat<T>;
 
     #[inline]
-    fn signum(&self) -> Self {
-        OrderedFloat(self.0.signum())
-    }
-    #[inline]
-    fn is_positive(&self) -> bool {
-        self.0.is_positive()
+    fn sort_descending(&mut self) {
+        self.sort_unstable_by(|a, b| b.cmp(a));
     }
-    #[inline]
-    fn is_negative(&self) -> bool {
-        self.0.is_negative()
+
+    fn max_with_nan_flag(&self) -> (Option<OrderedFloat<T>>, bool) {
+        let max = self.iter().copied().max();
+        // NaN is the maximum whenever one is present.
+        let has_nan = max.map_or(false, |m| m.0.is_nan());
+        (max, has_nan)
     }
 }
 
-impl<T: Bounded> Bounded for OrderedFloat<T> {
+impl<T: FloatCore> OrderedFloatSliceExt for [NotNan<T>] {
+    type Elem = NotNan<T>;
+
     #[inline]
-    fn min_value() -> Self {
-        OrderedFloat(T::min_value())
+    fn sort_descending(&mut self) {
+        self.sort_unstable_by(|a, b| b.cmp(a));
     }
 
     #[inline]
-    fn max_value() -> Self {
-        OrderedFloat(T::max_value())
+    fn max_with_nan_flag(&self) -> (Option<NotNan<T>>, bool) {
+        (self.iter().copied().max(), false)
     }
 }
 
-impl<T: FromStr> FromStr for OrderedFloat<T> {
-    type Err = T::Err;
-
-    /// Convert a &str to `OrderedFloat`. Returns an error if the string fails to parse.
+/// Extension methods for [`BTreeMap`](std::collections::BTreeMap)s keyed by [`OrderedFloat`].
+#[cfg(feature = "std")]
+pub trait OrderedFloatMapExt<T, V> {
+    /// Iterates over the entries with keys in `lo..=hi`, in ascending order.
+    ///
+    /// This is `map.range(ordered_range(lo, hi))`, except that it returns an empty range rather
+    /// than panicking if `lo > hi`. See [`ordered_range`] for how NaN and signed zero bounds
+    /// behave.
     ///
     /// ```
-    /// use ordered_float::OrderedFloat;
+    /// use std::collections::BTreeMap;
+    /// use ordered_float::{OrderedFloat, OrderedFloatMapExt};
     ///
-    /// assert!("-10".parse::<OrderedFloat<f32>>().is_ok());
-    /// assert!("abc".parse::<OrderedFloat<f32>>().is_err());
-    /// assert!("NaN".parse::<OrderedFloat<f32>>().is_ok());
+    /// let mut samples = BTreeMap::new();
+    /// for (t, v) in [(0.5, 'a'), (1.0, 'b'), (1.5, 'c'), (2.5, 'd')] {
+    ///     samples.insert(OrderedFloat(t), v);
+    /// }
+    /// let in_window: Vec<char> = samples.range_float(1.0, 2.0).map(|(_, v)| *v).collect();
+    /// assert_eq!(in_window, ['b', 'c']);
     /// ```
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        T::from_str(s).map(OrderedFloat)
+    fn range_float(
+        &self,
+        lo: T,
+        hi: T,
+    ) -> std::collections::btree_map::Range<'_, OrderedFloat<T>, V>;
+}
+
+#[cfg(feature = "std")]
+impl<T: FloatCore, V> OrderedFloatMapExt<T, V> for std::collections::BTreeMap<OrderedFloat<T>, V> {
+    fn range_float(
+        &self,
+        lo: T,
+        hi: T,
+    ) -> std::collections::btree_map::Range<'_, OrderedFloat<T>, V> {
+        let (lo, hi) = (OrderedFloat(lo), OrderedFloat(hi));
+        if lo > hi {
+            // An empty half-open range, which unlike `hi..=lo` doesn't panic.
+            self.range(lo..lo)
+        } else {
+            self.range(lo..=hi)
+        }
     }
 }
 
-impl<T: Neg> Neg for OrderedFloat<T> {
-    type Output = OrderedFloat<T::Output>;
+impl<T> AsRef<T> for OrderedFloat<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
 
+impl<T> AsMut<T> for OrderedFloat<T> {
     #[inline]
-    fn neg(self) -> Self::Output {
-        OrderedFloat(-self.0)
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
     }
 }
 
-impl<'a, T> Neg for &'a OrderedFloat<T>
-where
-    &'a T: Neg,
-{
-    type Output = OrderedFloat<<&'a T as Neg>::Output>;
+impl<'a, T> From<&'a T> for &'a OrderedFloat<T> {
+    #[inline]
+    fn from(t: &'a T) -> &'a OrderedFloat<T> {
+        // Safety: OrderedFloat is #[repr(transparent)] and has no invalid values.
+        unsafe { &*(t as *const T as *const OrderedFloat<T>) }
+    }
+}
 
+impl<'a, T> From<&'a mut T> for &'a mut OrderedFloat<T> {
     #[inline]
-    fn neg(self) -> Self::Output {
-        OrderedFloat(-(&self.0))
+    fn from(t: &'a mut T) -> &'a mut OrderedFloat<T> {
+        // Safety: OrderedFloat is #[repr(transparent)] and has no invalid values.
+        unsafe { &mut *(t as *mut T as *mut OrderedFloat<T>) }
     }
 }
 
-impl<T: Zero> Zero for OrderedFloat<T> {
+impl<T: FloatCore> PartialOrd for OrderedFloat<T> {
     #[inline]
-    fn zero() -> Self {
-        OrderedFloat(T::zero())
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 
     #[inline]
-    fn is_zero(&self) -> bool {
-        self.0.is_zero()
+    fn lt(&self, other: &Self) -> bool {
+        !self.ge(other)
     }
-}
 
-impl<T: One> One for OrderedFloat<T> {
     #[inline]
-    fn one() -> Self {
-        OrderedFloat(T::one())
+    fn le(&self, other: &Self) -> bool {
+        other.ge(self)
     }
-}
 
-impl<T: NumCast> NumCast for OrderedFloat<T> {
     #[inline]
-    fn from<F: ToPrimitive>(n: F) -> Option<Self> {
-        T::from(n).map(OrderedFloat)
+    fn gt(&self, other: &Self) -> bool {
+        !other.ge(self)
+    }
+
+    #[inline]
+    fn ge(&self, other: &Self) -> bool {
+        // We consider all NaNs equal, and NaN is the largest possible
+        // value. Thus if self is NaN we always return true. Otherwise
+        // self >= other is correct. If other is also not NaN it is trivially
+        // correct, and if it is we note that nothing can be greater or
+        // equal to NaN except NaN itself, which we already handled earlier.
+        self.0.is_nan() | (self.0 >= other.0)
     }
 }
 
-macro_rules! impl_as_primitive {
-    (@ (NotNan<$T: ty>) => $(#[$cfg:meta])* impl (NotNan<$U: ty>) ) => {
-        $(#[$cfg])*
-        impl AsPrimitive<NotNan<$U>> for NotNan<$T> {
-            #[inline] fn as_(self) -> NotNan<$U> {
-                // Safety: `NotNan` guarantees that the value is not NaN.
-                unsafe {NotNan::new_unchecked(self.0 as $U) }
-            }
-        }
-    };
-    (@ ($T: ty) => $(#[$cfg:meta])* impl (NotNan<$U: ty>) ) => {
-        $(#[$cfg])*
-        impl AsPrimitive<NotNan<$U>> for $T {
-            #[inline] fn as_(self) -> NotNan<$U> { NotNan(self as $U) }
-        }
-    };
-    (@ (NotNan<$T: ty>) => $(#[$cfg:meta])* impl ($U: ty) ) => {
-        $(#[$cfg])*
-        impl AsPrimitive<$U> for NotNan<$T> {
-            #[inline] fn as_(self) -> $U { self.0 as $U }
-        }
-    };
-    (@ (OrderedFloat<$T: ty>) => $(#[$cfg:meta])* impl (OrderedFloat<$U: ty>) ) => {
-        $(#[$cfg])*
-        impl AsPrimitive<OrderedFloat<$U>> for OrderedFloat<$T> {
-            #[inline] fn as_(self) -> OrderedFloat<$U> { OrderedFloat(self.0 as $U) }
-        }
-    };
-    (@ ($T: ty) => $(#[$cfg:meta])* impl (OrderedFloat<$U: ty>) ) => {
-        $(#[$cfg])*
-        impl AsPrimitive<OrderedFloat<$U>> for $T {
-            #[inline] fn as_(self) -> OrderedFloat<$U> { OrderedFloat(self as $U) }
-        }
-    };
-    (@ (OrderedFloat<$T: ty>) => $(#[$cfg:meta])* impl ($U: ty) ) => {
-        $(#[$cfg])*
-        impl AsPrimitive<$U> for OrderedFloat<$T> {
-            #[inline] fn as_(self) -> $U { self.0 as $U }
+impl<T: FloatCore> Ord for OrderedFloat<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Specialized impls for `f32`/`f64` (e.g. based on `total_cmp`) would conflict with this
+        // one on stable Rust.
+        #[allow(clippy::comparison_chain)]
+        if self < other {
+            Ordering::Less
+        } else if self > other {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
         }
-    };
-    ($T: tt => { $( $U: tt ),* } ) => {$(
-        impl_as_primitive!(@ $T => impl $U);
-    )*};
+    }
 }
 
-impl_as_primitive!((OrderedFloat<f32>) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-impl_as_primitive!((OrderedFloat<f64>) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-
-impl_as_primitive!((NotNan<f32>) => { (NotNan<f32>), (NotNan<f64>) });
-impl_as_primitive!((NotNan<f64>) => { (NotNan<f32>), (NotNan<f64>) });
-
-impl_as_primitive!((u8) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-impl_as_primitive!((i8) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-impl_as_primitive!((u16) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-impl_as_primitive!((i16) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-impl_as_primitive!((u32) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-impl_as_primitive!((i32) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-impl_as_primitive!((u64) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-impl_as_primitive!((i64) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-impl_as_primitive!((usize) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-impl_as_primitive!((isize) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-impl_as_primitive!((f32) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-impl_as_primitive!((f64) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
-
-impl_as_primitive!((u8) => { (NotNan<f32>), (NotNan<f64>) });
-impl_as_primitive!((i8) => { (NotNan<f32>), (NotNan<f64>) });
-impl_as_primitive!((u16) => { (NotNan<f32>), (NotNan<f64>) });
-impl_as_primitive!((i16) => { (NotNan<f32>), (NotNan<f64>) });
-impl_as_primitive!((u32) => { (NotNan<f32>), (NotNan<f64>) });
-impl_as_primitive!((i32) => { (NotNan<f32>), (NotNan<f64>) });
-impl_as_primitive!((u64) => { (NotNan<f32>), (NotNan<f64>) });
-impl_as_primitive!((i64) => { (NotNan<f32>), (NotNan<f64>) });
-impl_as_primitive!((usize) => { (NotNan<f32>), (NotNan<f64>) });
-impl_as_primitive!((isize) => { (NotNan<f32>), (NotNan<f64>) });
-
-impl_as_primitive!((OrderedFloat<f32>) => { (u8), (u16), (u32), (u64), (usize), (i8), (i16), (i32), (i64), (isize), (f32), (f64) });
-impl_as_primitive!((OrderedFloat<f64>) => { (u8), (u16), (u32), (u64), (usize), (i8), (i16), (i32), (i64), (isize), (f32), (f64) });
-
-impl_as_primitive!((NotNan<f32>) => { (u8), (u16), (u32), (u64), (usize), (i8), (i16), (i32), (i64), (isize), (f32), (f64) });
-impl_as_primitive!((NotNan<f64>) => { (u8), (u16), (u32), (u64), (usize), (i8), (i16), (i32), (i64), (isize), (f32), (f64) });
-
-impl<T: FromPrimitive> FromPrimitive for OrderedFloat<T> {
-    fn from_i64(n: i64) -> Option<Self> {
-        T::from_i64(n).map(OrderedFloat)
-    }
-    fn from_u64(n: u64) -> Option<Self> {
-        T::from_u64(n).map(OrderedFloat)
-    }
-    fn from_isize(n: isize) -> Option<Self> {
-        T::from_isize(n).map(OrderedFloat)
-    }
-    fn from_i8(n: i8) -> Option<Self> {
-        T::from_i8(n).map(OrderedFloat)
-    }
-    fn from_i16(n: i16) -> Option<Self> {
-        T::from_i16(n).map(OrderedFloat)
-    }
-    fn from_i32(n: i32) -> Option<Self> {
-        T::from_i32(n).map(OrderedFloat)
-    }
-    fn from_usize(n: usize) -> Option<Self> {
-        T::from_usize(n).map(OrderedFloat)
-    }
-    fn from_u8(n: u8) -> Option<Self> {
-        T::from_u8(n).map(OrderedFloat)
-    }
-    fn from_u16(n: u16) -> Option<Self> {
-        T::from_u16(n).map(OrderedFloat)
+impl<T: FloatCore> PartialEq for OrderedFloat<T> {
+    #[inline]
+    fn eq(&self, other: &OrderedFloat<T>) -> bool {
+        if self.0.is_nan() {
+            other.0.is_nan()
+        } else {
+            self.0 == other.0
+        }
     }
-    fn from_u32(n: u32) -> Option<Self> {
-        T::from_u32(n).map(OrderedFloat)
+}
+
+/// Compares with the raw float using IEEE 754 equality, so **NaN is not equal to NaN** here,
+/// unlike `OrderedFloat(NaN) == OrderedFloat(NaN)`.
+///
+/// Use [`OrderedFloat::eq_total`] to compare with a raw float using the wrapper's own equality.
+///
+/// ```
+/// # use ordered_float::OrderedFloat;
+/// assert_ne!(OrderedFloat(f64::NAN), f64::NAN);
+/// assert!(OrderedFloat(f64::NAN).eq_total(&f64::NAN));
+/// ```
+impl<T: FloatCore> PartialEq<T> for OrderedFloat<T> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.0 == *other
     }
-    fn from_f32(n: f32) -> Option<Self> {
-        T::from_f32(n).map(OrderedFloat)
+}
+
+/// Values are hashed by writing a 64-bit key to the hasher, and two values have the same key if
+/// and only if they are equal: `-0.0` and `+0.0` share a key, as do all NaNs regardless of sign
+/// and payload, while every other value, including each subnormal, `MAX`, and the infinities,
+/// has a key of its own. Unequal values can therefore only collide through the hasher itself.
+///
+/// Hashing a slice, such as a `Vec<OrderedFloat<T>>` map key, writes the canonicalized bits of
+/// the elements to the hasher in bulk, staged through a small stack buffer rather than one call
+/// per element. No allocation is performed. Slices that are equal element-wise hash equally.
+impl<T: FloatCore> Hash for OrderedFloat<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        ordered_float_hash_bits(self.0).hash(state)
     }
-    fn from_f64(n: f64) -> Option<Self> {
-        T::from_f64(n).map(OrderedFloat)
+
+    fn hash_slice<H: Hasher>(data: &[Self], state: &mut H) {
+        hash_bits_slice(data.iter().map(|x| ordered_float_hash_bits(x.0)), state)
     }
 }
 
-impl<T: ToPrimitive> ToPrimitive for OrderedFloat<T> {
-    fn to_i64(&self) -> Option<i64> {
-        self.0.to_i64()
+// `OrderedFloat` hashes the key that `f64::total_cmp` compares on, taken after canonicalizing
+// signed zero and NaN. The canonicalized values are exactly the classes of `Ordering::Equal`
+// under `cmp`, and the key transform is a bijection, so `a.cmp(&b) == Equal` if and only if the
+// keys are equal.
+#[inline]
+fn ordered_float_hash_bits<T: FloatCore>(x: T) -> u64 {
+    if x.is_nan() {
+        total_order_key(CANONICAL_NAN_BITS)
+    } else {
+        total_order_key(total_order_bits(canonicalize_signed_zero(x)))
     }
-    fn to_u64(&self) -> Option<u64> {
-        self.0.to_u64()
+}
+
+/// The `f64` bits that [`total_order_key`] is taken from. Every `f32` and `f64` converts to
+/// `f64` exactly; other float types that can't fall back to [`raw_double_bits`].
+#[inline(always)]
+fn total_order_bits<T: FloatCore>(x: T) -> u64 {
+    match x.to_f64() {
+        Some(f) => f.to_bits(),
+        None => raw_double_bits(&x),
     }
-    fn to_isize(&self) -> Option<isize> {
-        self.0.to_isize()
+}
+
+/// Maps the bits of an `f64` to the integer that `f64::total_cmp` compares: negative values have
+/// their magnitude bits flipped, so that signed integer order matches the float order.
+#[inline(always)]
+fn total_order_key(bits: u64) -> u64 {
+    let bits = bits as i64;
+    (bits ^ (((bits >> 63) as u64) >> 1) as i64) as u64
+}
+
+/// Writes a sequence of hash bits to `state` through a fixed-size stack buffer, so that long
+/// slices are hashed with a few large `Hasher::write` calls.
+fn hash_bits_slice<H: Hasher, I: Iterator<Item = u64>>(bits: I, state: &mut H) {
+    const CHUNK_BYTES: usize = 256;
+    let mut buf = [0u8; CHUNK_BYTES];
+    let mut len = 0;
+    for b in bits {
+        buf[len..len + 8].copy_from_slice(&b.to_ne_bytes());
+        len += 8;
+        if len == CHUNK_BYTES {
+            state.write(&buf);
+            len = 0;
+        }
     }
-    fn to_i8(&self) -> Option<i8> {
-        self.0.to_i8()
+    if len > 0 {
+        state.write(&buf[..len]);
     }
-    fn to_i16(&self) -> Option<i16> {
-        self.0.to_i16()
+}
+
+impl<T: fmt::Debug> fmt::Debug for OrderedFloat<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
     }
-    fn to_i32(&self) -> Option<i32> {
-        self.0.to_i32()
+}
+
+impl<T: fmt::Display> fmt::Display for OrderedFloat<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
     }
-    fn to_usize(&self) -> Option<usize> {
-        self.0.to_usize()
+}
+
+impl<T: fmt::LowerExp> fmt::LowerExp for OrderedFloat<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
     }
-    fn to_u8(&self) -> Option<u8> {
-        self.0.to_u8()
+}
+
+impl<T: fmt::UpperExp> fmt::UpperExp for OrderedFloat<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
     }
-    fn to_u16(&self) -> Option<u16> {
-        self.0.to_u16()
+}
+
+// A generic `impl<T: FloatCore> From<OrderedFloat<T>> for T` is rejected by the orphan rules,
+// since `T` would be an uncovered type parameter. Generic code can use `into_inner` or the
+// `AsRef`/`Borrow` impls instead, and the conversions are provided for concrete types here and
+// in the feature-gated modules (e.g. `impl_half_convert`).
+impl From<OrderedFloat<f32>> for f32 {
+    #[inline]
+    fn from(f: OrderedFloat<f32>) -> f32 {
+        f.0
     }
-    fn to_u32(&self) -> Option<u32> {
-        self.0.to_u32()
+}
+
+impl From<OrderedFloat<f64>> for f64 {
+    #[inline]
+    fn from(f: OrderedFloat<f64>) -> f64 {
+        f.0
     }
-    fn to_f32(&self) -> Option<f32> {
-        self.0.to_f32()
+}
+
+impl<T> From<T> for OrderedFloat<T> {
+    #[inline]
+    fn from(val: T) -> Self {
+        OrderedFloat(val)
     }
-    fn to_f64(&self) -> Option<f64> {
-        self.0.to_f64()
+}
+
+impl From<OrderedFloat<f32>> for OrderedFloat<f64> {
+    #[inline]
+    fn from(v: OrderedFloat<f32>) -> OrderedFloat<f64> {
+        OrderedFloat(v.0 as f64)
     }
 }
 
-impl<T: FloatCore> FloatCore for OrderedFloat<T> {
-    fn nan() -> Self {
-        OrderedFloat(T::nan())
+impl OrderedFloat<f64> {
+    /// Converts this [`OrderedFloat`]`<`[`f64`]`>` to an [`OrderedFloat`]`<`[`f32`]`>` while
+    /// giving up on precision, [using `roundTiesToEven` as rounding mode, yielding `Infinity` on
+    /// overflow](https://doc.rust-lang.org/reference/expressions/operator-expr.html#semantics).
+    ///
+    /// Note: For the reverse conversion (from `OrderedFloat<f32>` to `OrderedFloat<f64>`), you
+    /// can use `.into()`.
+    #[inline]
+    pub fn as_f32(self) -> OrderedFloat<f32> {
+        OrderedFloat(self.0 as f32)
     }
-    fn infinity() -> Self {
-        OrderedFloat(T::infinity())
+
+    /// Converts to an `f32` only if no precision is lost, i.e. if converting the result back
+    /// gives the same `f64`. Returns `None` for values that would be rounded or that overflow
+    /// `f32`.
+    ///
+    /// Infinities convert to infinities. NaN converts to NaN, as `OrderedFloat` treats all NaNs
+    /// as equal; the payload is not guaranteed to survive.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// assert_eq!(OrderedFloat(1.0).to_f32_exact(), Some(1.0));
+    /// assert_eq!(OrderedFloat(0.1).to_f32_exact(), None);
+    /// assert_eq!(OrderedFloat(1e300).to_f32_exact(), None);
+    /// ```
+    #[inline]
+    pub fn to_f32_exact(self) -> Option<f32> {
+        let narrowed = self.0 as f32;
+        if OrderedFloat(narrowed as f64) == self {
+            Some(narrowed)
+        } else {
+            None
+        }
     }
-    fn neg_infinity() -> Self {
-        OrderedFloat(T::neg_infinity())
+
+    /// Interprets the value as a number of seconds and converts it to a `Duration`.
+    ///
+    /// Returns `None` for NaN and otherwise behaves like [`NotNan::to_duration`].
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn to_duration(self) -> Option<std::time::Duration> {
+        secs_to_duration(self.0)
     }
-    fn neg_zero() -> Self {
-        OrderedFloat(T::neg_zero())
+
+    /// Encodes the value as a 16-digit lowercase hex string whose lexicographic order matches
+    /// the order of `OrderedFloat`, e.g. for keys in a sorted key-value store.
+    ///
+    /// The value is canonicalized first, so `-0.0` and `+0.0` encode to the same string, as do
+    /// all NaNs. [`from_sortable_str`](Self::from_sortable_str) decodes the string again.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// let a = OrderedFloat(-1.5).sortable_string();
+    /// let b = OrderedFloat(0.25).sortable_string();
+    /// assert!(a < b);
+    /// assert_eq!(OrderedFloat::from_sortable_str(&b), Some(OrderedFloat(0.25)));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn sortable_string(self) -> std::string::String {
+        // Flipping the sign bit of the signed `total_cmp` key gives a key in unsigned order.
+        let key = total_order_key(canonicalize(self.0).to_bits()) ^ SIGN_MASK;
+        std::format!("{:016x}", key)
     }
-    fn min_value() -> Self {
-        OrderedFloat(T::min_value())
+
+    /// Decodes a string produced by `sortable_string`, which needs the `std` feature.
+    ///
+    /// Returns `None` unless `s` is exactly 16 hex digits.
+    pub fn from_sortable_str(s: &str) -> Option<Self> {
+        if s.len() != 16 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let key = u64::from_str_radix(s, 16).ok()?;
+        // `total_order_key` is its own inverse.
+        Some(OrderedFloat(f64::from_bits(total_order_key(
+            key ^ SIGN_MASK,
+        ))))
     }
-    fn min_positive_value() -> Self {
-        OrderedFloat(T::min_positive_value())
+}
+
+impl From<bool> for OrderedFloat<f32> {
+    fn from(val: bool) -> Self {
+        OrderedFloat(val as u8 as f32)
     }
-    fn max_value() -> Self {
-        OrderedFloat(T::max_value())
+}
+
+impl From<bool> for OrderedFloat<f64> {
+    fn from(val: bool) -> Self {
+        OrderedFloat(val as u8 as f64)
     }
-    fn is_nan(self) -> bool {
-        self.0.is_nan()
+}
+
+macro_rules! impl_ordered_float_from {
+    ($dst:ty, $src:ty) => {
+        impl From<$src> for OrderedFloat<$dst> {
+            fn from(val: $src) -> Self {
+                OrderedFloat(val.into())
+            }
+        }
+    };
+}
+impl_ordered_float_from! {f64, i8}
+impl_ordered_float_from! {f64, i16}
+impl_ordered_float_from! {f64, i32}
+impl_ordered_float_from! {f64, u8}
+impl_ordered_float_from! {f64, u16}
+impl_ordered_float_from! {f64, u32}
+impl_ordered_float_from! {f32, i8}
+impl_ordered_float_from! {f32, i16}
+impl_ordered_float_from! {f32, u8}
+impl_ordered_float_from! {f32, u16}
+
+impl<T> Deref for OrderedFloat<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
     }
-    fn is_infinite(self) -> bool {
-        self.0.is_infinite()
+}
+
+impl<T> DerefMut for OrderedFloat<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
     }
-    fn is_finite(self) -> bool {
+}
+
+impl<T: FloatCore> Eq for OrderedFloat<T> {}
+
+macro_rules! impl_ordered_float_binop {
+    ($imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident) => {
+        impl<T: $imp> $imp for OrderedFloat<T> {
+            type Output = OrderedFloat<T::Output>;
+
+            #[inline]
+            fn $method(self, other: Self) -> Self::Output {
+                OrderedFloat((self.0).$method(other.0))
+            }
+        }
+
+        // Work around for: https://github.com/reem/rust-ordered-float/issues/91
+        impl<'a, T: $imp + Copy> $imp<Self> for &'a OrderedFloat<T> {
+            type Output = OrderedFloat<T::Output>;
+
+            #[inline]
+            fn $method(self, other: Self) -> Self::Output {
+                OrderedFloat((self.0).$method(other.0))
+            }
+        }
+
+        impl<T: $imp> $imp<T> for OrderedFloat<T> {
+            type Output = OrderedFloat<T::Output>;
+
+            #[inline]
+            fn $method(self, other: T) -> Self::Output {
+                OrderedFloat((self.0).$method(other))
+            }
+        }
+
+        impl<'a, T> $imp<&'a T> for OrderedFloat<T>
+        where
+            T: $imp<&'a T>,
+        {
+            type Output = OrderedFloat<<T as $imp<&'a T>>::Output>;
+
+            #[inline]
+            fn $method(self, other: &'a T) -> Self::Output {
+                OrderedFloat((self.0).$method(other))
+            }
+        }
+
+        impl<'a, T> $imp<&'a Self> for OrderedFloat<T>
+        where
+            T: $imp<&'a T>,
+        {
+            type Output = OrderedFloat<<T as $imp<&'a T>>::Output>;
+
+            #[inline]
+            fn $method(self, other: &'a Self) -> Self::Output {
+                OrderedFloat((self.0).$method(&other.0))
+            }
+        }
+
+        impl<'a, T> $imp<OrderedFloat<T>> for &'a OrderedFloat<T>
+        where
+            &'a T: $imp<T>,
+        {
+            type Output = OrderedFloat<<&'a T as $imp<T>>::Output>;
+
+            #[inline]
+            fn $method(self, other: OrderedFloat<T>) -> Self::Output {
+                OrderedFloat((self.0).$method(other.0))
+            }
+        }
+
+        impl<'a, T> $imp<T> for &'a OrderedFloat<T>
+        where
+            &'a T: $imp<T>,
+        {
+            type Output = OrderedFloat<<&'a T as $imp<T>>::Output>;
+
+            #[inline]
+            fn $method(self, other: T) -> Self::Output {
+                OrderedFloat((self.0).$method(other))
+            }
+        }
+
+        impl<'a, T> $imp<&'a T> for &'a OrderedFloat<T>
+        where
+            &'a T: $imp,
+        {
+            type Output = OrderedFloat<<&'a T as $imp>::Output>;
+
+            #[inline]
+            fn $method(self, other: &'a T) -> Self::Output {
+                OrderedFloat((self.0).$method(other))
+            }
+        }
+
+        impl<T: $assign_imp> $assign_imp<T> for OrderedFloat<T> {
+            #[inline]
+            fn $assign_method(&mut self, other: T) {
+                (self.0).$assign_method(other);
+            }
+        }
+
+        impl<'a, T: $assign_imp<&'a T>> $assign_imp<&'a T> for OrderedFloat<T> {
+            #[inline]
+            fn $assign_method(&mut self, other: &'a T) {
+                (self.0).$assign_method(other);
+            }
+        }
+
+        impl<T: $assign_imp> $assign_imp for OrderedFloat<T> {
+            #[inline]
+            fn $assign_method(&mut self, other: Self) {
+                (self.0).$assign_method(other.0);
+            }
+        }
+
+        impl<'a, T: $assign_imp<&'a T>> $assign_imp<&'a Self> for OrderedFloat<T> {
+            #[inline]
+            fn $assign_method(&mut self, other: &'a Self) {
+                (self.0).$assign_method(&other.0);
+            }
+        }
+    };
+}
+
+impl_ordered_float_binop! {Add, add, AddAssign, add_assign}
+impl_ordered_float_binop! {Sub, sub, SubAssign, sub_assign}
+impl_ordered_float_binop! {Mul, mul, MulAssign, mul_assign}
+impl_ordered_float_binop! {Div, div, DivAssign, div_assign}
+impl_ordered_float_binop! {Rem, rem, RemAssign, rem_assign}
+
+/// Integer power for exponent types that `num_traits` has no `Pow` impl for. Exponents that fit
+/// in an `i32` use `powi`; larger ones fall back to `powf` on the magnitude. Converting such an
+/// exponent to a float can round an odd value to an even one, so the sign of a negative base is
+/// restored from the exponent's parity.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+fn pow_wide_int<F: Float, N: ToPrimitive>(x: F, n: N) -> F {
+    match n.to_i32() {
+        Some(n) => x.powi(n),
+        None => {
+            let n = n.to_i128().unwrap();
+            let magnitude = x.abs().powf(F::from(n).unwrap());
+            if x.is_sign_negative() && n & 1 == 1 {
+                -magnitude
+            } else {
+                magnitude
+            }
+        }
+    }
+}
+
+macro_rules! impl_ordered_float_pow {
+    ($inner:ty, $rhs:ty) => {
+        impl_ordered_float_pow! {$inner, $rhs, <$inner>::pow}
+    };
+    ($inner:ty, $rhs:ty, $pow:expr) => {
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl Pow<$rhs> for OrderedFloat<$inner> {
+            type Output = OrderedFloat<$inner>;
+            #[inline]
+            fn pow(self, rhs: $rhs) -> OrderedFloat<$inner> {
+                OrderedFloat($pow(self.0, rhs))
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl<'a> Pow<&'a $rhs> for OrderedFloat<$inner> {
+            type Output = OrderedFloat<$inner>;
+            #[inline]
+            fn pow(self, rhs: &'a $rhs) -> OrderedFloat<$inner> {
+                OrderedFloat($pow(self.0, *rhs))
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl<'a> Pow<$rhs> for &'a OrderedFloat<$inner> {
+            type Output = OrderedFloat<$inner>;
+            #[inline]
+            fn pow(self, rhs: $rhs) -> OrderedFloat<$inner> {
+                OrderedFloat($pow(self.0, rhs))
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl<'a, 'b> Pow<&'a $rhs> for &'b OrderedFloat<$inner> {
+            type Output = OrderedFloat<$inner>;
+            #[inline]
+            fn pow(self, rhs: &'a $rhs) -> OrderedFloat<$inner> {
+                OrderedFloat($pow(self.0, *rhs))
+            }
+        }
+    };
+}
+
+impl_ordered_float_pow! {f32, i8}
+impl_ordered_float_pow! {f32, i16}
+impl_ordered_float_pow! {f32, u8}
+impl_ordered_float_pow! {f32, u16}
+impl_ordered_float_pow! {f32, i32}
+impl_ordered_float_pow! {f64, i8}
+impl_ordered_float_pow! {f64, i16}
+impl_ordered_float_pow! {f64, u8}
+impl_ordered_float_pow! {f64, u16}
+impl_ordered_float_pow! {f64, i32}
+impl_ordered_float_pow! {f32, u32, pow_wide_int}
+impl_ordered_float_pow! {f64, u32, pow_wide_int}
+impl_ordered_float_pow! {f64, i64, pow_wide_int}
+impl_ordered_float_pow! {f64, u64, pow_wide_int}
+impl_ordered_float_pow! {f32, f32}
+impl_ordered_float_pow! {f64, f32}
+impl_ordered_float_pow! {f64, f64}
+
+macro_rules! impl_ordered_float_self_pow {
+    ($base:ty, $exp:ty) => {
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl Pow<OrderedFloat<$exp>> for OrderedFloat<$base> {
+            type Output = OrderedFloat<$base>;
+            #[inline]
+            fn pow(self, rhs: OrderedFloat<$exp>) -> OrderedFloat<$base> {
+                OrderedFloat(<$base>::pow(self.0, rhs.0))
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl<'a> Pow<&'a OrderedFloat<$exp>> for OrderedFloat<$base> {
+            type Output = OrderedFloat<$base>;
+            #[inline]
+            fn pow(self, rhs: &'a OrderedFloat<$exp>) -> OrderedFloat<$base> {
+                OrderedFloat(<$base>::pow(self.0, rhs.0))
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl<'a> Pow<OrderedFloat<$exp>> for &'a OrderedFloat<$base> {
+            type Output = OrderedFloat<$base>;
+            #[inline]
+            fn pow(self, rhs: OrderedFloat<$exp>) -> OrderedFloat<$base> {
+                OrderedFloat(<$base>::pow(self.0, rhs.0))
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl<'a, 'b> Pow<&'a OrderedFloat<$exp>> for &'b OrderedFloat<$base> {
+            type Output = OrderedFloat<$base>;
+            #[inline]
+            fn pow(self, rhs: &'a OrderedFloat<$exp>) -> OrderedFloat<$base> {
+                OrderedFloat(<$base>::pow(self.0, rhs.0))
+            }
+        }
+    };
+}
+
+impl_ordered_float_self_pow! {f32, f32}
+impl_ordered_float_self_pow! {f64, f32}
+impl_ordered_float_self_pow! {f64, f64}
+
+/// Adds a float directly.
+impl<T: Sum> Sum for OrderedFloat<T> {
+    fn sum<I: Iterator<Item = OrderedFloat<T>>>(iter: I) -> Self {
+        OrderedFloat(iter.map(|v| v.0).sum())
+    }
+}
+
+impl<'a, T: Sum + Clone + 'a> Sum<&'a OrderedFloat<T>> for OrderedFloat<T> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a OrderedFloat<T>>>(iter: I) -> Self {
+        iter.cloned().sum()
+    }
+}
+
+impl<T: Product> Product for OrderedFloat<T> {
+    fn product<I: Iterator<Item = OrderedFloat<T>>>(iter: I) -> Self {
+        OrderedFloat(iter.map(|v| v.0).product())
+    }
+}
+
+impl<'a, T: Product + Clone + 'a> Product<&'a OrderedFloat<T>> for OrderedFloat<T> {
+    #[inline]
+    fn product<I: Iterator<Item = &'a OrderedFloat<T>>>(iter: I) -> Self {
+        iter.cloned().product()
+    }
+}
+
+impl<T: FloatCore + Signed> Signed for OrderedFloat<T> {
+    #[inline]
+    fn abs(&self) -> Self {
+        OrderedFloat(self.0.abs())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        OrderedFloat(Signed::abs_sub(&self.0, &other.0))
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        OrderedFloat(self.0.signum())
+    }
+    #[inline]
+    fn is_positive(&self) -> bool {
+        self.0.is_positive()
+    }
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+}
+
+impl<T: Bounded> Bounded for OrderedFloat<T> {
+    #[inline]
+    fn min_value() -> Self {
+        OrderedFloat(T::min_value())
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        OrderedFloat(T::max_value())
+    }
+}
+
+impl<T: FromStr> FromStr for OrderedFloat<T> {
+    type Err = T::Err;
+
+    /// Convert a &str to `OrderedFloat`. Returns an error if the string fails to parse.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// assert!("-10".parse::<OrderedFloat<f32>>().is_ok());
+    /// assert!("abc".parse::<OrderedFloat<f32>>().is_err());
+    /// assert!("NaN".parse::<OrderedFloat<f32>>().is_ok());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        T::from_str(s).map(OrderedFloat)
+    }
+}
+
+impl<T: Neg> Neg for OrderedFloat<T> {
+    type Output = OrderedFloat<T::Output>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        OrderedFloat(-self.0)
+    }
+}
+
+impl<'a, T> Neg for &'a OrderedFloat<T>
+where
+    &'a T: Neg,
+{
+    type Output = OrderedFloat<<&'a T as Neg>::Output>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        OrderedFloat(-(&self.0))
+    }
+}
+
+impl<T: Zero> Zero for OrderedFloat<T> {
+    #[inline]
+    fn zero() -> Self {
+        OrderedFloat(T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<T: One> One for OrderedFloat<T> {
+    #[inline]
+    fn one() -> Self {
+        OrderedFloat(T::one())
+    }
+}
+
+impl<T: NumCast> NumCast for OrderedFloat<T> {
+    #[inline]
+    fn from<F: ToPrimitive>(n: F) -> Option<Self> {
+        T::from(n).map(OrderedFloat)
+    }
+}
+
+macro_rules! impl_as_primitive {
+    (@ (NotNan<$T: ty>) => $(#[$cfg:meta])* impl (NotNan<$U: ty>) ) => {
+        $(#[$cfg])*
+        impl AsPrimitive<NotNan<$U>> for NotNan<$T> {
+            #[inline] fn as_(self) -> NotNan<$U> {
+                // Safety: `NotNan` guarantees that the value is not NaN.
+                unsafe {NotNan::new_unchecked(self.0 as $U) }
+            }
+        }
+    };
+    (@ ($T: ty) => $(#[$cfg:meta])* impl (NotNan<$U: ty>) ) => {
+        $(#[$cfg])*
+        impl AsPrimitive<NotNan<$U>> for $T {
+            #[inline] fn as_(self) -> NotNan<$U> { NotNan(self as $U) }
+        }
+    };
+    (@ (NotNan<$T: ty>) => $(#[$cfg:meta])* impl ($U: ty) ) => {
+        $(#[$cfg])*
+        impl AsPrimitive<$U> for NotNan<$T> {
+            #[inline] fn as_(self) -> $U { self.0 as $U }
+        }
+    };
+    (@ (OrderedFloat<$T: ty>) => $(#[$cfg:meta])* impl (OrderedFloat<$U: ty>) ) => {
+        $(#[$cfg])*
+        impl AsPrimitive<OrderedFloat<$U>> for OrderedFloat<$T> {
+            #[inline] fn as_(self) -> OrderedFloat<$U> { OrderedFloat(self.0 as $U) }
+        }
+    };
+    (@ ($T: ty) => $(#[$cfg:meta])* impl (OrderedFloat<$U: ty>) ) => {
+        $(#[$cfg])*
+        impl AsPrimitive<OrderedFloat<$U>> for $T {
+            #[inline] fn as_(self) -> OrderedFloat<$U> { OrderedFloat(self as $U) }
+        }
+    };
+    (@ (OrderedFloat<$T: ty>) => $(#[$cfg:meta])* impl ($U: ty) ) => {
+        $(#[$cfg])*
+        impl AsPrimitive<$U> for OrderedFloat<$T> {
+            #[inline] fn as_(self) -> $U { self.0 as $U }
+        }
+    };
+    ($T: tt => { $( $U: tt ),* } ) => {$(
+        impl_as_primitive!(@ $T => impl $U);
+    )*};
+}
+
+impl_as_primitive!((OrderedFloat<f32>) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+impl_as_primitive!((OrderedFloat<f64>) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+
+impl_as_primitive!((NotNan<f32>) => { (NotNan<f32>), (NotNan<f64>) });
+impl_as_primitive!((NotNan<f64>) => { (NotNan<f32>), (NotNan<f64>) });
+
+impl_as_primitive!((u8) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+impl_as_primitive!((i8) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+impl_as_primitive!((u16) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+impl_as_primitive!((i16) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+impl_as_primitive!((u32) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+impl_as_primitive!((i32) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+impl_as_primitive!((u64) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+impl_as_primitive!((i64) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+impl_as_primitive!((usize) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+impl_as_primitive!((isize) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+impl_as_primitive!((f32) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+impl_as_primitive!((f64) => { (OrderedFloat<f32>), (OrderedFloat<f64>) });
+
+impl_as_primitive!((u8) => { (NotNan<f32>), (NotNan<f64>) });
+impl_as_primitive!((i8) => { (NotNan<f32>), (NotNan<f64>) });
+impl_as_primitive!((u16) => { (NotNan<f32>), (NotNan<f64>) });
+impl_as_primitive!((i16) => { (NotNan<f32>), (NotNan<f64>) });
+impl_as_primitive!((u32) => { (NotNan<f32>), (NotNan<f64>) });
+impl_as_primitive!((i32) => { (NotNan<f32>), (NotNan<f64>) });
+impl_as_primitive!((u64) => { (NotNan<f32>), (NotNan<f64>) });
+impl_as_primitive!((i64) => { (NotNan<f32>), (NotNan<f64>) });
+impl_as_primitive!((usize) => { (NotNan<f32>), (NotNan<f64>) });
+impl_as_primitive!((isize) => { (NotNan<f32>), (NotNan<f64>) });
+
+impl_as_primitive!((OrderedFloat<f32>) => { (u8), (u16), (u32), (u64), (usize), (i8), (i16), (i32), (i64), (isize), (f32), (f64) });
+impl_as_primitive!((OrderedFloat<f64>) => { (u8), (u16), (u32), (u64), (usize), (i8), (i16), (i32), (i64), (isize), (f32), (f64) });
+
+impl_as_primitive!((NotNan<f32>) => { (u8), (u16), (u32), (u64), (usize), (i8), (i16), (i32), (i64), (isize), (f32), (f64) });
+impl_as_primitive!((NotNan<f64>) => { (u8), (u16), (u32), (u64), (usize), (i8), (i16), (i32), (i64), (isize), (f32), (f64) });
+
+impl<T: FromPrimitive> FromPrimitive for OrderedFloat<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        T::from_i64(n).map(OrderedFloat)
+    }
+    fn from_u64(n: u64) -> Option<Self> {
+        T::from_u64(n).map(OrderedFloat)
+    }
+    fn from_isize(n: isize) -> Option<Self> {
+        T::from_isize(n).map(OrderedFloat)
+    }
+    fn from_i8(n: i8) -> Option<Self> {
+        T::from_i8(n).map(OrderedFloat)
+    }
+    fn from_i16(n: i16) -> Option<Self> {
+        T::from_i16(n).map(OrderedFloat)
+    }
+    fn from_i32(n: i32) -> Option<Self> {
+        T::from_i32(n).map(OrderedFloat)
+    }
+    fn from_usize(n: usize) -> Option<Self> {
+        T::from_usize(n).map(OrderedFloat)
+    }
+    fn from_u8(n: u8) -> Option<Self> {
+        T::from_u8(n).map(OrderedFloat)
+    }
+    fn from_u16(n: u16) -> Option<Self> {
+        T::from_u16(n).map(OrderedFloat)
+    }
+    fn from_u32(n: u32) -> Option<Self> {
+        T::from_u32(n).map(OrderedFloat)
+    }
+    fn from_f32(n: f32) -> Option<Self> {
+        T::from_f32(n).map(OrderedFloat)
+    }
+    fn from_f64(n: f64) -> Option<Self> {
+        T::from_f64(n).map(OrderedFloat)
+    }
+}
+
+impl<T: ToPrimitive> ToPrimitive for OrderedFloat<T> {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+    fn to_isize(&self) -> Option<isize> {
+        self.0.to_isize()
+    }
+    fn to_i8(&self) -> Option<i8> {
+        self.0.to_i8()
+    }
+    fn to_i16(&self) -> Option<i16> {
+        self.0.to_i16()
+    }
+    fn to_i32(&self) -> Option<i32> {
+        self.0.to_i32()
+    }
+    fn to_usize(&self) -> Option<usize> {
+        self.0.to_usize()
+    }
+    fn to_u8(&self) -> Option<u8> {
+        self.0.to_u8()
+    }
+    fn to_u16(&self) -> Option<u16> {
+        self.0.to_u16()
+    }
+    fn to_u32(&self) -> Option<u32> {
+        self.0.to_u32()
+    }
+    fn to_f32(&self) -> Option<f32> {
+        self.0.to_f32()
+    }
+    fn to_f64(&self) -> Option<f64> {
+        self.0.to_f64()
+    }
+}
+
+impl<T: FloatCore> FloatCore for OrderedFloat<T> {
+    fn nan() -> Self {
+        OrderedFloat(T::nan())
+    }
+    fn infinity() -> Self {
+        OrderedFloat(T::infinity())
+    }
+    fn neg_infinity() -> Self {
+        OrderedFloat(T::neg_infinity())
+    }
+    fn neg_zero() -> Self {
+        OrderedFloat(T::neg_zero())
+    }
+    fn min_value() -> Self {
+        OrderedFloat(T::min_value())
+    }
+    fn min_positive_value() -> Self {
+        OrderedFloat(T::min_positive_value())
+    }
+    fn max_value() -> Self {
+        OrderedFloat(T::max_value())
+    }
+    fn is_nan(self) -> bool {
+        self.0.is_nan()
+    }
+    fn is_infinite(self) -> bool {
+        self.0.is_infinite()
+    }
+    fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+    fn is_normal(self) -> bool {
+        self.0.is_normal()
+    }
+    fn classify(self) -> FpCategory {
+        self.0.classify()
+    }
+    fn floor(self) -> Self {
+        OrderedFloat(self.0.floor())
+    }
+    fn ceil(self) -> Self {
+        OrderedFloat(self.0.ceil())
+    }
+    fn round(self) -> Self {
+        OrderedFloat(self.0.round())
+    }
+    fn trunc(self) -> Self {
+        OrderedFloat(self.0.trunc())
+    }
+    fn fract(self) -> Self {
+        OrderedFloat(self.0.fract())
+    }
+    fn abs(self) -> Self {
+        OrderedFloat(self.0.abs())
+    }
+    fn signum(self) -> Self {
+        OrderedFloat(self.0.signum())
+    }
+    fn is_sign_positive(self) -> bool {
+        self.0.is_sign_positive()
+    }
+    fn is_sign_negative(self) -> bool {
+        self.0.is_sign_negative()
+    }
+    fn recip(self) -> Self {
+        OrderedFloat(self.0.recip())
+    }
+    fn powi(self, n: i32) -> Self {
+        OrderedFloat(self.0.powi(n))
+    }
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.0.integer_decode()
+    }
+    fn epsilon() -> Self {
+        OrderedFloat(T::epsilon())
+    }
+    fn to_degrees(self) -> Self {
+        OrderedFloat(self.0.to_degrees())
+    }
+    fn to_radians(self) -> Self {
+        OrderedFloat(self.0.to_radians())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Float + FloatCore> OrderedFloat<T> {
+    /// Rounds to `decimals` decimal places, with halfway cases rounded away from zero. A negative
+    /// `decimals` rounds to tens, hundreds, and so on.
+    ///
+    /// The scaling is done in floating point, so the result is the nearest representable value
+    /// rather than an exact decimal, which makes this suited to display rather than accounting.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// assert_eq!(OrderedFloat(3.14159).round_to(2), OrderedFloat(3.14));
+    /// assert_eq!(OrderedFloat(12345.0).round_to(-2), OrderedFloat(12300.0));
+    /// ```
+    #[inline]
+    pub fn round_to(self, decimals: i32) -> Self {
+        OrderedFloat(round_to(self.0, decimals))
+    }
+
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`) in radians, like
+    /// [`f64::atan2`].
+    ///
+    /// The result is NaN only if either operand is NaN. `atan2(±0, +0)` is `±0` and
+    /// `atan2(±0, -0)` is `±π`.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// let y = OrderedFloat(1.0f64);
+    /// assert_eq!(y.atan2(OrderedFloat(1.0)), OrderedFloat(std::f64::consts::FRAC_PI_4));
+    /// ```
+    #[inline]
+    pub fn atan2(self, other: Self) -> Self {
+        OrderedFloat(Float::atan2(self.0, other.0))
+    }
+
+    /// Computes `sqrt(self² + other²)` without intermediate overflow or underflow, like
+    /// [`f64::hypot`].
+    ///
+    /// An infinite operand gives `+inf`, even if the other is NaN; otherwise a NaN operand gives
+    /// NaN.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// assert_eq!(OrderedFloat(3.0f64).hypot(OrderedFloat(4.0)), OrderedFloat(5.0));
+    /// ```
+    #[inline]
+    pub fn hypot(self, other: Self) -> Self {
+        OrderedFloat(Float::hypot(self.0, other.0))
+    }
+
+    /// Computes `(self * a) + b` with a single rounding, like [`f64::mul_add`].
+    ///
+    /// The result is NaN if any operand is NaN, if one factor is zero and the other infinite,
+    /// or if the product is an infinity and `b` is the opposite infinity.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// let x = OrderedFloat(2.0f64);
+    /// assert_eq!(x.mul_add(OrderedFloat(3.0), OrderedFloat(1.0)), OrderedFloat(7.0));
+    /// ```
+    #[inline]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        OrderedFloat(Float::mul_add(self.0, a.0, b.0))
+    }
+
+    /// Raises `self` to a floating point power, returning `fallback` instead if the result is
+    /// NaN, such as for a negative base and a fractional exponent, or a NaN operand.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// let x = OrderedFloat(-2.0f64);
+    /// assert_eq!(x.powf_or(OrderedFloat(0.5), OrderedFloat(0.0)), OrderedFloat(0.0));
+    /// assert_eq!(x.powf_or(OrderedFloat(2.0), OrderedFloat(0.0)), OrderedFloat(4.0));
+    /// ```
+    #[inline]
+    pub fn powf_or(self, n: Self, fallback: Self) -> Self {
+        let result = Float::powf(self.0, n.0);
+        if FloatCore::is_nan(result) {
+            fallback
+        } else {
+            OrderedFloat(result)
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Float + FloatCore> Float for OrderedFloat<T> {
+    fn nan() -> Self {
+        OrderedFloat(<T as Float>::nan())
+    }
+    fn infinity() -> Self {
+        OrderedFloat(<T as Float>::infinity())
+    }
+    fn neg_infinity() -> Self {
+        OrderedFloat(<T as Float>::neg_infinity())
+    }
+    fn neg_zero() -> Self {
+        OrderedFloat(<T as Float>::neg_zero())
+    }
+    fn min_value() -> Self {
+        OrderedFloat(<T as Float>::min_value())
+    }
+    fn min_positive_value() -> Self {
+        OrderedFloat(<T as Float>::min_positive_value())
+    }
+    fn max_value() -> Self {
+        OrderedFloat(<T as Float>::max_value())
+    }
+    fn is_nan(self) -> bool {
+        Float::is_nan(self.0)
+    }
+    fn is_infinite(self) -> bool {
+        Float::is_infinite(self.0)
+    }
+    fn is_finite(self) -> bool {
+        Float::is_finite(self.0)
+    }
+    fn is_normal(self) -> bool {
+        Float::is_normal(self.0)
+    }
+    fn classify(self) -> FpCategory {
+        Float::classify(self.0)
+    }
+    fn floor(self) -> Self {
+        OrderedFloat(Float::floor(self.0))
+    }
+    fn ceil(self) -> Self {
+        OrderedFloat(Float::ceil(self.0))
+    }
+    fn round(self) -> Self {
+        OrderedFloat(Float::round(self.0))
+    }
+    fn trunc(self) -> Self {
+        OrderedFloat(Float::trunc(self.0))
+    }
+    fn fract(self) -> Self {
+        OrderedFloat(Float::fract(self.0))
+    }
+    fn abs(self) -> Self {
+        OrderedFloat(Float::abs(self.0))
+    }
+    fn signum(self) -> Self {
+        OrderedFloat(Float::signum(self.0))
+    }
+    fn is_sign_positive(self) -> bool {
+        Float::is_sign_positive(self.0)
+    }
+    fn is_sign_negative(self) -> bool {
+        Float::is_sign_negative(self.0)
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        OrderedFloat(self.0.mul_add(a.0, b.0))
+    }
+    fn recip(self) -> Self {
+        OrderedFloat(Float::recip(self.0))
+    }
+    fn powi(self, n: i32) -> Self {
+        OrderedFloat(Float::powi(self.0, n))
+    }
+    fn powf(self, n: Self) -> Self {
+        OrderedFloat(self.0.powf(n.0))
+    }
+    fn sqrt(self) -> Self {
+        OrderedFloat(self.0.sqrt())
+    }
+    fn exp(self) -> Self {
+        OrderedFloat(self.0.exp())
+    }
+    fn exp2(self) -> Self {
+        OrderedFloat(self.0.exp2())
+    }
+    fn ln(self) -> Self {
+        OrderedFloat(self.0.ln())
+    }
+    fn log(self, base: Self) -> Self {
+        OrderedFloat(self.0.log(base.0))
+    }
+    fn log2(self) -> Self {
+        OrderedFloat(self.0.log2())
+    }
+    fn log10(self) -> Self {
+        OrderedFloat(self.0.log10())
+    }
+    fn max(self, other: Self) -> Self {
+        OrderedFloat(Float::max(self.0, other.0))
+    }
+    fn min(self, other: Self) -> Self {
+        OrderedFloat(Float::min(self.0, other.0))
+    }
+    fn abs_sub(self, other: Self) -> Self {
+        OrderedFloat(self.0.abs_sub(other.0))
+    }
+    fn cbrt(self) -> Self {
+        OrderedFloat(self.0.cbrt())
+    }
+    fn hypot(self, other: Self) -> Self {
+        OrderedFloat(self.0.hypot(other.0))
+    }
+    fn sin(self) -> Self {
+        OrderedFloat(self.0.sin())
+    }
+    fn cos(self) -> Self {
+        OrderedFloat(self.0.cos())
+    }
+    fn tan(self) -> Self {
+        OrderedFloat(self.0.tan())
+    }
+    fn asin(self) -> Self {
+        OrderedFloat(self.0.asin())
+    }
+    fn acos(self) -> Self {
+        OrderedFloat(self.0.acos())
+    }
+    fn atan(self) -> Self {
+        OrderedFloat(self.0.atan())
+    }
+    fn atan2(self, other: Self) -> Self {
+        OrderedFloat(self.0.atan2(other.0))
+    }
+    fn sin_cos(self) -> (Self, Self) {
+        let (a, b) = self.0.sin_cos();
+        (OrderedFloat(a), OrderedFloat(b))
+    }
+    fn exp_m1(self) -> Self {
+        OrderedFloat(self.0.exp_m1())
+    }
+    fn ln_1p(self) -> Self {
+        OrderedFloat(self.0.ln_1p())
+    }
+    fn sinh(self) -> Self {
+        OrderedFloat(self.0.sinh())
+    }
+    fn cosh(self) -> Self {
+        OrderedFloat(self.0.cosh())
+    }
+    fn tanh(self) -> Self {
+        OrderedFloat(self.0.tanh())
+    }
+    fn asinh(self) -> Self {
+        OrderedFloat(self.0.asinh())
+    }
+    fn acosh(self) -> Self {
+        OrderedFloat(self.0.acosh())
+    }
+    fn atanh(self) -> Self {
+        OrderedFloat(self.0.atanh())
+    }
+    fn integer_decode(self) -> (u64, i16, i8) {
+        Float::integer_decode(self.0)
+    }
+    fn epsilon() -> Self {
+        OrderedFloat(<T as Float>::epsilon())
+    }
+    fn to_degrees(self) -> Self {
+        OrderedFloat(Float::to_degrees(self.0))
+    }
+    fn to_radians(self) -> Self {
+        OrderedFloat(Float::to_radians(self.0))
+    }
+}
+
+impl<T: FloatCore + Num> Num for OrderedFloat<T> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(str, radix).map(OrderedFloat)
+    }
+}
+
+/// A wrapper around floats providing an implementation of `Eq`, `Ord` and `Hash`.
+///
+/// A NaN value cannot be stored in this type.
+///
+/// ```
+/// use ordered_float::NotNan;
+///
+/// let mut v = [
+///     NotNan::new(2.0).unwrap(),
+///     NotNan::new(1.0).unwrap(),
+/// ];
+/// v.sort();
+/// assert_eq!(v, [1.0, 2.0]);
+/// ```
+///
+/// Because `NotNan` implements `Ord` and `Eq`, it can be used as a key in a `HashSet`,
+/// `HashMap`, `BTreeMap`, or `BTreeSet` (unlike the primitive `f32` or `f64` types):
+///
+/// ```
+/// # use ordered_float::NotNan;
+/// # use std::collections::HashSet;
+/// let mut s: HashSet<NotNan<f32>> = HashSet::new();
+/// let key = NotNan::new(1.0).unwrap();
+/// s.insert(key);
+/// assert!(s.contains(&key));
+/// ```
+///
+/// `-0.0` and `+0.0` are still considered equal. This different sign may show up in printing,
+/// or when dividing by zero (the sign of the zero becomes the sign of the resulting infinity).
+/// Therefore, `NotNan` may be unsuitable for use as a key in interning and memoization
+/// applications which require equal results from equal inputs, unless signed zeros make no
+/// difference or are canonicalized before insertion.
+///
+/// Arithmetic on NotNan values will panic if it produces a NaN value:
+///
+/// ```should_panic
+/// # use ordered_float::NotNan;
+/// let a = NotNan::new(std::f32::INFINITY).unwrap();
+/// let b = NotNan::new(std::f32::NEG_INFINITY).unwrap();
+///
+/// // This will panic:
+/// let c = a + b;
+/// ```
+///
+/// This applies to every operator form with a `NotNan` on the left, whether the right operand is a
+/// `NotNan`, a raw float, or a reference to either: the result is always a validated `NotNan`.
+/// The same goes for `%`, which panics for example when dividing by zero:
+///
+/// ```should_panic
+/// # use ordered_float::NotNan;
+/// let mut a = NotNan::new(1.0).unwrap();
+/// let zero = NotNan::new(0.0).unwrap();
+///
+/// // This will panic:
+/// a %= &zero;
+/// ```
+///
+/// The [`Default`] value is `+0.0` for the primitive floats. For `f32` and `f64`,
+/// [`default_const`](NotNan::default_const) gives the same value in `const` contexts.
+///
+/// # Representation
+///
+/// `NotNan` has `#[repr(transparent)]`, so it is sound to use
+/// [transmute](core::mem::transmute) or pointer casts to convert between any type `T` and
+/// `NotNan<T>`, as long as this does not create a NaN value.
+/// However, consider using [`bytemuck`] as a safe alternative if possible.
+///
+#[cfg_attr(
+    not(feature = "bytemuck"),
+    doc = "[`bytemuck`]: https://docs.rs/bytemuck/1/"
+)]
+#[derive(PartialOrd, PartialEq, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct NotNan<T>(T);
+
+impl<T: FloatCore> NotNan<T> {
+    /// Create a `NotNan` value.
+    ///
+    /// Returns `Err` if `val` is NaN
+    pub fn new(val: T) -> Result<Self, FloatIsNan> {
+        match val {
+            ref val if val.is_nan() => Err(FloatIsNan),
+            val => Ok(NotNan(val)),
+        }
+    }
+
+    /// Formats the inner value with a caller-supplied function, e.g. one that applies digit
+    /// grouping or locale conventions.
+    ///
+    /// See [`OrderedFloat::format_with`].
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn format_with(&self, f: impl Fn(T) -> std::string::String) -> std::string::String {
+        f(self.0)
+    }
+
+    /// Formats the value in the given radix. See [`OrderedFloat::to_string_radix`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in `2..=36`.
+    #[cfg(feature = "std")]
+    pub fn to_string_radix(self, radix: u32) -> std::string::String {
+        to_string_radix(self.0, radix)
+    }
+
+    /// Returns `true` if `self` and `other` are close, using the same rule as Python's
+    /// `math.isclose`. See [`OrderedFloat::is_close`].
+    #[inline]
+    pub fn is_close(self, other: Self, rel_tol: T, abs_tol: T) -> bool {
+        is_close(self.0, other.0, rel_tol, abs_tol)
+    }
+
+    /// Returns the floating point category of the number. See [`f64::classify`].
+    ///
+    /// This and the predicates below are inherent so that they don't need `FloatCore` in scope.
+    #[inline]
+    pub fn classify(self) -> FpCategory {
+        self.0.classify()
+    }
+
+    /// Always returns `false`, since a `NotNan` never holds NaN.
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        false
+    }
+
+    /// Returns `true` if the number is neither infinite nor NaN.
+    #[inline]
+    pub fn is_finite(self) -> bool {
         self.0.is_finite()
     }
-    fn is_normal(self) -> bool {
-        self.0.is_normal()
+
+    /// Returns `true` if the number is positive or negative infinity.
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        self.0.is_infinite()
+    }
+
+    /// Returns `true` if the number is neither zero, infinite, subnormal, nor NaN.
+    #[inline]
+    pub fn is_normal(self) -> bool {
+        self.0.is_normal()
+    }
+
+    /// Returns `true` if the number is [subnormal].
+    ///
+    /// [subnormal]: https://en.wikipedia.org/wiki/Denormal_number
+    #[inline]
+    pub fn is_subnormal(self) -> bool {
+        self.0.classify() == FpCategory::Subnormal
+    }
+
+    /// Returns `true` if the sign bit is clear, including for `+0.0`.
+    #[inline]
+    pub fn is_sign_positive(self) -> bool {
+        self.0.is_sign_positive()
+    }
+
+    /// Returns `true` if the sign bit is set, including for `-0.0`.
+    #[inline]
+    pub fn is_sign_negative(self) -> bool {
+        self.0.is_sign_negative()
+    }
+
+    /// Returns the canonical representative of this value: `-0.0` becomes `+0.0`.
+    ///
+    /// Values that compare equal are bit-identical after canonicalization. See
+    /// [`OrderedFloat::to_canonical`].
+    #[inline]
+    pub fn to_canonical(self) -> Self {
+        NotNan(canonicalize_signed_zero(self.0))
+    }
+
+    /// Negates the value, returning `+0.0` for either zero.
+    ///
+    /// Plain negation (`-x`, for both `NotNan` and `&NotNan`) follows IEEE 754 and turns `+0.0`
+    /// into `-0.0`. Use this when the result should be canonical, e.g. because its bits are used
+    /// as a key. It takes `&self`, so it can be called on owned values and references alike.
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// let zero = NotNan::new(0.0f64).unwrap();
+    /// assert!((-&zero).is_sign_negative());
+    /// assert!(zero.neg_canonical().is_sign_positive());
+    /// assert_eq!(NotNan::new(1.5f64).unwrap().neg_canonical(), -1.5);
+    /// ```
+    #[inline]
+    pub fn neg_canonical(&self) -> Self {
+        NotNan(canonicalize_signed_zero(-self.0))
+    }
+
+    /// Clamps the value into `[0.0, 1.0]`, as the `saturate` function in shading languages does.
+    /// `-0.0` maps to `+0.0`.
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// assert_eq!(NotNan::new(1.5).unwrap().saturate(), 1.0);
+    /// assert_eq!(NotNan::new(-0.5).unwrap().saturate(), 0.0);
+    /// ```
+    #[inline]
+    pub fn saturate(self) -> Self {
+        NotNan(saturate(self.0))
+    }
+
+    /// Returns `1.0` for positive values, `-1.0` for negative values, and `+0.0` for both zeros.
+    /// See [`OrderedFloat::signum_zero`].
+    #[inline]
+    pub fn signum_zero(self) -> Self {
+        NotNan(signum_zero(self.0))
+    }
+
+    /// Returns the lesser of two values using IEEE 754-2019 `minimum` semantics, under which
+    /// `-0.0` is less than `+0.0`. [`Ord::min`] considers the two zeros equal.
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// let zero = NotNan::new(0.0).unwrap();
+    /// let neg_zero = NotNan::new(-0.0).unwrap();
+    /// assert!(zero.minimum(neg_zero).is_sign_negative());
+    /// ```
+    #[inline]
+    pub fn minimum(self, other: Self) -> Self {
+        NotNan(ieee_minimum(self.0, other.0))
+    }
+
+    /// Returns the greater of two values using IEEE 754-2019 `maximum` semantics, under which
+    /// `+0.0` is greater than `-0.0`. [`Ord::max`] considers the two zeros equal.
+    #[inline]
+    pub fn maximum(self, other: Self) -> Self {
+        NotNan(ieee_maximum(self.0, other.0))
+    }
+
+    /// Converts a boxed float into a boxed `NotNan` without reallocating.
+    ///
+    /// Returns `Err` if the value is NaN.
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// let b: Box<NotNan<f64>> = NotNan::try_from_boxed(Box::new(1.5)).unwrap();
+    /// assert_eq!(*b, 1.5);
+    /// assert!(NotNan::try_from_boxed(Box::new(f64::NAN)).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn try_from_boxed(b: std::boxed::Box<T>) -> Result<std::boxed::Box<Self>, FloatIsNan> {
+        if b.is_nan() {
+            return Err(FloatIsNan);
+        }
+        // Safety: NotNan is #[repr(transparent)] and the value was checked above.
+        Ok(unsafe { std::boxed::Box::from_raw(std::boxed::Box::into_raw(b) as *mut Self) })
+    }
+
+    /// Converts a boxed `NotNan` into a boxed float without reallocating.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn into_boxed_inner(b: std::boxed::Box<Self>) -> std::boxed::Box<T> {
+        // Safety: NotNan is #[repr(transparent)].
+        unsafe { std::boxed::Box::from_raw(std::boxed::Box::into_raw(b) as *mut T) }
+    }
+
+    /// Converts a shared slice of floats into a shared slice of `NotNan` without copying.
+    ///
+    /// Returns `Err` if any element is NaN.
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    /// use std::sync::Arc;
+    ///
+    /// let a: Arc<[f64]> = Arc::from([2.0, 1.0]);
+    /// let b: Arc<[NotNan<f64>]> = NotNan::try_from_arc_slice(a).unwrap();
+    /// assert_eq!(b.iter().max().map(|x| x.into_inner()), Some(2.0));
+    /// assert!(NotNan::try_from_arc_slice(Arc::from([1.0, f64::NAN])).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn try_from_arc_slice(
+        a: std::sync::Arc<[T]>,
+    ) -> Result<std::sync::Arc<[Self]>, FloatIsNan> {
+        if a.iter().any(|x| x.is_nan()) {
+            return Err(FloatIsNan);
+        }
+        // Safety: NotNan is #[repr(transparent)], every element was checked above, and the
+        // contents can't change while shared.
+        Ok(unsafe { std::sync::Arc::from_raw(std::sync::Arc::into_raw(a) as *const [Self]) })
+    }
+
+    /// Converts a shared slice of `NotNan` into a shared slice of floats without copying.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn into_arc_slice_inner(a: std::sync::Arc<[Self]>) -> std::sync::Arc<[T]> {
+        // Safety: NotNan is #[repr(transparent)].
+        unsafe { std::sync::Arc::from_raw(std::sync::Arc::into_raw(a) as *const [T]) }
+    }
+
+    /// Calculates the least nonnegative remainder of `self (mod rhs)`, like the primitive
+    /// `rem_euclid` method.
+    ///
+    /// Returns `Err` if the result is NaN, which happens when `rhs` is zero or `self` is infinite.
+    ///
+    /// ```
+    /// # use ordered_float::{FloatIsNan, NotNan};
+    /// let a = NotNan::new(-7.0).unwrap();
+    /// assert_eq!(a.checked_rem_euclid(NotNan::new(4.0).unwrap()), Ok(NotNan::new(1.0).unwrap()));
+    /// assert_eq!(a.checked_rem_euclid(NotNan::new(0.0).unwrap()), Err(FloatIsNan));
+    /// ```
+    #[inline]
+    pub fn checked_rem_euclid(self, rhs: Self) -> Result<Self, FloatIsNan> {
+        let r = self.0 % rhs.0;
+        NotNan::new(if r < T::zero() { r + rhs.0.abs() } else { r })
+    }
+
+    /// Calculates the least nonnegative remainder of `self (mod rhs)` for integer-valued
+    /// operands. See [`OrderedFloat::rem_euclid_int`].
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// let a = NotNan::new(-7.0).unwrap();
+    /// assert_eq!(a.rem_euclid_int(NotNan::new(4.0).unwrap()), Some(NotNan::new(1.0).unwrap()));
+    /// assert_eq!(a.rem_euclid_int(NotNan::new(0.5).unwrap()), None);
+    /// ```
+    #[inline]
+    pub fn rem_euclid_int(self, rhs: Self) -> Option<Self> {
+        rem_euclid_int(self.0, rhs.0).map(NotNan)
+    }
+
+    /// Multiplies by `factor`, returning `Err` if the product is NaN.
+    ///
+    /// This is the same as `self * factor`, except that a NaN result (from `0.0 * inf` or a NaN
+    /// `factor`) is reported as an error instead of a panic.
+    ///
+    /// ```
+    /// # use ordered_float::{FloatIsNan, NotNan};
+    /// let gain = NotNan::new(0.5).unwrap();
+    /// assert_eq!(gain.scale(3.0), Ok(NotNan::new(1.5).unwrap()));
+    /// assert_eq!(NotNan::new(0.0).unwrap().scale(f64::INFINITY), Err(FloatIsNan));
+    /// ```
+    #[inline]
+    pub fn scale(self, factor: T) -> Result<Self, FloatIsNan> {
+        NotNan::new(self.0 * factor)
+    }
+
+    /// Multiplies by `factor` without checking the product for NaN.
+    ///
+    /// # Safety
+    ///
+    /// Behaviour is undefined if the product is NaN, i.e. if `factor` is NaN, or if one of
+    /// `self` and `factor` is zero and the other is infinite.
+    #[inline]
+    pub unsafe fn scale_unchecked(self, factor: T) -> Self {
+        NotNan::new_unchecked(self.0 * factor)
+    }
+
+    /// Linearly interpolates between `self` and `other`, computing `self + (other - self) * t`.
+    ///
+    /// For finite endpoints, `t = 0` gives `self`. Values of `t` outside `[0, 1]` extrapolate
+    /// along the same line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is NaN, like the arithmetic operators on `NotNan`. This happens when:
+    ///
+    /// - `t` is NaN,
+    /// - `t` is zero and either endpoint is infinite,
+    /// - `self` and `other` are the same infinity,
+    /// - `t` is infinite and `self == other`, or
+    /// - `self` is infinite and `(other - self) * t` is the opposite infinity, e.g. for `self =
+    ///   -inf`, `other = inf` and a positive `t`.
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// let a = NotNan::new(1.0).unwrap();
+    /// let b = NotNan::new(3.0).unwrap();
+    /// assert_eq!(a.lerp(b, 0.5), 2.0);
+    /// assert_eq!(a.lerp(b, -1.0), -1.0);
+    /// ```
+    #[inline]
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        NotNan::new(self.0 + (other.0 - self.0) * t).expect("Interpolation resulted in NaN")
+    }
+
+    /// Borrow the inner value mutably through a guard that checks for NaN when it is dropped.
+    ///
+    /// The new value is written back only if it is not NaN; otherwise dropping the guard panics
+    /// and `self` keeps its previous value. If the guard is dropped with a NaN while the thread
+    /// is already panicking, it doesn't panic again, which would abort the process (this check
+    /// needs the `std` feature).
+    ///
+    /// ```
+    /// # use ordered_float::NotNan;
+    /// let mut x = NotNan::new(1.0).unwrap();
+    /// *x.guard_mut() += 1.0;
+    /// assert_eq!(x, 2.0);
+    /// ```
+    #[inline]
+    pub fn guard_mut(&mut self) -> NotNanGuard<'_, T> {
+        NotNanGuard {
+            value: self.0,
+            target: self,
+            error: None,
+        }
+    }
+
+    /// Like [`guard_mut`](Self::guard_mut), but instead of panicking on a NaN the guard stores
+    /// `Err(FloatIsNan)` in `error` when it is dropped. `self` keeps its previous value in that
+    /// case.
+    ///
+    /// ```
+    /// # use ordered_float::NotNan;
+    /// let mut x = NotNan::new(1.0).unwrap();
+    /// let mut res = Ok(());
+    /// *x.try_guard_mut(&mut res) *= f64::NAN;
+    /// assert!(res.is_err());
+    /// assert_eq!(x, 1.0);
+    /// ```
+    #[inline]
+    pub fn try_guard_mut<'a>(
+        &'a mut self,
+        error: &'a mut Result<(), FloatIsNan>,
+    ) -> NotNanGuard<'a, T> {
+        NotNanGuard {
+            value: self.0,
+            target: self,
+            error: Some(error),
+        }
+    }
+}
+
+impl<T> NotNan<T> {
+    /// Get the value out.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Create a `NotNan` value from a value that is guaranteed to not be NaN
+    ///
+    /// # Safety
+    ///
+    /// Behaviour is undefined if `val` is NaN
+    #[inline]
+    pub const unsafe fn new_unchecked(val: T) -> Self {
+        NotNan(val)
+    }
+
+    /// Create a `NotNan` value from a value that is guaranteed to not be NaN
+    ///
+    /// # Safety
+    ///
+    /// Behaviour is undefined if `val` is NaN
+    #[deprecated(
+        since = "2.5.0",
+        note = "Please use the new_unchecked function instead."
+    )]
+    #[inline]
+    pub const unsafe fn unchecked_new(val: T) -> Self {
+        Self::new_unchecked(val)
+    }
+}
+
+/// Creates a [`NotNan`] from a constant expression, failing to compile if the value is NaN.
+///
+/// This is a safe alternative to [`NotNan::new_unchecked`] for constants and statics. The
+/// argument must itself be a constant expression, since it is checked at compile time.
+///
+/// `not_nan!(value)` creates a `NotNan<f64>`. For another float type, name it first, as in
+/// `not_nan!(f32, value)`: the value is checked at that type, so an expression that only becomes
+/// NaN when evaluated in `f32` is rejected too.
+///
+/// ```
+/// use ordered_float::{not_nan, NotNan};
+///
+/// const HALF: NotNan<f64> = not_nan!(0.5);
+/// const QUARTER: NotNan<f32> = not_nan!(f32, 0.25);
+/// static LIMITS: [NotNan<f64>; 2] = [not_nan!(f64::NEG_INFINITY), not_nan!(1.0e3)];
+/// assert_eq!(HALF + LIMITS[1], 1000.5);
+/// assert_eq!(QUARTER, 0.25);
+/// ```
+///
+/// A NaN argument is a compile error:
+///
+/// ```compile_fail
+/// use ordered_float::{not_nan, NotNan};
+///
+/// const NAN: NotNan<f64> = not_nan!(f64::NAN);
+/// ```
+///
+/// This includes values that are only NaN at the requested type, such as `1e30 * 1e30 * 0.0`,
+/// which overflows to `inf * 0.0` in `f32`:
+///
+/// ```compile_fail
+/// use ordered_float::{not_nan, NotNan};
+///
+/// const NAN: NotNan<f32> = not_nan!(f32, 1e30 * 1e30 * 0.0);
+/// ```
+///
+/// Without a type the value is an `f64`, so it can't be used as a `NotNan<f32>`:
+///
+/// ```compile_fail
+/// use ordered_float::{not_nan, NotNan};
+///
+/// const NAN: NotNan<f32> = not_nan!(1e30 * 1e30 * 0.0);
+/// ```
+#[macro_export]
+macro_rules! not_nan {
+    ($value:expr) => {
+        $crate::not_nan!(f64, $value)
+    };
+    ($float:ty, $value:expr) => {{
+        // Bind the value at its final type before checking it, so that it can't be checked as
+        // one float type and then stored as another.
+        const NOT_NAN_VALUE: $float = $value;
+        // NaN is the only value not equal to itself. Float comparisons are allowed in `const`
+        // items on all supported Rust versions, unlike `is_nan` in `const fn`.
+        #[allow(clippy::eq_op)]
+        const _: () = ::core::assert!(NOT_NAN_VALUE == NOT_NAN_VALUE, "not_nan! argument is NaN");
+        #[allow(unused_unsafe)]
+        let value = unsafe { $crate::NotNan::<$float>::new_unchecked(NOT_NAN_VALUE) };
+        value
+    }};
+}
+
+impl<T: FloatCore> AsRef<T> for NotNan<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl Borrow<f32> for NotNan<f32> {
+    #[inline]
+    fn borrow(&self) -> &f32 {
+        &self.0
+    }
+}
+
+impl Borrow<f64> for NotNan<f64> {
+    #[inline]
+    fn borrow(&self) -> &f64 {
+        &self.0
+    }
+}
+
+#[allow(clippy::derive_ord_xor_partial_ord)]
+impl<T: FloatCore> Ord for NotNan<T> {
+    fn cmp(&self, other: &NotNan<T>) -> Ordering {
+        // Can't use unreachable_unchecked because unsafe code can't depend on FloatCore impl.
+        // https://github.com/reem/rust-ordered-float/issues/150
+        self.partial_cmp(other)
+            .expect("partial_cmp failed for non-NaN value")
+    }
+}
+
+/// A `NotNan` hashes exactly like an `OrderedFloat` holding the same value, including `-0.0`
+/// hashing like `+0.0`, so the two can key a shared cache (e.g. through a common key type that
+/// hashes either). This also holds for slices, which are hashed in bulk as for [`OrderedFloat`].
+impl<T: FloatCore> Hash for NotNan<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        not_nan_hash_bits(self.0).hash(state)
+    }
+
+    fn hash_slice<H: Hasher>(data: &[Self], state: &mut H) {
+        hash_bits_slice(data.iter().map(|x| not_nan_hash_bits(x.0)), state)
+    }
+}
+
+#[inline]
+fn not_nan_hash_bits<T: FloatCore>(x: T) -> u64 {
+    // Only zeros need canonicalizing, so avoid the floating-point add in
+    // `canonicalize_signed_zero` for the common non-zero case.
+    // This must stay equal to `ordered_float_hash_bits` for non-NaN values, so that `NotNan` and
+    // `OrderedFloat` holding equal values hash the same.
+    if x == T::zero() {
+        total_order_key(total_order_bits(T::zero()))
+    } else {
+        total_order_key(total_order_bits(x))
+    }
+}
+
+/// Wraps `f` as a map or set key in canonical form: `-0.0` becomes `+0.0` and every NaN becomes
+/// `T::nan()`.
+///
+/// `OrderedFloat` already treats `-0.0` and `+0.0` as the same key, and all NaNs as the same key,
+/// so maps such as `HashMap`, `BTreeMap` or `indexmap::IndexMap` merge them. The map keeps
+/// whichever bit pattern was inserted first, though, so a key read back from the map may be `-0.0`
+/// or a NaN with an unusual payload. Building keys with `canonical_key` makes the stored key
+/// independent of insertion order, which is what interning or deduplicating by key needs.
+///
+/// ```
+/// use ordered_float::canonical_key;
+/// use std::collections::HashMap;
+///
+/// // The same holds for `indexmap::IndexMap`, which relies on the same `Hash` and `Eq`.
+/// let mut map = HashMap::new();
+/// map.insert(canonical_key(-0.0f64), "negative");
+/// map.insert(canonical_key(0.0f64), "positive");
+/// assert_eq!(map.len(), 1);
+///
+/// let (key, value) = map.iter().next().unwrap();
+/// assert!(key.0.is_sign_positive());
+/// assert_eq!(*value, "positive");
+///
+/// map.insert(canonical_key(f64::from_bits(0x7ff8_0000_0000_0001)), "nan");
+/// assert!(map.contains_key(&canonical_key(-f64::NAN)));
+/// ```
+#[inline]
+pub fn canonical_key<T: FloatCore>(f: T) -> OrderedFloat<T> {
+    OrderedFloat(f).to_canonical()
+}
+
+/// Returns the inclusive range `OrderedFloat(lo)..=OrderedFloat(hi)`, for example to pass to
+/// [`BTreeMap::range`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html#method.range).
+///
+/// Bounds follow `OrderedFloat`'s ordering: NaN is greater than every other value, so a NaN `hi`
+/// includes all keys from `lo` up, NaN keys included, and a NaN `lo` only matches NaN keys.
+/// `-0.0` and `+0.0` are equal, so either zero includes both. As with any range, `BTreeMap::range`
+/// panics if `lo > hi`; `OrderedFloatMapExt::range_float` (with the `std` feature) returns an
+/// empty range instead.
+///
+/// ```
+/// use ordered_float::{ordered_range, OrderedFloat};
+///
+/// let r = ordered_range(1.0, 2.0);
+/// assert!(r.contains(&OrderedFloat(1.0)));
+/// assert!(r.contains(&OrderedFloat(2.0)));
+/// assert!(!r.contains(&OrderedFloat(f64::NAN)));
+/// assert!(ordered_range(1.0, f64::NAN).contains(&OrderedFloat(f64::NAN)));
+/// ```
+#[inline]
+pub fn ordered_range<T: FloatCore>(lo: T, hi: T) -> RangeInclusive<OrderedFloat<T>> {
+    OrderedFloat(lo)..=OrderedFloat(hi)
+}
+
+/// Checks that the `Eq`, `Ord`, and `Hash` implementations of [`OrderedFloat<T>`] and
+/// [`NotNan<T>`] agree with each other for a set of special values of `T`, including signed
+/// zeros, infinities, the extreme finite values, and NaN.
+///
+/// For every pair of sampled values `a` and `b` this asserts that `a == b` implies
+/// `hash(a) == hash(b)`, that `a.cmp(&b) == Equal` holds exactly when `a == b`, and that
+/// `partial_cmp` agrees with `cmp`. This is meant to be called from the tests of a crate that
+/// implements [`FloatCore`] for its own type.
+///
+/// # Panics
+///
+/// Panics with a description of the failing pair if any check fails.
+///
+/// ```
+/// ordered_float::verify_float_consistency::<f32>();
+/// ordered_float::verify_float_consistency::<f64>();
+/// ```
+#[cfg(feature = "std")]
+pub fn verify_float_consistency<T: FloatCore + fmt::Debug>() {
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash_of<H: Hash>(x: &H) -> u64 {
+        let mut state = DefaultHasher::new();
+        x.hash(&mut state);
+        state.finish()
+    }
+
+    fn check<K: Ord + Hash + fmt::Debug>(a: &K, b: &K) {
+        let eq = a == b;
+        assert_eq!(
+            a.cmp(b) == Ordering::Equal,
+            eq,
+            "cmp and eq disagree for {:?} and {:?}",
+            a,
+            b
+        );
+        assert_eq!(
+            a.partial_cmp(b),
+            Some(a.cmp(b)),
+            "partial_cmp and cmp disagree for {:?} and {:?}",
+            a,
+            b
+        );
+        if eq {
+            assert_eq!(
+                hash_of(a),
+                hash_of(b),
+                "equal values {:?} and {:?} hash differently",
+                a,
+                b
+            );
+        }
+    }
+
+    let one = T::one();
+    let samples = [
+        T::zero(),
+        T::neg_zero(),
+        one,
+        -one,
+        one + one,
+        T::epsilon(),
+        T::min_positive_value(),
+        -T::min_positive_value(),
+        T::max_value(),
+        T::min_value(),
+        T::infinity(),
+        T::neg_infinity(),
+        T::nan(),
+        -T::nan(),
+    ];
+    for &a in &samples {
+        for &b in &samples {
+            check(&OrderedFloat(a), &OrderedFloat(b));
+            if let (Ok(a), Ok(b)) = (NotNan::new(a), NotNan::new(b)) {
+                check(&a, &b);
+            }
+        }
     }
-    fn classify(self) -> FpCategory {
-        self.0.classify()
+}
+
+impl<T: fmt::Debug> fmt::Debug for NotNan<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: FloatCore + fmt::Display> fmt::Display for NotNan<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl NotNan<f64> {
+    /// Converts this [`NotNan`]`<`[`f64`]`>` to a [`NotNan`]`<`[`f32`]`>` while giving up on
+    /// precision, [using `roundTiesToEven` as rounding mode, yielding `Infinity` on
+    /// overflow](https://doc.rust-lang.org/reference/expressions/operator-expr.html#semantics).
+    ///
+    /// Note: For the reverse conversion (from `NotNan<f32>` to `NotNan<f64>`), you can use
+    /// `.into()`.
+    pub fn as_f32(self) -> NotNan<f32> {
+        // This is not destroying invariants, as it is a pure rounding operation. The only two special
+        // cases are where f32 would be overflowing, then the operation yields Infinity, or where
+        // the input is already NaN, in which case the invariant is already broken elsewhere.
+        NotNan(self.0 as f32)
+    }
+
+    /// Converts to an `f32` only if no precision is lost, i.e. if converting the result back
+    /// gives the same `f64`. Returns `None` for values that would be rounded or that overflow
+    /// `f32`. Infinities convert to infinities.
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// assert_eq!(NotNan::new(1.0).unwrap().to_f32_exact(), Some(1.0));
+    /// assert_eq!(NotNan::new(0.1).unwrap().to_f32_exact(), None);
+    /// assert_eq!(NotNan::new(1e300).unwrap().to_f32_exact(), None);
+    /// ```
+    #[inline]
+    pub fn to_f32_exact(self) -> Option<f32> {
+        let narrowed = self.0 as f32;
+        if narrowed as f64 == self.0 {
+            Some(narrowed)
+        } else {
+            None
+        }
+    }
+
+    /// Interprets the value as a number of seconds and converts it to a `Duration`.
+    ///
+    /// Returns `None` if the value is negative, infinite, or too large for a `Duration`. `-0.0`
+    /// converts to a zero `Duration`. The result is rounded to whole nanoseconds.
+    ///
+    /// Below 2<sup>23</sup> seconds (about 97 days), consecutive `f64` values are at most
+    /// 2<sup>-30</sup> seconds apart, so every nanosecond has a distinct `f64`. From 2<sup>23</sup>
+    /// seconds on they are 2<sup>-29</sup> seconds (about 1.9 ns) or more apart, and nearby
+    /// nanoseconds share a value.
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    /// use std::time::Duration;
+    ///
+    /// let secs = NotNan::new(1.5).unwrap();
+    /// assert_eq!(secs.to_duration(), Some(Duration::from_millis(1500)));
+    /// assert_eq!(NotNan::new(-1.0).unwrap().to_duration(), None);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn to_duration(self) -> Option<std::time::Duration> {
+        secs_to_duration(self.0)
+    }
+
+    /// Converts a `Duration` to a number of seconds.
+    ///
+    /// The result is never NaN. It is rounded to the nearest `f64`, which distinguishes every
+    /// nanosecond only below the bound given in [`NotNan::to_duration`].
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_duration(d: std::time::Duration) -> Self {
+        NotNan(d.as_secs_f64())
+    }
+
+    /// Returns the index of the bucket containing `self` in a histogram of `n` buckets of equal
+    /// `width`, the first starting at `lo`.
+    ///
+    /// Values below `lo` go into the first bucket and values past the last bucket into the last
+    /// one. Returns `None` if `width` is not positive, if `n` is zero, or if the bucket can't be
+    /// determined because `self` and `lo` are the same infinity.
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// let (lo, width) = (NotNan::new(0.0).unwrap(), NotNan::new(0.5).unwrap());
+    /// assert_eq!(NotNan::new(1.2).unwrap().bucket_index(lo, width, 4), Some(2));
+    /// assert_eq!(NotNan::new(-3.0).unwrap().bucket_index(lo, width, 4), Some(0));
+    /// assert_eq!(NotNan::new(9.0).unwrap().bucket_index(lo, width, 4), Some(3));
+    /// assert_eq!(NotNan::new(1.2).unwrap().bucket_index(lo, -width, 4), None);
+    /// ```
+    pub fn bucket_index(self, lo: Self, width: Self, n: usize) -> Option<usize> {
+        if width.0 <= 0.0 || n == 0 {
+            return None;
+        }
+        let q = (self.0 - lo.0) / width.0;
+        if q.is_nan() {
+            None
+        } else if q < 0.0 {
+            Some(0)
+        } else {
+            // `as` truncates, which is `floor` for non-negative values, and saturates on overflow.
+            Some((q as usize).min(n - 1))
+        }
+    }
+}
+
+// Formats `x` in `radix` without an exponent. The value is `mantissa * 2^exp` exactly, so the
+// digits are computed with a little arbitrary-precision arithmetic on the mantissa: the integer
+// part is exact, and the fraction has the fewest digits that still identify the value.
+#[cfg(feature = "std")]
+fn to_string_radix<T: FloatCore>(x: T, radix: u32) -> std::string::String {
+    use std::vec::Vec;
+
+    assert!(
+        (2..=36).contains(&radix),
+        "to_string_radix: radix must be in 2..=36, got {}",
+        radix
+    );
+    let mut out = std::string::String::new();
+    if x.is_nan() {
+        out.push_str("NaN");
+        return out;
+    }
+    if x.is_sign_negative() {
+        out.push('-');
+    }
+    if x.is_infinite() {
+        out.push_str("inf");
+        return out;
+    }
+
+    // Little-endian 32-bit limbs.
+    fn mul_small(n: &mut Vec<u32>, m: u32) {
+        let mut carry = 0u64;
+        for limb in n.iter_mut() {
+            let v = (*limb as u64) * (m as u64) + carry;
+            *limb = v as u32;
+            carry = v >> 32;
+        }
+        if carry != 0 {
+            n.push(carry as u32);
+        }
+    }
+    fn divmod_small(n: &mut Vec<u32>, d: u32) -> u32 {
+        let mut rem = 0u64;
+        for limb in n.iter_mut().rev() {
+            let v = (rem << 32) | (*limb as u64);
+            *limb = (v / (d as u64)) as u32;
+            rem = v % (d as u64);
+        }
+        while n.last() == Some(&0) {
+            n.pop();
+        }
+        rem as u32
+    }
+    fn shl(m: u64, shift: usize) -> Vec<u32> {
+        let mut n = std::vec![0u32; shift / 32];
+        let wide = (m as u128) << (shift % 32);
+        n.extend((0..3).map(|i| (wide >> (32 * i)) as u32));
+        while n.last() == Some(&0) {
+            n.pop();
+        }
+        n
+    }
+    fn add(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+        let mut carry = 0u64;
+        for i in 0..a.len().max(b.len()) {
+            let v = a.get(i).copied().unwrap_or(0) as u64
+                + b.get(i).copied().unwrap_or(0) as u64
+                + carry;
+            sum.push(v as u32);
+            carry = v >> 32;
+        }
+        if carry != 0 {
+            sum.push(carry as u32);
+        }
+        sum
+    }
+    // Both operands have no leading zero limbs.
+    fn cmp(a: &[u32], b: &[u32]) -> Ordering {
+        a.len()
+            .cmp(&b.len())
+            .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+    }
+    let (mantissa, exp, _) = x.integer_decode();
+    let (mut int, frac_bits) = if exp >= 0 {
+        (shl(mantissa, exp as usize), 0)
+    } else {
+        let k = -(exp as i32) as usize;
+        let int = if k < 64 { mantissa >> k } else { 0 };
+        (shl(int, 0), k)
+    };
+
+    // Digit values, most significant first.
+    let mut digits = Vec::new();
+    while !int.is_empty() {
+        digits.push(divmod_small(&mut int, radix));
+    }
+    if digits.is_empty() {
+        digits.push(0);
+    }
+    digits.reverse();
+    let mut int_len = digits.len();
+
+    if frac_bits > 0 {
+        let mask = if frac_bits < 64 {
+            (1u64 << frac_bits) - 1
+        } else {
+            u64::MAX
+        };
+        // After `p` fraction digits, the rest of the value is `frac / 2^frac_bits` units of the
+        // last digit, and half the spacing of neighbouring floats is `2^frac_bits / 2` units of
+        // `2^frac_bits`, i.e. `scale / 2` with `scale = radix^p` in the same terms. Generation
+        // stops at the first `p` where truncating or rounding up stays within that half spacing,
+        // so the output is the shortest that identifies the value. The spacing below a power of
+        // two is half as wide.
+        let mut frac = shl(mantissa & mask, 0);
+        let one = shl(1, frac_bits);
+        let lower_factor = if mantissa.is_power_of_two() { 4 } else { 2 };
+        let mut scale = std::vec![1u32];
+        let (limb, bit) = (frac_bits / 32, frac_bits % 32);
+        while !frac.is_empty() {
+            mul_small(&mut frac, radix);
+            mul_small(&mut scale, radix);
+            let above = |i: usize| frac.get(i).copied().unwrap_or(0) as u64;
+            digits.push(((above(limb) | above(limb + 1) << 32) >> bit) as u32);
+            frac.truncate(limb + 1);
+            if let Some(last) = frac.get_mut(limb) {
+                *last &= (1u32 << bit).wrapping_sub(1);
+            }
+            while frac.last() == Some(&0) {
+                frac.pop();
+            }
+
+            let mut low = frac.clone();
+            mul_small(&mut low, lower_factor);
+            let can_truncate = cmp(&low, &scale) == Ordering::Less;
+            let mut twice = frac.clone();
+            mul_small(&mut twice, 2);
+            // `2 * (one - frac) < scale`, rearranged to avoid subtracting.
+            let mut one_twice = one.clone();
+            mul_small(&mut one_twice, 2);
+            let can_round_up = cmp(&one_twice, &add(&scale, &twice)) == Ordering::Less;
+            if can_truncate || can_round_up {
+                // Ties go away from zero.
+                let round_up =
+                    can_round_up && (!can_truncate || cmp(&twice, &one) != Ordering::Less);
+                if round_up {
+                    let mut carry = true;
+                    for d in digits.iter_mut().rev() {
+                        if *d + 1 == radix {
+                            *d = 0;
+                        } else {
+                            *d += 1;
+                            carry = false;
+                            break;
+                        }
+                    }
+                    if carry {
+                        digits.insert(0, 1);
+                        int_len += 1;
+                    }
+                }
+                break;
+            }
+        }
+    }
+    while digits.len() > int_len && digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    let digit = |d: &u32| core::char::from_digit(*d, radix).unwrap();
+    out.extend(digits[..int_len].iter().map(digit));
+    if digits.len() > int_len {
+        out.push('.');
+        out.extend(digits[int_len..].iter().map(digit));
+    }
+    out
+}
+
+/// `Duration::try_from_secs_f64` needs Rust 1.66, so check the range by hand.
+#[cfg(feature = "std")]
+fn secs_to_duration(secs: f64) -> Option<std::time::Duration> {
+    // 2^64: the first value whose whole seconds no longer fit in a `u64`.
+    const MAX: f64 = 18446744073709551616.0;
+    if (0.0..MAX).contains(&secs) {
+        Some(std::time::Duration::from_secs_f64(secs))
+    } else {
+        None
+    }
+}
+
+macro_rules! impl_not_nan_bits {
+    ($f:ident, $bits:ty) => {
+        impl NotNan<$f> {
+            /// Raw transmutation to the underlying bit representation, as with the primitive
+            /// `to_bits` method.
+            #[inline]
+            pub fn to_bits(self) -> $bits {
+                self.0.to_bits()
+            }
+
+            /// Raw transmutation from a bit representation, as with the primitive `from_bits`
+            /// function.
+            ///
+            /// Returns `Err` if `bits` is a NaN bit pattern.
+            #[inline]
+            pub fn from_bits(bits: $bits) -> Result<Self, FloatIsNan> {
+                NotNan::new(<$f>::from_bits(bits))
+            }
+        }
+    };
+}
+
+impl_not_nan_bits! {f32, u32}
+impl_not_nan_bits! {f64, u64}
+
+macro_rules! impl_float_bytes {
+    ($f:ident, $n:literal, $($to:ident, $from:ident, $order:literal);*) => {
+        impl OrderedFloat<$f> {
+            $(
+                #[doc = concat!("Returns the value as a byte array in ", $order, " byte order, as with")]
+                #[doc = concat!("the primitive `", stringify!($to), "` method.")]
+                #[inline]
+                pub fn $to(self) -> [u8; $n] {
+                    self.0.$to()
+                }
+
+                #[doc = concat!("Creates a value from a byte array in ", $order, " byte order, as with")]
+                #[doc = concat!("the primitive `", stringify!($from), "` function. NaN patterns are accepted.")]
+                #[inline]
+                pub fn $from(bytes: [u8; $n]) -> Self {
+                    OrderedFloat(<$f>::$from(bytes))
+                }
+            )*
+        }
+
+        impl NotNan<$f> {
+            $(
+                #[doc = concat!("Returns the value as a byte array in ", $order, " byte order, as with")]
+                #[doc = concat!("the primitive `", stringify!($to), "` method.")]
+                #[inline]
+                pub fn $to(self) -> [u8; $n] {
+                    self.0.$to()
+                }
+
+                #[doc = concat!("Creates a value from a byte array in ", $order, " byte order, as with")]
+                #[doc = concat!("the primitive `", stringify!($from), "` function.")]
+                ///
+                /// Returns `Err` if the bytes are a NaN pattern.
+                #[inline]
+                pub fn $from(bytes: [u8; $n]) -> Result<Self, FloatIsNan> {
+                    NotNan::new(<$f>::$from(bytes))
+                }
+            )*
+        }
+    };
+}
+
+impl_float_bytes! {f32, 4,
+    to_le_bytes, from_le_bytes, "little-endian";
+    to_be_bytes, from_be_bytes, "big-endian";
+    to_ne_bytes, from_ne_bytes, "native"
+}
+impl_float_bytes! {f64, 8,
+    to_le_bytes, from_le_bytes, "little-endian";
+    to_be_bytes, from_be_bytes, "big-endian";
+    to_ne_bytes, from_ne_bytes, "native"
+}
+
+impl From<NotNan<f32>> for f32 {
+    #[inline]
+    fn from(value: NotNan<f32>) -> Self {
+        value.0
+    }
+}
+
+impl From<NotNan<f64>> for f64 {
+    #[inline]
+    fn from(value: NotNan<f64>) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<f32> for NotNan<f32> {
+    type Error = FloatIsNan;
+    #[inline]
+    fn try_from(v: f32) -> Result<Self, Self::Error> {
+        NotNan::new(v)
+    }
+}
+
+impl TryFrom<f64> for NotNan<f64> {
+    type Error = FloatIsNan;
+    #[inline]
+    fn try_from(v: f64) -> Result<Self, Self::Error> {
+        NotNan::new(v)
+    }
+}
+
+/// Narrows an `f64` into a `NotNan<f32>`.
+///
+/// Only NaN is rejected. Values whose magnitude is too large for `f32` become `±inf`, and values
+/// too small become `±0.0` or subnormals, exactly as with `v as f32`.
+impl TryFrom<f64> for NotNan<f32> {
+    type Error = FloatIsNan;
+    #[inline]
+    fn try_from(v: f64) -> Result<Self, Self::Error> {
+        NotNan::new(v as f32)
+    }
+}
+
+macro_rules! impl_from_int_primitive {
+    ($primitive:ty, $inner:ty) => {
+        impl From<$primitive> for NotNan<$inner> {
+            fn from(source: $primitive) -> Self {
+                // the primitives with which this macro will be called cannot hold a value that
+                // f64::from would convert to NaN, so this does not hurt invariants
+                NotNan(<$inner as From<$primitive>>::from(source))
+            }
+        }
+    };
+}
+
+impl_from_int_primitive!(i8, f64);
+impl_from_int_primitive!(i16, f64);
+impl_from_int_primitive!(i32, f64);
+impl_from_int_primitive!(u8, f64);
+impl_from_int_primitive!(u16, f64);
+impl_from_int_primitive!(u32, f64);
+
+impl_from_int_primitive!(i8, f32);
+impl_from_int_primitive!(i16, f32);
+impl_from_int_primitive!(u8, f32);
+impl_from_int_primitive!(u16, f32);
+
+impl From<NotNan<f32>> for NotNan<f64> {
+    #[inline]
+    fn from(v: NotNan<f32>) -> NotNan<f64> {
+        unsafe { NotNan::new_unchecked(v.0 as f64) }
+    }
+}
+
+impl<T: FloatCore> Deref for NotNan<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A scoped mutable borrow of the value inside a [`NotNan`], created by
+/// [`NotNan::guard_mut`] or [`NotNan::try_guard_mut`].
+///
+/// The guard works on a copy of the value and writes it back on drop if it is not NaN, so the
+/// `NotNan` never holds a NaN, even while unwinding.
+pub struct NotNanGuard<'a, T: FloatCore> {
+    target: &'a mut NotNan<T>,
+    value: T,
+    error: Option<&'a mut Result<(), FloatIsNan>>,
+}
+
+impl<'a, T: FloatCore> Deref for NotNanGuard<'a, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<'a, T: FloatCore> DerefMut for NotNanGuard<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<'a, T: FloatCore> Drop for NotNanGuard<'a, T> {
+    fn drop(&mut self) {
+        if !self.value.is_nan() {
+            self.target.0 = self.value;
+            return;
+        }
+        match self.error.as_mut() {
+            Some(error) => **error = Err(FloatIsNan),
+            None => {
+                // A second panic during unwinding would abort the process. The target keeps its
+                // previous value either way.
+                #[cfg(feature = "std")]
+                if std::thread::panicking() {
+                    return;
+                }
+                panic!("NotNanGuard dropped with a NaN value")
+            }
+        }
+    }
+}
+
+impl<'a, T: FloatCore + fmt::Debug> fmt::Debug for NotNanGuard<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: FloatCore + PartialEq> Eq for NotNan<T> {}
+
+impl<T: FloatCore> PartialEq<T> for NotNan<T> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Equality with an [`OrderedFloat`] holding the same value. `-0.0` equals `+0.0`, and a NaN
+/// `OrderedFloat` never equals a `NotNan`.
+impl<T: FloatCore> PartialEq<OrderedFloat<T>> for NotNan<T> {
+    #[inline]
+    fn eq(&self, other: &OrderedFloat<T>) -> bool {
+        OrderedFloat(self.0) == *other
+    }
+}
+
+/// Equality with a [`NotNan`] holding the same value. `-0.0` equals `+0.0`, and a NaN
+/// `OrderedFloat` never equals a `NotNan`.
+impl<T: FloatCore> PartialEq<NotNan<T>> for OrderedFloat<T> {
+    #[inline]
+    fn eq(&self, other: &NotNan<T>) -> bool {
+        *self == OrderedFloat(other.0)
     }
-    fn floor(self) -> Self {
-        OrderedFloat(self.0.floor())
+}
+
+/// Orders by the total order of [`OrderedFloat`], so a `NotNan` is less than a NaN
+/// `OrderedFloat`.
+impl<T: FloatCore> PartialOrd<OrderedFloat<T>> for NotNan<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &OrderedFloat<T>) -> Option<Ordering> {
+        Some(OrderedFloat(self.0).cmp(other))
     }
-    fn ceil(self) -> Self {
-        OrderedFloat(self.0.ceil())
+}
+
+/// Orders by the total order of `OrderedFloat`, so a NaN `OrderedFloat` is greater than every
+/// [`NotNan`].
+impl<T: FloatCore> PartialOrd<NotNan<T>> for OrderedFloat<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &NotNan<T>) -> Option<Ordering> {
+        Some(self.cmp(&OrderedFloat(other.0)))
     }
-    fn round(self) -> Self {
-        OrderedFloat(self.0.round())
+}
+
+/// Adds a float directly.
+///
+/// Panics if the provided value is NaN or the computation results in NaN
+impl<T: FloatCore> Add<T> for NotNan<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: T) -> Self {
+        NotNan::new(self.0 + other).expect("Addition resulted in NaN")
     }
-    fn trunc(self) -> Self {
-        OrderedFloat(self.0.trunc())
+}
+
+/// Adds a float directly.
+///
+/// Panics if the provided value is NaN.
+impl<T: FloatCore + Sum> Sum for NotNan<T> {
+    fn sum<I: Iterator<Item = NotNan<T>>>(iter: I) -> Self {
+        NotNan::new(iter.map(|v| v.0).sum()).expect("Sum resulted in NaN")
     }
-    fn fract(self) -> Self {
-        OrderedFloat(self.0.fract())
+}
+
+impl<'a, T: FloatCore + Sum + 'a> Sum<&'a NotNan<T>> for NotNan<T> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a NotNan<T>>>(iter: I) -> Self {
+        iter.cloned().sum()
     }
-    fn abs(self) -> Self {
-        OrderedFloat(self.0.abs())
+}
+
+/// Sums without panicking, returning `Err` as soon as the running total becomes NaN.
+///
+/// ```
+/// use ordered_float::{FloatIsNan, NotNan};
+///
+/// let v = [NotNan::new(f64::INFINITY).unwrap(), NotNan::new(f64::NEG_INFINITY).unwrap()];
+/// assert_eq!(v.iter().copied().sum::<Result<NotNan<f64>, _>>(), Err(FloatIsNan));
+/// ```
+impl<T: FloatCore> Sum<NotNan<T>> for Result<NotNan<T>, FloatIsNan> {
+    fn sum<I: Iterator<Item = NotNan<T>>>(mut iter: I) -> Self {
+        iter.try_fold(T::zero(), |acc, v| NotNan::new(acc + v.0).map(|s| s.0))
+            .map(NotNan)
     }
-    fn signum(self) -> Self {
-        OrderedFloat(self.0.signum())
+}
+
+impl<'a, T: FloatCore + 'a> Sum<&'a NotNan<T>> for Result<NotNan<T>, FloatIsNan> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a NotNan<T>>>(iter: I) -> Self {
+        iter.cloned().sum()
     }
-    fn is_sign_positive(self) -> bool {
-        self.0.is_sign_positive()
+}
+
+/// Subtracts a float directly.
+///
+/// Panics if the provided value is NaN or the computation results in NaN
+impl<T: FloatCore> Sub<T> for NotNan<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: T) -> Self {
+        NotNan::new(self.0 - other).expect("Subtraction resulted in NaN")
     }
-    fn is_sign_negative(self) -> bool {
-        self.0.is_sign_negative()
+}
+
+/// Multiplies a float directly.
+///
+/// Panics if the provided value is NaN or the computation results in NaN
+impl<T: FloatCore> Mul<T> for NotNan<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: T) -> Self {
+        NotNan::new(self.0 * other).expect("Multiplication resulted in NaN")
     }
-    fn recip(self) -> Self {
-        OrderedFloat(self.0.recip())
+}
+
+impl<T: FloatCore + Product> Product for NotNan<T> {
+    fn product<I: Iterator<Item = NotNan<T>>>(iter: I) -> Self {
+        NotNan::new(iter.map(|v| v.0).product()).expect("Product resulted in NaN")
     }
-    fn powi(self, n: i32) -> Self {
-        OrderedFloat(self.0.powi(n))
+}
+
+impl<'a, T: FloatCore + Product + 'a> Product<&'a NotNan<T>> for NotNan<T> {
+    #[inline]
+    fn product<I: Iterator<Item = &'a NotNan<T>>>(iter: I) -> Self {
+        iter.cloned().product()
     }
-    fn integer_decode(self) -> (u64, i16, i8) {
-        self.0.integer_decode()
+}
+
+/// Multiplies without panicking, returning `Err` as soon as the running product becomes NaN.
+impl<T: FloatCore> Product<NotNan<T>> for Result<NotNan<T>, FloatIsNan> {
+    fn product<I: Iterator<Item = NotNan<T>>>(mut iter: I) -> Self {
+        iter.try_fold(T::one(), |acc, v| NotNan::new(acc * v.0).map(|p| p.0))
+            .map(NotNan)
     }
-    fn epsilon() -> Self {
-        OrderedFloat(T::epsilon())
+}
+
+impl<'a, T: FloatCore + 'a> Product<&'a NotNan<T>> for Result<NotNan<T>, FloatIsNan> {
+    #[inline]
+    fn product<I: Iterator<Item = &'a NotNan<T>>>(iter: I) -> Self {
+        iter.cloned().product()
     }
-    fn to_degrees(self) -> Self {
-        OrderedFloat(self.0.to_degrees())
+}
+
+/// Divides a float directly.
+///
+/// Panics if the provided value is NaN or the computation results in NaN
+impl<T: FloatCore> Div<T> for NotNan<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: T) -> Self {
+        NotNan::new(self.0 / other).expect("Division resulted in NaN")
     }
-    fn to_radians(self) -> Self {
-        OrderedFloat(self.0.to_radians())
+}
+
+/// Calculates `%` with a float directly.
+///
+/// Panics if the provided value is NaN or the computation results in NaN
+impl<T: FloatCore> Rem<T> for NotNan<T> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, other: T) -> Self {
+        NotNan::new(self.0 % other).expect("Rem resulted in NaN")
     }
 }
 
+macro_rules! impl_not_nan_binop {
+    ($imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident) => {
+        impl<T: FloatCore> $imp for NotNan<T> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, other: Self) -> Self {
+                self.$method(other.0)
+            }
+        }
+
+        impl<T: FloatCore> $imp<&T> for NotNan<T> {
+            type Output = NotNan<T>;
+
+            #[inline]
+            fn $method(self, other: &T) -> Self::Output {
+                self.$method(*other)
+            }
+        }
+
+        impl<T: FloatCore> $imp<&Self> for NotNan<T> {
+            type Output = NotNan<T>;
+
+            #[inline]
+            fn $method(self, other: &Self) -> Self::Output {
+                self.$method(other.0)
+            }
+        }
+
+        impl<T: FloatCore> $imp for &NotNan<T> {
+            type Output = NotNan<T>;
+
+            #[inline]
+            fn $method(self, other: Self) -> Self::Output {
+                (*self).$method(other.0)
+            }
+        }
+
+        impl<T: FloatCore> $imp<NotNan<T>> for &NotNan<T> {
+            type Output = NotNan<T>;
+
+            #[inline]
+            fn $method(self, other: NotNan<T>) -> Self::Output {
+                (*self).$method(other.0)
+            }
+        }
+
+        impl<T: FloatCore> $imp<T> for &NotNan<T> {
+            type Output = NotNan<T>;
+
+            #[inline]
+            fn $method(self, other: T) -> Self::Output {
+                (*self).$method(other)
+            }
+        }
+
+        impl<T: FloatCore> $imp<&T> for &NotNan<T> {
+            type Output = NotNan<T>;
+
+            #[inline]
+            fn $method(self, other: &T) -> Self::Output {
+                (*self).$method(*other)
+            }
+        }
+
+        impl<T: FloatCore + $assign_imp> $assign_imp<T> for NotNan<T> {
+            #[inline]
+            fn $assign_method(&mut self, other: T) {
+                *self = (*self).$method(other);
+            }
+        }
+
+        impl<T: FloatCore + $assign_imp> $assign_imp<&T> for NotNan<T> {
+            #[inline]
+            fn $assign_method(&mut self, other: &T) {
+                *self = (*self).$method(*other);
+            }
+        }
+
+        impl<T: FloatCore + $assign_imp> $assign_imp for NotNan<T> {
+            #[inline]
+            fn $assign_method(&mut self, other: Self) {
+                (*self).$assign_method(other.0);
+            }
+        }
+
+        impl<T: FloatCore + $assign_imp> $assign_imp<&Self> for NotNan<T> {
+            #[inline]
+            fn $assign_method(&mut self, other: &Self) {
+                (*self).$assign_method(other.0);
+            }
+        }
+    };
+}
+
+impl_not_nan_binop! {Add, add, AddAssign, add_assign}
+impl_not_nan_binop! {Sub, sub, SubAssign, sub_assign}
+impl_not_nan_binop! {Mul, mul, MulAssign, mul_assign}
+impl_not_nan_binop! {Div, div, DivAssign, div_assign}
+impl_not_nan_binop! {Rem, rem, RemAssign, rem_assign}
+
+macro_rules! impl_not_nan_checked_assign {
+    ($($checked:ident, $method:ident, $op:literal);*) => {
+        impl<T: FloatCore> NotNan<T> {
+            $(
+                #[doc = concat!("Computes `self ", $op, " rhs` in place, returning `Err` and leaving `self` unchanged if the result is NaN.")]
+                ///
+                #[doc = concat!("This is the non-panicking counterpart of `", $op, "=`.")]
+                #[inline]
+                pub fn $checked(&mut self, rhs: T) -> Result<(), FloatIsNan> {
+                    *self = NotNan::new(self.0.$method(rhs))?;
+                    Ok(())
+                }
+            )*
+        }
+    };
+}
+
+impl_not_nan_checked_assign! {
+    checked_add_assign, add, "+";
+    checked_sub_assign, sub, "-";
+    checked_mul_assign, mul, "*";
+    checked_div_assign, div, "/";
+    checked_rem_assign, rem, "%"
+}
+
+// Mixed `NotNan`/`OrderedFloat` arithmetic returns `OrderedFloat`, because the result may be NaN
+// (e.g. `inf - inf`) and `OrderedFloat` can hold it without panicking.
+macro_rules! impl_mixed_binop {
+    ($imp:ident, $method:ident) => {
+        /// Returns an `OrderedFloat`, since the result may be NaN.
+        impl<T: FloatCore> $imp<OrderedFloat<T>> for NotNan<T> {
+            type Output = OrderedFloat<T>;
+
+            #[inline]
+            fn $method(self, other: OrderedFloat<T>) -> Self::Output {
+                OrderedFloat((self.0).$method(other.0))
+            }
+        }
+
+        impl<'a, T: FloatCore> $imp<&'a OrderedFloat<T>> for NotNan<T> {
+            type Output = OrderedFloat<T>;
+
+            #[inline]
+            fn $method(self, other: &'a OrderedFloat<T>) -> Self::Output {
+                OrderedFloat((self.0).$method(other.0))
+            }
+        }
+
+        impl<'a, T: FloatCore> $imp<OrderedFloat<T>> for &'a NotNan<T> {
+            type Output = OrderedFloat<T>;
+
+            #[inline]
+            fn $method(self, other: OrderedFloat<T>) -> Self::Output {
+                OrderedFloat((self.0).$method(other.0))
+            }
+        }
+
+        impl<'a, 'b, T: FloatCore> $imp<&'a OrderedFloat<T>> for &'b NotNan<T> {
+            type Output = OrderedFloat<T>;
+
+            #[inline]
+            fn $method(self, other: &'a OrderedFloat<T>) -> Self::Output {
+                OrderedFloat((self.0).$method(other.0))
+            }
+        }
+
+        /// Returns an `OrderedFloat`, since the result may be NaN.
+        impl<T: FloatCore> $imp<NotNan<T>> for OrderedFloat<T> {
+            type Output = OrderedFloat<T>;
+
+            #[inline]
+            fn $method(self, other: NotNan<T>) -> Self::Output {
+                OrderedFloat((self.0).$method(other.0))
+            }
+        }
+
+        impl<'a, T: FloatCore> $imp<&'a NotNan<T>> for OrderedFloat<T> {
+            type Output = OrderedFloat<T>;
+
+            #[inline]
+            fn $method(self, other: &'a NotNan<T>) -> Self::Output {
+                OrderedFloat((self.0).$method(other.0))
+            }
+        }
+
+        impl<'a, T: FloatCore> $imp<NotNan<T>> for &'a OrderedFloat<T> {
+            type Output = OrderedFloat<T>;
+
+            #[inline]
+            fn $method(self, other: NotNan<T>) -> Self::Output {
+                OrderedFloat((self.0).$method(other.0))
+            }
+        }
+
+        impl<'a, 'b, T: FloatCore> $imp<&'a NotNan<T>> for &'b OrderedFloat<T> {
+            type Output = OrderedFloat<T>;
+
+            #[inline]
+            fn $method(self, other: &'a NotNan<T>) -> Self::Output {
+                OrderedFloat((self.0).$method(other.0))
+            }
+        }
+    };
+}
+
+impl_mixed_binop! {Add, add}
+impl_mixed_binop! {Sub, sub}
+impl_mixed_binop! {Mul, mul}
+impl_mixed_binop! {Div, div}
+impl_mixed_binop! {Rem, rem}
+
+// Primitive-on-the-left arithmetic. A generic `impl<T> Add<NotNan<T>> for T` is rejected by the
+// orphan rules, so these are provided for the primitive floats only. With a `NotNan` operand the
+// result is a plain float, because the other operand may be NaN; with an `OrderedFloat` operand
+// the result stays wrapped.
+macro_rules! impl_reverse_binop {
+    ($imp:ident, $method:ident, $($f:ident),*) => {$(
+        impl $imp<NotNan<$f>> for $f {
+            type Output = $f;
+
+            #[inline]
+            fn $method(self, other: NotNan<$f>) -> $f {
+                self.$method(other.0)
+            }
+        }
+
+        impl<'a> $imp<&'a NotNan<$f>> for $f {
+            type Output = $f;
+
+            #[inline]
+            fn $method(self, other: &'a NotNan<$f>) -> $f {
+                self.$method(other.0)
+            }
+        }
+
+        impl $imp<OrderedFloat<$f>> for $f {
+            type Output = OrderedFloat<$f>;
+
+            #[inline]
+            fn $method(self, other: OrderedFloat<$f>) -> OrderedFloat<$f> {
+                OrderedFloat(self.$method(other.0))
+            }
+        }
+
+        impl<'a> $imp<&'a OrderedFloat<$f>> for $f {
+            type Output = OrderedFloat<$f>;
+
+            #[inline]
+            fn $method(self, other: &'a OrderedFloat<$f>) -> OrderedFloat<$f> {
+                OrderedFloat(self.$method(other.0))
+            }
+        }
+    )*};
+}
+
+impl_reverse_binop! {Add, add, f32, f64}
+impl_reverse_binop! {Sub, sub, f32, f64}
+impl_reverse_binop! {Mul, mul, f32, f64}
+impl_reverse_binop! {Div, div, f32, f64}
+impl_reverse_binop! {Rem, rem, f32, f64}
+
+// Will panic if NaN value is return from the operation
+macro_rules! impl_not_nan_pow {
+    ($inner:ty, $rhs:ty) => {
+        impl_not_nan_pow! {$inner, $rhs, <$inner>::pow}
+    };
+    ($inner:ty, $rhs:ty, $pow:expr) => {
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl Pow<$rhs> for NotNan<$inner> {
+            type Output = NotNan<$inner>;
+            #[inline]
+            fn pow(self, rhs: $rhs) -> NotNan<$inner> {
+                NotNan::new($pow(self.0, rhs)).expect("Pow resulted in NaN")
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl<'a> Pow<&'a $rhs> for NotNan<$inner> {
+            type Output = NotNan<$inner>;
+            #[inline]
+            fn pow(self, rhs: &'a $rhs) -> NotNan<$inner> {
+                NotNan::new($pow(self.0, *rhs)).expect("Pow resulted in NaN")
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl<'a> Pow<$rhs> for &'a NotNan<$inner> {
+            type Output = NotNan<$inner>;
+            #[inline]
+            fn pow(self, rhs: $rhs) -> NotNan<$inner> {
+                NotNan::new($pow(self.0, rhs)).expect("Pow resulted in NaN")
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl<'a, 'b> Pow<&'a $rhs> for &'b NotNan<$inner> {
+            type Output = NotNan<$inner>;
+            #[inline]
+            fn pow(self, rhs: &'a $rhs) -> NotNan<$inner> {
+                NotNan::new($pow(self.0, *rhs)).expect("Pow resulted in NaN")
+            }
+        }
+    };
+}
+
+impl_not_nan_pow! {f32, i8}
+impl_not_nan_pow! {f32, i16}
+impl_not_nan_pow! {f32, u8}
+impl_not_nan_pow! {f32, u16}
+impl_not_nan_pow! {f32, i32}
+impl_not_nan_pow! {f64, i8}
+impl_not_nan_pow! {f64, i16}
+impl_not_nan_pow! {f64, u8}
+impl_not_nan_pow! {f64, u16}
+impl_not_nan_pow! {f64, i32}
+impl_not_nan_pow! {f32, u32, pow_wide_int}
+impl_not_nan_pow! {f64, u32, pow_wide_int}
+impl_not_nan_pow! {f64, i64, pow_wide_int}
+impl_not_nan_pow! {f64, u64, pow_wide_int}
+impl_not_nan_pow! {f32, f32}
+impl_not_nan_pow! {f64, f32}
+impl_not_nan_pow! {f64, f64}
+
+// This also should panic on NaN
+macro_rules! impl_not_nan_self_pow {
+    ($base:ty, $exp:ty) => {
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl Pow<NotNan<$exp>> for NotNan<$base> {
+            type Output = NotNan<$base>;
+            #[inline]
+            fn pow(self, rhs: NotNan<$exp>) -> NotNan<$base> {
+                NotNan::new(self.0.pow(rhs.0)).expect("Pow resulted in NaN")
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl<'a> Pow<&'a NotNan<$exp>> for NotNan<$base> {
+            type Output = NotNan<$base>;
+            #[inline]
+            fn pow(self, rhs: &'a NotNan<$exp>) -> NotNan<$base> {
+                NotNan::new(self.0.pow(rhs.0)).expect("Pow resulted in NaN")
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl<'a> Pow<NotNan<$exp>> for &'a NotNan<$base> {
+            type Output = NotNan<$base>;
+            #[inline]
+            fn pow(self, rhs: NotNan<$exp>) -> NotNan<$base> {
+                NotNan::new(self.0.pow(rhs.0)).expect("Pow resulted in NaN")
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl<'a, 'b> Pow<&'a NotNan<$exp>> for &'b NotNan<$base> {
+            type Output = NotNan<$base>;
+            #[inline]
+            fn pow(self, rhs: &'a NotNan<$exp>) -> NotNan<$base> {
+                NotNan::new(self.0.pow(rhs.0)).expect("Pow resulted in NaN")
+            }
+        }
+    };
+}
+
+impl_not_nan_self_pow! {f32, f32}
+impl_not_nan_self_pow! {f64, f32}
+impl_not_nan_self_pow! {f64, f64}
+
 #[cfg(any(feature = "std", feature = "libm"))]
-impl<T: Float + FloatCore> Float for OrderedFloat<T> {
-    fn nan() -> Self {
-        OrderedFloat(<T as Float>::nan())
+impl<T: Float + FloatCore> NotNan<T> {
+    /// Raises `self` to a floating point power, returning `Err` instead of panicking if the
+    /// result is NaN (e.g. for a negative base and a fractional exponent).
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// let two = NotNan::new(2.0).unwrap();
+    /// assert_eq!(two.checked_powf(NotNan::new(3.0).unwrap()), Ok(NotNan::new(8.0).unwrap()));
+    /// assert!((-two).checked_powf(NotNan::new(0.5).unwrap()).is_err());
+    /// ```
+    #[inline]
+    pub fn checked_powf(self, n: Self) -> Result<Self, FloatIsNan> {
+        NotNan::new(Float::powf(self.0, n.0))
     }
-    fn infinity() -> Self {
-        OrderedFloat(<T as Float>::infinity())
+
+    /// Raises `self` to an integer power, returning `Err` instead of panicking if the result
+    /// is NaN.
+    #[inline]
+    pub fn checked_powi(self, n: i32) -> Result<Self, FloatIsNan> {
+        NotNan::new(Float::powi(self.0, n))
     }
-    fn neg_infinity() -> Self {
-        OrderedFloat(<T as Float>::neg_infinity())
+
+    /// Raises `self` to a floating point power, returning `fallback` instead of panicking if the
+    /// result is NaN (e.g. for a negative base and a fractional exponent).
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// let x = NotNan::new(-2.0).unwrap();
+    /// let zero = NotNan::new(0.0).unwrap();
+    /// assert_eq!(x.powf_or(NotNan::new(0.5).unwrap(), zero), zero);
+    /// ```
+    #[inline]
+    pub fn powf_or(self, n: Self, fallback: Self) -> Self {
+        self.checked_powf(n).unwrap_or(fallback)
     }
-    fn neg_zero() -> Self {
-        OrderedFloat(<T as Float>::neg_zero())
+
+    /// Rounds to `decimals` decimal places, with halfway cases rounded away from zero. A negative
+    /// `decimals` rounds to tens, hundreds, and so on.
+    ///
+    /// See [`OrderedFloat::round_to`].
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// assert_eq!(NotNan::new(3.14159).unwrap().round_to(2), 3.14);
+    /// assert_eq!(NotNan::new(12345.0).unwrap().round_to(-2), 12300.0);
+    /// ```
+    #[inline]
+    pub fn round_to(self, decimals: i32) -> Self {
+        NotNan::new(round_to(self.0, decimals)).expect("Rounding resulted in NaN")
     }
-    fn min_value() -> Self {
-        OrderedFloat(<T as Float>::min_value())
+
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`) in radians. See
+    /// [`OrderedFloat::atan2`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is NaN. For the standard float types this can't happen for non-NaN
+    /// operands, including `atan2(0, 0)`, which is `0`.
+    #[inline]
+    pub fn atan2(self, other: Self) -> Self {
+        NotNan::new(Float::atan2(self.0, other.0)).expect("atan2 resulted in NaN")
     }
-    fn min_positive_value() -> Self {
-        OrderedFloat(<T as Float>::min_positive_value())
+
+    /// Computes `sqrt(self² + other²)` without intermediate overflow or underflow. See
+    /// [`OrderedFloat::hypot`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is NaN. For the standard float types this can't happen for non-NaN
+    /// operands.
+    #[inline]
+    pub fn hypot(self, other: Self) -> Self {
+        NotNan::new(Float::hypot(self.0, other.0)).expect("hypot resulted in NaN")
     }
-    fn max_value() -> Self {
-        OrderedFloat(<T as Float>::max_value())
+
+    /// Computes `(self * a) + b` with a single rounding. See [`OrderedFloat::mul_add`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is NaN, like the arithmetic operators on `NotNan`. This happens when
+    /// one factor is zero and the other infinite, or when the product is an infinity and `b` is
+    /// the opposite infinity.
+    ///
+    /// ```should_panic
+    /// use ordered_float::NotNan;
+    ///
+    /// let inf = NotNan::new(f64::INFINITY).unwrap();
+    /// let zero = NotNan::new(0.0).unwrap();
+    /// let _ = inf.mul_add(zero, -inf);
+    /// ```
+    #[inline]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        NotNan::new(Float::mul_add(self.0, a.0, b.0)).expect("Multiply-add resulted in NaN")
     }
-    fn is_nan(self) -> bool {
-        Float::is_nan(self.0)
+}
+
+/// Negation follows IEEE 754, so `+0.0` becomes `-0.0`; see [`NotNan::neg_canonical`] for a
+/// variant that always returns `+0.0` for zero.
+///
+/// ```
+/// use ordered_float::NotNan;
+///
+/// let x = NotNan::new(0.0f64).unwrap();
+/// assert!((-x).is_sign_negative());
+/// assert_eq!(-NotNan::new(2.0f64).unwrap(), -2.0);
+/// ```
+impl<T: FloatCore> Neg for NotNan<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        NotNan(-self.0)
     }
-    fn is_infinite(self) -> bool {
-        Float::is_infinite(self.0)
+}
+
+/// Negation of a reference, behaving exactly like negation of the owned value.
+///
+/// ```
+/// use ordered_float::NotNan;
+///
+/// let x = NotNan::new(0.0f64).unwrap();
+/// assert!((-&x).is_sign_negative());
+/// assert_eq!(-&x, -x);
+/// ```
+impl<T: FloatCore> Neg for &NotNan<T> {
+    type Output = NotNan<T>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        NotNan(-self.0)
+    }
+}
+
+/// An error indicating an attempt to construct NotNan from a NaN
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct FloatIsNan;
+
+#[cfg(feature = "std")]
+impl Error for FloatIsNan {
+    fn description(&self) -> &str {
+        "NotNan constructed with NaN"
     }
-    fn is_finite(self) -> bool {
-        Float::is_finite(self.0)
+}
+
+impl fmt::Display for FloatIsNan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NotNan constructed with NaN")
     }
-    fn is_normal(self) -> bool {
-        Float::is_normal(self.0)
+}
+
+/// An error indicating that a computation on finite floats overflowed to infinity.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct FloatOverflow;
+
+#[cfg(feature = "std")]
+impl Error for FloatOverflow {}
+
+impl fmt::Display for FloatOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "floating-point overflow to infinity")
     }
-    fn classify(self) -> FpCategory {
-        Float::classify(self.0)
+}
+
+#[cfg(feature = "std")]
+impl From<FloatIsNan> for std::io::Error {
+    #[inline]
+    fn from(e: FloatIsNan) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, e)
     }
-    fn floor(self) -> Self {
-        OrderedFloat(Float::floor(self.0))
+}
+
+#[inline]
+/// Used for hashing. Input must not be zero or NaN.
+fn raw_double_bits<F: FloatCore>(f: &F) -> u64 {
+    let (man, exp, sign) = f.integer_decode();
+    let exp_u64 = exp as u16 as u64;
+    let sign_u64 = (sign > 0) as u64;
+    (man & MAN_MASK) | ((exp_u64 << 52) & EXP_MASK) | ((sign_u64 << 63) & SIGN_MASK)
+}
+
+impl<T: FloatCore> Zero for NotNan<T> {
+    #[inline]
+    fn zero() -> Self {
+        NotNan(T::zero())
     }
-    fn ceil(self) -> Self {
-        OrderedFloat(Float::ceil(self.0))
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
     }
-    fn round(self) -> Self {
-        OrderedFloat(Float::round(self.0))
+}
+
+impl<T: FloatCore> One for NotNan<T> {
+    #[inline]
+    fn one() -> Self {
+        NotNan(T::one())
     }
-    fn trunc(self) -> Self {
-        OrderedFloat(Float::trunc(self.0))
+}
+
+impl<T: FloatCore> Bounded for NotNan<T> {
+    #[inline]
+    fn min_value() -> Self {
+        NotNan(T::min_value())
     }
-    fn fract(self) -> Self {
-        OrderedFloat(Float::fract(self.0))
+
+    #[inline]
+    fn max_value() -> Self {
+        NotNan(T::max_value())
     }
-    fn abs(self) -> Self {
-        OrderedFloat(Float::abs(self.0))
+}
+
+impl<T: FloatCore + FromStr> FromStr for NotNan<T> {
+    type Err = ParseNotNanError<T::Err>;
+
+    /// Convert a &str to `NotNan`. Returns an error if the string fails to parse,
+    /// or if the resulting value is NaN
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// assert!("-10".parse::<NotNan<f32>>().is_ok());
+    /// assert!("abc".parse::<NotNan<f32>>().is_err());
+    /// assert!("NaN".parse::<NotNan<f32>>().is_err());
+    /// ```
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        src.parse()
+            .map_err(ParseNotNanError::ParseFloatError)
+            .and_then(|f| NotNan::new(f).map_err(|_| ParseNotNanError::IsNaN))
     }
-    fn signum(self) -> Self {
-        OrderedFloat(Float::signum(self.0))
+}
+
+impl<T: FloatCore + FromPrimitive> FromPrimitive for NotNan<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        T::from_i64(n).and_then(|n| NotNan::new(n).ok())
     }
-    fn is_sign_positive(self) -> bool {
-        Float::is_sign_positive(self.0)
+    fn from_u64(n: u64) -> Option<Self> {
+        T::from_u64(n).and_then(|n| NotNan::new(n).ok())
     }
-    fn is_sign_negative(self) -> bool {
-        Float::is_sign_negative(self.0)
+
+    fn from_isize(n: isize) -> Option<Self> {
+        T::from_isize(n).and_then(|n| NotNan::new(n).ok())
     }
-    fn mul_add(self, a: Self, b: Self) -> Self {
-        OrderedFloat(self.0.mul_add(a.0, b.0))
+    fn from_i8(n: i8) -> Option<Self> {
+        T::from_i8(n).and_then(|n| NotNan::new(n).ok())
     }
-    fn recip(self) -> Self {
-        OrderedFloat(Float::recip(self.0))
+    fn from_i16(n: i16) -> Option<Self> {
+        T::from_i16(n).and_then(|n| NotNan::new(n).ok())
     }
-    fn powi(self, n: i32) -> Self {
-        OrderedFloat(Float::powi(self.0, n))
+    fn from_i32(n: i32) -> Option<Self> {
+        T::from_i32(n).and_then(|n| NotNan::new(n).ok())
     }
-    fn powf(self, n: Self) -> Self {
-        OrderedFloat(self.0.powf(n.0))
+    fn from_usize(n: usize) -> Option<Self> {
+        T::from_usize(n).and_then(|n| NotNan::new(n).ok())
     }
-    fn sqrt(self) -> Self {
-        OrderedFloat(self.0.sqrt())
+    fn from_u8(n: u8) -> Option<Self> {
+        T::from_u8(n).and_then(|n| NotNan::new(n).ok())
     }
-    fn exp(self) -> Self {
-        OrderedFloat(self.0.exp())
+    fn from_u16(n: u16) -> Option<Self> {
+        T::from_u16(n).and_then(|n| NotNan::new(n).ok())
     }
-    fn exp2(self) -> Self {
-        OrderedFloat(self.0.exp2())
+    fn from_u32(n: u32) -> Option<Self> {
+        T::from_u32(n).and_then(|n| NotNan::new(n).ok())
     }
-    fn ln(self) -> Self {
-        OrderedFloat(self.0.ln())
+    fn from_f32(n: f32) -> Option<Self> {
+        T::from_f32(n).and_then(|n| NotNan::new(n).ok())
     }
-    fn log(self, base: Self) -> Self {
-        OrderedFloat(self.0.log(base.0))
+    fn from_f64(n: f64) -> Option<Self> {
+        T::from_f64(n).and_then(|n| NotNan::new(n).ok())
     }
-    fn log2(self) -> Self {
-        OrderedFloat(self.0.log2())
+}
+
+impl<T: FloatCore> ToPrimitive for NotNan<T> {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
     }
-    fn log10(self) -> Self {
-        OrderedFloat(self.0.log10())
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
     }
-    fn max(self, other: Self) -> Self {
-        OrderedFloat(Float::max(self.0, other.0))
+
+    fn to_isize(&self) -> Option<isize> {
+        self.0.to_isize()
     }
-    fn min(self, other: Self) -> Self {
-        OrderedFloat(Float::min(self.0, other.0))
+    fn to_i8(&self) -> Option<i8> {
+        self.0.to_i8()
     }
-    fn abs_sub(self, other: Self) -> Self {
-        OrderedFloat(self.0.abs_sub(other.0))
+    fn to_i16(&self) -> Option<i16> {
+        self.0.to_i16()
     }
-    fn cbrt(self) -> Self {
-        OrderedFloat(self.0.cbrt())
+    fn to_i32(&self) -> Option<i32> {
+        self.0.to_i32()
     }
-    fn hypot(self, other: Self) -> Self {
-        OrderedFloat(self.0.hypot(other.0))
+    fn to_usize(&self) -> Option<usize> {
+        self.0.to_usize()
     }
-    fn sin(self) -> Self {
-        OrderedFloat(self.0.sin())
+    fn to_u8(&self) -> Option<u8> {
+        self.0.to_u8()
     }
-    fn cos(self) -> Self {
-        OrderedFloat(self.0.cos())
+    fn to_u16(&self) -> Option<u16> {
+        self.0.to_u16()
     }
-    fn tan(self) -> Self {
-        OrderedFloat(self.0.tan())
+    fn to_u32(&self) -> Option<u32> {
+        self.0.to_u32()
     }
-    fn asin(self) -> Self {
-        OrderedFloat(self.0.asin())
+    fn to_f32(&self) -> Option<f32> {
+        self.0.to_f32()
     }
-    fn acos(self) -> Self {
-        OrderedFloat(self.0.acos())
+    fn to_f64(&self) -> Option<f64> {
+        self.0.to_f64()
     }
-    fn atan(self) -> Self {
-        OrderedFloat(self.0.atan())
+}
+
+/// An error indicating a parse error from a string for `NotNan`.
+///
+/// Parsing is available without the `std` feature; only the `std::error::Error` impl needs it.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ParseNotNanError<E> {
+    /// A plain parse error from the underlying float type.
+    ParseFloatError(E),
+    /// The parsed float value resulted in a NaN.
+    IsNaN,
+}
+
+#[cfg(feature = "std")]
+impl<E: fmt::Debug + Error + 'static> Error for ParseNotNanError<E> {
+    fn description(&self) -> &str {
+        "Error parsing a not-NaN floating point value"
     }
-    fn atan2(self, other: Self) -> Self {
-        OrderedFloat(self.0.atan2(other.0))
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseNotNanError::ParseFloatError(e) => Some(e),
+            ParseNotNanError::IsNaN => None,
+        }
     }
-    fn sin_cos(self) -> (Self, Self) {
-        let (a, b) = self.0.sin_cos();
-        (OrderedFloat(a), OrderedFloat(b))
+}
+
+impl<E: fmt::Display> fmt::Display for ParseNotNanError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseNotNanError::ParseFloatError(e) => write!(f, "Parse error: {}", e),
+            ParseNotNanError::IsNaN => write!(f, "NotNan parser encounter a NaN"),
+        }
     }
-    fn exp_m1(self) -> Self {
-        OrderedFloat(self.0.exp_m1())
+}
+
+impl<T: FloatCore> Num for NotNan<T> {
+    type FromStrRadixErr = ParseNotNanError<T::FromStrRadixErr>;
+
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(src, radix)
+            .map_err(ParseNotNanError::ParseFloatError)
+            .and_then(|n| NotNan::new(n).map_err(|_| ParseNotNanError::IsNaN))
     }
-    fn ln_1p(self) -> Self {
-        OrderedFloat(self.0.ln_1p())
+}
+
+impl<T: FloatCore + Signed> NotNan<T> {
+    /// The positive difference of two numbers, like [`Signed::abs_sub`], but returning `Err`
+    /// instead of panicking if the result is NaN.
+    ///
+    /// Returns zero if `self <= other`, and `self - other` otherwise. For the primitive float
+    /// types this never produces NaN from non-NaN inputs: equal infinities give zero, and
+    /// `inf.checked_abs_sub(-inf)` gives `inf`.
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// let inf = NotNan::new(f64::INFINITY).unwrap();
+    /// assert_eq!(inf.checked_abs_sub(&inf), Ok(NotNan::new(0.0).unwrap()));
+    /// assert_eq!(inf.checked_abs_sub(&-inf), Ok(inf));
+    /// ```
+    pub fn checked_abs_sub(&self, other: &Self) -> Result<Self, FloatIsNan> {
+        NotNan::new(Signed::abs_sub(&self.0, &other.0))
     }
-    fn sinh(self) -> Self {
-        OrderedFloat(self.0.sinh())
+}
+
+impl<T: FloatCore + Signed> Signed for NotNan<T> {
+    #[inline]
+    fn abs(&self) -> Self {
+        NotNan(self.0.abs())
     }
-    fn cosh(self) -> Self {
-        OrderedFloat(self.0.cosh())
+
+    /// Panics if the computation results in NaN, which is not possible for the primitive
+    /// float types. See [`NotNan::checked_abs_sub`] for a non-panicking version.
+    fn abs_sub(&self, other: &Self) -> Self {
+        NotNan::new(Signed::abs_sub(&self.0, &other.0)).expect("Subtraction resulted in NaN")
     }
-    fn tanh(self) -> Self {
-        OrderedFloat(self.0.tanh())
+
+    #[inline]
+    fn signum(&self) -> Self {
+        NotNan(self.0.signum())
     }
-    fn asinh(self) -> Self {
-        OrderedFloat(self.0.asinh())
+    #[inline]
+    fn is_positive(&self) -> bool {
+        self.0.is_positive()
     }
-    fn acosh(self) -> Self {
-        OrderedFloat(self.0.acosh())
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.0.is_negative()
     }
-    fn atanh(self) -> Self {
-        OrderedFloat(self.0.atanh())
+}
+
+/// Saturating arithmetic in the sense of staying within the finite range: infinite operands and
+/// results are clamped to `T::max_value()` or `T::min_value()`, so the result is always finite.
+///
+/// Because the operands are clamped first, this never produces NaN: for example,
+/// `inf.saturating_add(-inf)` is `MAX + MIN`, which is zero.
+///
+/// ```
+/// use num_traits::Saturating;
+/// use ordered_float::NotNan;
+///
+/// let max = NotNan::new(f64::MAX).unwrap();
+/// assert_eq!(max.saturating_add(max), max);
+/// assert_eq!((-max).saturating_sub(max), -max);
+/// ```
+impl<T: FloatCore> Saturating for NotNan<T> {
+    fn saturating_add(self, v: Self) -> Self {
+        NotNan(clamp_finite(clamp_finite(self.0) + clamp_finite(v.0)))
     }
-    fn integer_decode(self) -> (u64, i16, i8) {
-        Float::integer_decode(self.0)
+
+    fn saturating_sub(self, v: Self) -> Self {
+        NotNan(clamp_finite(clamp_finite(self.0) - clamp_finite(v.0)))
     }
-    fn epsilon() -> Self {
-        OrderedFloat(<T as Float>::epsilon())
+}
+
+#[inline]
+fn clamp_finite<T: FloatCore>(x: T) -> T {
+    if x > T::max_value() {
+        T::max_value()
+    } else if x < T::min_value() {
+        T::min_value()
+    } else {
+        x
     }
-    fn to_degrees(self) -> Self {
-        OrderedFloat(Float::to_degrees(self.0))
+}
+
+impl<T: FloatCore> NumCast for NotNan<T> {
+    fn from<F: ToPrimitive>(n: F) -> Option<Self> {
+        T::from(n).and_then(|n| NotNan::new(n).ok())
     }
-    fn to_radians(self) -> Self {
-        OrderedFloat(Float::to_radians(self.0))
+}
+
+macro_rules! impl_float_const_method {
+    ($wrapper:expr, $method:ident) => {
+        #[allow(non_snake_case)]
+        #[allow(clippy::redundant_closure_call)]
+        fn $method() -> Self {
+            $wrapper(T::$method())
+        }
+    };
+}
+
+macro_rules! impl_float_const {
+    ($type:ident, $wrapper:expr) => {
+        impl<T: FloatConst> FloatConst for $type<T> {
+            impl_float_const_method!($wrapper, E);
+            impl_float_const_method!($wrapper, FRAC_1_PI);
+            impl_float_const_method!($wrapper, FRAC_1_SQRT_2);
+            impl_float_const_method!($wrapper, FRAC_2_PI);
+            impl_float_const_method!($wrapper, FRAC_2_SQRT_PI);
+            impl_float_const_method!($wrapper, FRAC_PI_2);
+            impl_float_const_method!($wrapper, FRAC_PI_3);
+            impl_float_const_method!($wrapper, FRAC_PI_4);
+            impl_float_const_method!($wrapper, FRAC_PI_6);
+            impl_float_const_method!($wrapper, FRAC_PI_8);
+            impl_float_const_method!($wrapper, LN_10);
+            impl_float_const_method!($wrapper, LN_2);
+            impl_float_const_method!($wrapper, LOG10_E);
+            impl_float_const_method!($wrapper, LOG2_E);
+            impl_float_const_method!($wrapper, PI);
+            impl_float_const_method!($wrapper, SQRT_2);
+        }
+    };
+}
+
+impl_float_const!(OrderedFloat, OrderedFloat);
+// Float constants are not NaN.
+impl_float_const!(NotNan, |x| unsafe { NotNan::new_unchecked(x) });
+
+/// Wraps `x` into `[lo, lo + 2π)`, returning values already in range unchanged.
+#[inline]
+fn wrap_angle<T: FloatCore + FloatConst>(x: T, lo: T) -> T {
+    let tau = T::PI() + T::PI();
+    let hi = lo + tau;
+    if x >= lo && x < hi {
+        return x;
+    }
+    let r = (x - lo) % tau;
+    let r = if r < T::zero() { r + tau } else { r };
+    // Rounding can push a tiny negative remainder up to exactly `tau`.
+    if r >= tau {
+        lo
+    } else {
+        lo + r
     }
 }
 
-impl<T: FloatCore + Num> Num for OrderedFloat<T> {
-    type FromStrRadixErr = T::FromStrRadixErr;
-    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        T::from_str_radix(str, radix).map(OrderedFloat)
+impl<T: FloatCore + FloatConst> OrderedFloat<T> {
+    /// Wraps an angle in radians into the range `[-π, π)`.
+    ///
+    /// NaN and infinite inputs produce NaN.
+    ///
+    /// ```
+    /// use ordered_float::OrderedFloat;
+    /// use std::f64::consts::PI;
+    ///
+    /// assert_eq!(OrderedFloat(PI / 2.0).wrap_to_pi(), OrderedFloat(PI / 2.0));
+    /// assert_eq!(OrderedFloat(PI).wrap_to_pi(), OrderedFloat(-PI));
+    /// ```
+    #[inline]
+    pub fn wrap_to_pi(self) -> Self {
+        OrderedFloat(wrap_angle(self.0, -T::PI()))
+    }
+
+    /// Wraps an angle in radians into the range `[0, 2π)`.
+    ///
+    /// NaN and infinite inputs produce NaN.
+    #[inline]
+    pub fn wrap_to_2pi(self) -> Self {
+        OrderedFloat(wrap_angle(self.0, T::zero()))
     }
 }
 
-/// A wrapper around floats providing an implementation of `Eq`, `Ord` and `Hash`.
-///
-/// A NaN value cannot be stored in this type.
-///
-/// ```
-/// use ordered_float::NotNan;
-///
-/// let mut v = [
-///     NotNan::new(2.0).unwrap(),
-///     NotNan::new(1.0).unwrap(),
-/// ];
-/// v.sort();
-/// assert_eq!(v, [1.0, 2.0]);
-/// ```
-///
-/// Because `NotNan` implements `Ord` and `Eq`, it can be used as a key in a `HashSet`,
-/// `HashMap`, `BTreeMap`, or `BTreeSet` (unlike the primitive `f32` or `f64` types):
-///
-/// ```
-/// # use ordered_float::NotNan;
-/// # use std::collections::HashSet;
-/// let mut s: HashSet<NotNan<f32>> = HashSet::new();
-/// let key = NotNan::new(1.0).unwrap();
-/// s.insert(key);
-/// assert!(s.contains(&key));
-/// ```
-///
-/// `-0.0` and `+0.0` are still considered equal. This different sign may show up in printing,
-/// or when dividing by zero (the sign of the zero becomes the sign of the resulting infinity).
-/// Therefore, `NotNan` may be unsuitable for use as a key in interning and memoization
-/// applications which require equal results from equal inputs, unless signed zeros make no
-/// difference or are canonicalized before insertion.
-///
-/// Arithmetic on NotNan values will panic if it produces a NaN value:
-///
-/// ```should_panic
-/// # use ordered_float::NotNan;
-/// let a = NotNan::new(std::f32::INFINITY).unwrap();
-/// let b = NotNan::new(std::f32::NEG_INFINITY).unwrap();
-///
-/// // This will panic:
-/// let c = a + b;
-/// ```
-///
-/// # Representation
-///
-/// `NotNan` has `#[repr(transparent)]`, so it is sound to use
-/// [transmute](core::mem::transmute) or pointer casts to convert between any type `T` and
-/// `NotNan<T>`, as long as this does not create a NaN value.
-/// However, consider using [`bytemuck`] as a safe alternative if possible.
-///
-#[cfg_attr(
-    not(feature = "bytemuck"),
-    doc = "[`bytemuck`]: https://docs.rs/bytemuck/1/"
-)]
-#[derive(PartialOrd, PartialEq, Default, Clone, Copy)]
-#[repr(transparent)]
-pub struct NotNan<T>(T);
+impl<T: FloatCore + FloatConst> NotNan<T> {
+    /// Wraps an angle in radians into the range `[-π, π)`.
+    ///
+    /// Panics if `self` is infinite, because the result would be NaN.
+    #[inline]
+    pub fn wrap_to_pi(self) -> Self {
+        NotNan::new(wrap_angle(self.0, -T::PI())).expect("wrap_to_pi resulted in NaN")
+    }
 
-impl<T: FloatCore> NotNan<T> {
-    /// Create a `NotNan` value.
+    /// Wraps an angle in radians into the range `[0, 2π)`.
     ///
-    /// Returns `Err` if `val` is NaN
-    pub fn new(val: T) -> Result<Self, FloatIsNan> {
-        match val {
-            ref val if val.is_nan() => Err(FloatIsNan),
-            val => Ok(NotNan(val)),
+    /// Panics if `self` is infinite, because the result would be NaN.
+    #[inline]
+    pub fn wrap_to_2pi(self) -> Self {
+        NotNan::new(wrap_angle(self.0, T::zero())).expect("wrap_to_2pi resulted in NaN")
+    }
+}
+
+#[cfg(feature = "std")]
+mod impl_atomic {
+    use super::{canonicalize, OrderedFloat};
+    use core::fmt;
+    #[cfg(target_has_atomic = "32")]
+    use core::sync::atomic::AtomicU32;
+    #[cfg(target_has_atomic = "64")]
+    use core::sync::atomic::AtomicU64;
+    use core::sync::atomic::Ordering;
+
+    macro_rules! impl_atomic_ordered_float {
+        ($name:ident, $f:ident, $bits:ty, $atomic:ident, $width:literal) => {
+            #[doc = concat!("An [`OrderedFloat<", stringify!($f), ">`] that can be shared between threads,")]
+            #[doc = concat!("stored as its bit pattern in an [`", stringify!($atomic), "`](core::sync::atomic::", stringify!($atomic), ").")]
+            ///
+            /// Values are canonicalized when they are stored: `-0.0` becomes `+0.0` and every
+            /// NaN becomes the same NaN. Values that are equal as `OrderedFloat`s therefore have
+            /// the same bits, so `compare_exchange` succeeds exactly when the current value is
+            /// equal to `current` under `OrderedFloat`'s `Eq`.
+            ///
+            /// The `Ordering` arguments have the same meaning as for the integer atomics.
+            ///
+            /// ```
+            #[doc = concat!("use ordered_float::{", stringify!($name), ", OrderedFloat};")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let gauge = ", stringify!($name), "::new(OrderedFloat(1.5));")]
+            /// gauge.store(OrderedFloat(-0.0), Ordering::Relaxed);
+            /// assert!(gauge.load(Ordering::Relaxed).0.is_sign_positive());
+            /// assert_eq!(
+            ///     gauge.compare_exchange(OrderedFloat(0.0), OrderedFloat(2.0), Ordering::AcqRel, Ordering::Acquire),
+            ///     Ok(OrderedFloat(0.0)),
+            /// );
+            /// ```
+            #[cfg(target_has_atomic = $width)]
+            #[repr(transparent)]
+            pub struct $name {
+                bits: $atomic,
+            }
+
+            #[cfg(target_has_atomic = $width)]
+            impl $name {
+                /// Creates a new atomic holding `value`, canonicalized.
+                #[inline]
+                pub fn new(value: OrderedFloat<$f>) -> Self {
+                    $name {
+                        bits: $atomic::new(Self::to_bits(value)),
+                    }
+                }
+
+                #[inline]
+                fn to_bits(value: OrderedFloat<$f>) -> $bits {
+                    canonicalize(value.0).to_bits()
+                }
+
+                #[inline]
+                fn from_bits(bits: $bits) -> OrderedFloat<$f> {
+                    OrderedFloat($f::from_bits(bits))
+                }
+
+                /// Loads the current value.
+                #[inline]
+                pub fn load(&self, order: Ordering) -> OrderedFloat<$f> {
+                    Self::from_bits(self.bits.load(order))
+                }
+
+                /// Stores `value`, canonicalized.
+                #[inline]
+                pub fn store(&self, value: OrderedFloat<$f>, order: Ordering) {
+                    self.bits.store(Self::to_bits(value), order)
+                }
+
+                /// Stores `value`, canonicalized, and returns the previous value.
+                #[inline]
+                pub fn swap(&self, value: OrderedFloat<$f>, order: Ordering) -> OrderedFloat<$f> {
+                    Self::from_bits(self.bits.swap(Self::to_bits(value), order))
+                }
+
+                /// Stores `new` if the current value is equal to `current`, comparing as
+                /// `OrderedFloat`s. Returns the previous value, as `Ok` if `new` was stored.
+                #[inline]
+                pub fn compare_exchange(
+                    &self,
+                    current: OrderedFloat<$f>,
+                    new: OrderedFloat<$f>,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<OrderedFloat<$f>, OrderedFloat<$f>> {
+                    self.bits
+                        .compare_exchange(Self::to_bits(current), Self::to_bits(new), success, failure)
+                        .map(Self::from_bits)
+                        .map_err(Self::from_bits)
+                }
+
+                /// Like [`compare_exchange`](Self::compare_exchange), but may fail spuriously,
+                /// which can be more efficient in a loop.
+                #[inline]
+                pub fn compare_exchange_weak(
+                    &self,
+                    current: OrderedFloat<$f>,
+                    new: OrderedFloat<$f>,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<OrderedFloat<$f>, OrderedFloat<$f>> {
+                    self.bits
+                        .compare_exchange_weak(Self::to_bits(current), Self::to_bits(new), success, failure)
+                        .map(Self::from_bits)
+                        .map_err(Self::from_bits)
+                }
+
+                /// Stores the greater of the current value and `value` under `OrderedFloat`'s
+                /// total order, and returns the previous value.
+                ///
+                /// NaN is the greatest value, so once a NaN is stored it stays, and storing a
+                /// NaN always succeeds. `-0.0` and `+0.0` are equal, so neither replaces the
+                /// other.
+                ///
+                /// This is a compare-exchange loop on the bit pattern; `order` applies to the
+                /// final successful exchange, as for the integer `fetch_max`.
+                ///
+                /// ```
+                #[doc = concat!("use ordered_float::{", stringify!($name), ", OrderedFloat};")]
+                /// use std::sync::atomic::Ordering;
+                ///
+                #[doc = concat!("let peak = ", stringify!($name), "::new(OrderedFloat(1.0));")]
+                /// assert_eq!(peak.fetch_max(OrderedFloat(3.0), Ordering::Relaxed), OrderedFloat(1.0));
+                /// assert_eq!(peak.fetch_max(OrderedFloat(2.0), Ordering::Relaxed), OrderedFloat(3.0));
+                /// assert_eq!(peak.load(Ordering::Relaxed), OrderedFloat(3.0));
+                /// ```
+                #[inline]
+                pub fn fetch_max(&self, value: OrderedFloat<$f>, order: Ordering) -> OrderedFloat<$f> {
+                    self.fetch_update_if(order, |current| current < value, value)
+                }
+
+                /// Stores the lesser of the current value and `value` under `OrderedFloat`'s
+                /// total order, and returns the previous value.
+                ///
+                /// NaN is the greatest value, so it never replaces a stored number, and any
+                /// number replaces a stored NaN. `-0.0` and `+0.0` are equal, so neither replaces
+                /// the other.
+                #[inline]
+                pub fn fetch_min(&self, value: OrderedFloat<$f>, order: Ordering) -> OrderedFloat<$f> {
+                    self.fetch_update_if(order, |current| current > value, value)
+                }
+
+                // Stores `value` unless `replace` is false for the current value, retrying if
+                // another thread changes the value in between.
+                #[inline]
+                fn fetch_update_if(
+                    &self,
+                    order: Ordering,
+                    replace: impl Fn(OrderedFloat<$f>) -> bool,
+                    value: OrderedFloat<$f>,
+                ) -> OrderedFloat<$f> {
+                    let new = Self::to_bits(value);
+                    let fetch_order = match order {
+                        Ordering::Release | Ordering::Relaxed => Ordering::Relaxed,
+                        Ordering::SeqCst => Ordering::SeqCst,
+                        _ => Ordering::Acquire,
+                    };
+                    let previous = self.bits.fetch_update(order, fetch_order, |bits| {
+                        if replace(Self::from_bits(bits)) {
+                            Some(new)
+                        } else {
+                            None
+                        }
+                    });
+                    Self::from_bits(previous.unwrap_or_else(|bits| bits))
+                }
+
+                /// Consumes the atomic and returns the value it holds.
+                #[inline]
+                pub fn into_inner(self) -> OrderedFloat<$f> {
+                    Self::from_bits(self.bits.into_inner())
+                }
+            }
+
+            #[cfg(target_has_atomic = $width)]
+            impl Default for $name {
+                #[inline]
+                fn default() -> Self {
+                    Self::new(OrderedFloat(0.0))
+                }
+            }
+
+            #[cfg(target_has_atomic = $width)]
+            impl From<OrderedFloat<$f>> for $name {
+                #[inline]
+                fn from(value: OrderedFloat<$f>) -> Self {
+                    Self::new(value)
+                }
+            }
+
+            #[cfg(target_has_atomic = $width)]
+            impl fmt::Debug for $name {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+                }
+            }
+        };
+    }
+
+    impl_atomic_ordered_float! {AtomicOrderedF32, f32, u32, AtomicU32, "32"}
+    impl_atomic_ordered_float! {AtomicOrderedF64, f64, u64, AtomicU64, "64"}
+
+    #[cfg(all(test, target_has_atomic = "64"))]
+    mod tests {
+        use super::AtomicOrderedF64;
+        use crate::OrderedFloat;
+        use core::sync::atomic::Ordering::{AcqRel, Acquire, Relaxed, SeqCst};
+        use std::sync::Arc;
+        use std::thread;
+        use std::vec::Vec;
+
+        #[test]
+        fn test_canonicalizes() {
+            let a = AtomicOrderedF64::new(OrderedFloat(-0.0));
+            assert_eq!(a.load(Relaxed).0.to_bits(), 0.0f64.to_bits());
+            // The stored `+0.0` is equal to a `-0.0` expectation.
+            assert_eq!(
+                a.compare_exchange(OrderedFloat(-0.0), OrderedFloat(1.0), AcqRel, Acquire),
+                Ok(OrderedFloat(0.0))
+            );
+
+            a.store(OrderedFloat(f64::from_bits(0xfff8_0000_dead_beef)), Relaxed);
+            assert_eq!(a.load(Relaxed).0.to_bits(), f64::NAN.to_bits());
+            assert_eq!(
+                a.compare_exchange(OrderedFloat(f64::NAN), OrderedFloat(2.0), AcqRel, Acquire),
+                Ok(OrderedFloat(f64::NAN))
+            );
+            assert_eq!(
+                a.compare_exchange(OrderedFloat(1.0), OrderedFloat(3.0), AcqRel, Acquire),
+                Err(OrderedFloat(2.0))
+            );
+            assert_eq!(a.swap(OrderedFloat(4.0), Relaxed), OrderedFloat(2.0));
+            assert_eq!(a.into_inner(), OrderedFloat(4.0));
+            assert_eq!(AtomicOrderedF64::default().into_inner(), OrderedFloat(0.0));
+        }
+
+        #[test]
+        fn test_concurrent_store_load() {
+            let a = Arc::new(AtomicOrderedF64::new(OrderedFloat(0.0)));
+            let writers: Vec<_> = (1..=4)
+                .map(|i| {
+                    let a = Arc::clone(&a);
+                    thread::spawn(move || {
+                        for _ in 0..1000 {
+                            a.store(OrderedFloat(i as f64 * 1.5), Relaxed);
+                        }
+                    })
+                })
+                .collect();
+            for _ in 0..1000 {
+                // Loads never observe a torn value.
+                let x = a.load(Relaxed).0;
+                assert!([0.0, 1.5, 3.0, 4.5, 6.0].contains(&x), "{}", x);
+            }
+            for w in writers {
+                w.join().unwrap();
+            }
+        }
+
+        #[test]
+        fn test_fetch_max() {
+            let a = Arc::new(AtomicOrderedF64::new(OrderedFloat(f64::NEG_INFINITY)));
+            let threads: Vec<_> = (0..4)
+                .map(|t| {
+                    let a = Arc::clone(&a);
+                    thread::spawn(move || {
+                        for i in 0..1000 {
+                            a.fetch_max(OrderedFloat((i * 4 + t) as f64 - 2000.0), AcqRel);
+                        }
+                    })
+                })
+                .collect();
+            for t in threads {
+                t.join().unwrap();
+            }
+            assert_eq!(a.load(SeqCst), OrderedFloat(1999.0));
+
+            // NaN is the greatest value in the total order.
+            assert_eq!(
+                a.fetch_max(OrderedFloat(f64::NAN), SeqCst),
+                OrderedFloat(1999.0)
+            );
+            assert!(a.load(SeqCst).0.is_nan());
+            assert!(a.fetch_max(OrderedFloat(f64::INFINITY), SeqCst).0.is_nan());
+            assert!(a.load(SeqCst).0.is_nan());
+        }
+
+        #[test]
+        fn test_fetch_min() {
+            let a = Arc::new(AtomicOrderedF64::new(OrderedFloat(f64::NAN)));
+            let threads: Vec<_> = (0..4)
+                .map(|t| {
+                    let a = Arc::clone(&a);
+                    thread::spawn(move || {
+                        for i in (0..1000).rev() {
+                            a.fetch_min(OrderedFloat((i * 4 + t) as f64 + 0.5), AcqRel);
+                        }
+                    })
+                })
+                .collect();
+            for t in threads {
+                t.join().unwrap();
+            }
+            assert_eq!(a.load(SeqCst), OrderedFloat(0.5));
+
+            assert_eq!(
+                a.fetch_min(OrderedFloat(f64::NAN), SeqCst),
+                OrderedFloat(0.5)
+            );
+            assert_eq!(a.load(SeqCst), OrderedFloat(0.5));
+            a.store(OrderedFloat(0.0), SeqCst);
+            assert_eq!(a.fetch_min(OrderedFloat(-0.0), SeqCst), OrderedFloat(0.0));
+            assert_eq!(a.load(SeqCst).0.to_bits(), 0.0f64.to_bits());
+            assert_eq!(
+                a.fetch_min(OrderedFloat(f64::NEG_INFINITY), Relaxed),
+                OrderedFloat(0.0)
+            );
+            assert_eq!(a.load(SeqCst), OrderedFloat(f64::NEG_INFINITY));
         }
     }
 }
 
-impl<T> NotNan<T> {
-    /// Get the value out.
-    #[inline]
-    pub fn into_inner(self) -> T {
-        self.0
-    }
+#[cfg(feature = "serde")]
+mod impl_serde {
+    extern crate serde;
+    use self::serde::de::{Error, Unexpected};
+    use self::serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use super::{NotNan, OrderedFloat};
+    use core::f64;
+    use num_traits::float::FloatCore;
 
-    /// Create a `NotNan` value from a value that is guaranteed to not be NaN
-    ///
-    /// # Safety
-    ///
-    /// Behaviour is undefined if `val` is NaN
-    #[inline]
-    pub const unsafe fn new_unchecked(val: T) -> Self {
-        NotNan(val)
+    #[cfg(test)]
+    extern crate serde_test;
+    #[cfg(test)]
+    use self::serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    impl<T: FloatCore + Serialize> Serialize for OrderedFloat<T> {
+        #[inline]
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize(s)
+        }
     }
 
-    /// Create a `NotNan` value from a value that is guaranteed to not be NaN
-    ///
-    /// # Safety
-    ///
-    /// Behaviour is undefined if `val` is NaN
-    #[deprecated(
-        since = "2.5.0",
-        note = "Please use the new_unchecked function instead."
-    )]
-    #[inline]
-    pub const unsafe fn unchecked_new(val: T) -> Self {
-        Self::new_unchecked(val)
+    impl<'de, T: FloatCore + Deserialize<'de>> Deserialize<'de> for OrderedFloat<T> {
+        #[inline]
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            T::deserialize(d).map(OrderedFloat)
+        }
     }
-}
 
-impl<T: FloatCore> AsRef<T> for NotNan<T> {
-    #[inline]
-    fn as_ref(&self) -> &T {
-        &self.0
+    impl<T: FloatCore + Serialize> Serialize for NotNan<T> {
+        #[inline]
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize(s)
+        }
     }
-}
 
-impl Borrow<f32> for NotNan<f32> {
-    #[inline]
-    fn borrow(&self) -> &f32 {
-        &self.0
+    impl<'de, T: FloatCore + Deserialize<'de>> Deserialize<'de> for NotNan<T> {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            let float = T::deserialize(d)?;
+            NotNan::new(float).map_err(|_| {
+                Error::invalid_value(Unexpected::Float(f64::NAN), &"float (but not NaN)")
+            })
+        }
     }
-}
 
-impl Borrow<f64> for NotNan<f64> {
-    #[inline]
-    fn borrow(&self) -> &f64 {
-        &self.0
+    #[test]
+    fn test_ordered_float() {
+        let float = OrderedFloat(1.0f64);
+        assert_tokens(&float, &[Token::F64(1.0)]);
     }
-}
 
-#[allow(clippy::derive_ord_xor_partial_ord)]
-impl<T: FloatCore> Ord for NotNan<T> {
-    fn cmp(&self, other: &NotNan<T>) -> Ordering {
-        // Can't use unreachable_unchecked because unsafe code can't depend on FloatCore impl.
-        // https://github.com/reem/rust-ordered-float/issues/150
-        self.partial_cmp(other)
-            .expect("partial_cmp failed for non-NaN value")
+    #[test]
+    fn test_not_nan() {
+        let float = NotNan(1.0f64);
+        assert_tokens(&float, &[Token::F64(1.0)]);
     }
-}
 
-impl<T: FloatCore> Hash for NotNan<T> {
-    #[inline]
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        let bits = raw_double_bits(&canonicalize_signed_zero(self.0));
-        bits.hash(state)
+    #[test]
+    fn test_json_round_trip_is_exact() {
+        // `Serialize` delegates to the inner float, which serde_json writes with the shortest
+        // representation that round-trips. Reading it back exactly needs serde_json's
+        // `float_roundtrip` feature; its default parser can be off by one ULP.
+        let tricky = [
+            0.1,
+            0.2,
+            0.1 + 0.2,
+            1.0 / 3.0,
+            -0.0,
+            1e308,
+            f64::MAX,
+            f64::MIN,
+            f64::MIN_POSITIVE,
+            f64::EPSILON,
+            5e-324,
+            f64::from_bits(0x000f_ffff_ffff_ffff),
+            9007199254740993.0,
+            123456789.12345679,
+            core::f64::consts::PI,
+        ];
+        for &x in &tricky {
+            let json = serde_json::to_string(&OrderedFloat(x)).unwrap();
+            let back: OrderedFloat<f64> = serde_json::from_str(&json).unwrap();
+            assert_eq!(back.0.to_bits(), x.to_bits(), "{}", json);
+
+            let json = serde_json::to_string(&NotNan(x)).unwrap();
+            let back: NotNan<f64> = serde_json::from_str(&json).unwrap();
+            assert_eq!(back.0.to_bits(), x.to_bits(), "{}", json);
+        }
+
+        for &x in &[0.1f32, 1.0 / 3.0, f32::MAX, f32::MIN_POSITIVE, 1e-45] {
+            let json = serde_json::to_string(&OrderedFloat(x)).unwrap();
+            let back: OrderedFloat<f32> = serde_json::from_str(&json).unwrap();
+            assert_eq!(back.0.to_bits(), x.to_bits(), "{}", json);
+        }
     }
-}
 
-impl<T: fmt::Debug> fmt::Debug for NotNan<T> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.0.fmt(f)
+    #[test]
+    fn test_non_finite_round_trip_is_exact() {
+        use self::serde::de::value::{Error as ValueError, F32Deserializer, F64Deserializer};
+        use self::serde_test::{assert_de_tokens, Configure};
+
+        // Serialization forwards to the inner float, so binary formats with native floats keep
+        // NaN and the infinities, including the NaN payload and sign. `Token` compares floats
+        // with `==`, so NaN is only checked on the deserializing side.
+        for &x in &[f64::INFINITY, f64::NEG_INFINITY] {
+            assert_tokens(&OrderedFloat(x).compact(), &[Token::F64(x)]);
+            assert_tokens(&NotNan(x).compact(), &[Token::F64(x)]);
+        }
+        assert_tokens(
+            &OrderedFloat(f32::INFINITY).compact(),
+            &[Token::F32(f32::INFINITY)],
+        );
+        assert_de_tokens(&OrderedFloat(f64::NAN).compact(), &[Token::F64(f64::NAN)]);
+
+        let nans = [f64::NAN, -f64::NAN, f64::from_bits(0x7ff8_0000_dead_beef)];
+        for &x in &nans {
+            let de = F64Deserializer::<ValueError>::new(x);
+            let back = OrderedFloat::<f64>::deserialize(de).unwrap();
+            assert_eq!(back.0.to_bits(), x.to_bits());
+            let de = F64Deserializer::<ValueError>::new(x);
+            assert!(NotNan::<f64>::deserialize(de).is_err());
+        }
+        let x = f32::from_bits(0xffc0_beef);
+        let back = OrderedFloat::<f32>::deserialize(F32Deserializer::<ValueError>::new(x)).unwrap();
+        assert_eq!(back.0.to_bits(), x.to_bits());
     }
-}
 
-impl<T: FloatCore + fmt::Display> fmt::Display for NotNan<T> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.0.fmt(f)
+    #[test]
+    fn test_fail_on_nan() {
+        assert_de_tokens_error::<NotNan<f64>>(
+            &[Token::F64(f64::NAN)],
+            "invalid value: floating point `NaN`, expected float (but not NaN)",
+        );
     }
 }
 
-impl NotNan<f64> {
-    /// Converts this [`NotNan`]`<`[`f64`]`>` to a [`NotNan`]`<`[`f32`]`>` while giving up on
-    /// precision, [using `roundTiesToEven` as rounding mode, yielding `Infinity` on
-    /// overflow](https://doc.rust-lang.org/reference/expressions/operator-expr.html#semantics).
+/// Helpers for use with serde's `#[serde(with = "...")]` field attribute.
+#[cfg(feature = "serde")]
+pub mod serde {
+    /// Strict (de)serialization of [`NotNan`](crate::NotNan) fields that only accepts finite
+    /// numbers.
     ///
-    /// Note: For the reverse conversion (from `NotNan<f32>` to `NotNan<f64>`), you can use
-    /// `.into()`.
-    pub fn as_f32(self) -> NotNan<f32> {
-        // This is not destroying invariants, as it is a pure rounding operation. The only two special
-        // cases are where f32 would be overflowing, then the operation yields Infinity, or where
-        // the input is already NaN, in which case the invariant is already broken elsewhere.
-        NotNan(self.0 as f32)
-    }
-}
+    /// The accepted grammar is a number: a float or an integer, as reported by the format. For
+    /// JSON this is exactly a JSON number literal. Everything else is rejected with an error,
+    /// including:
+    ///
+    /// * strings, such as the non-standard `"NaN"`, `"Infinity"`, and `"-Infinity"` encodings;
+    /// * NaN and infinite numbers, for formats that can represent them;
+    /// * numbers that overflow the target type, like `1e300` for `NotNan<f32>`.
+    ///
+    /// Serializing a non-finite value is also an error, so that output is always readable by
+    /// [`deserialize`](strict::deserialize). Values are read with `deserialize_f64`, so this is
+    /// intended for self-describing formats such as JSON.
+    ///
+    /// Use [`strict::option`] for `Option<NotNan<T>>` fields, which also accepts `null` as `None`.
+    ///
+    /// ```
+    /// use ordered_float::NotNan;
+    ///
+    /// # fn main() -> Result<(), serde_json::Error> {
+    /// let mut de = serde_json::Deserializer::from_str("1.5");
+    /// let x: NotNan<f64> = ordered_float::serde::strict::deserialize(&mut de)?;
+    /// assert_eq!(x, 1.5);
+    ///
+    /// let mut de = serde_json::Deserializer::from_str(r#""NaN""#);
+    /// assert!(ordered_float::serde::strict::deserialize::<_, f64>(&mut de).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub mod strict {
+        use crate::NotNan;
+        use ::serde::de::{Deserializer, Error, Unexpected, Visitor};
+        use ::serde::ser::{Error as _, Serialize, Serializer};
+        use core::fmt;
+        use core::marker::PhantomData;
+        use num_traits::float::FloatCore;
+
+        /// Serializes a finite `NotNan`. Returns an error for infinite values.
+        pub fn serialize<S: Serializer, T: FloatCore + Serialize>(
+            value: &NotNan<T>,
+            s: S,
+        ) -> Result<S::Ok, S::Error> {
+            if value.0.is_infinite() {
+                return Err(S::Error::custom(
+                    "cannot serialize an infinite NotNan strictly",
+                ));
+            }
+            value.0.serialize(s)
+        }
 
-impl From<NotNan<f32>> for f32 {
-    #[inline]
-    fn from(value: NotNan<f32>) -> Self {
-        value.0
+        /// Deserializes a `NotNan` from a finite number, rejecting everything else.
+        pub fn deserialize<'de, D: Deserializer<'de>, T: FloatCore>(
+            d: D,
+        ) -> Result<NotNan<T>, D::Error> {
+            d.deserialize_f64(StrictVisitor(PhantomData))
+        }
+
+        struct StrictVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: FloatCore> Visitor<'de> for StrictVisitor<T> {
+            type Value = NotNan<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a finite number")
+            }
+
+            fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+                match T::from(v) {
+                    Some(x) if x.is_finite() => Ok(NotNan(x)),
+                    _ => Err(E::invalid_value(Unexpected::Float(v), &self)),
+                }
+            }
+
+            fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+                match T::from(v) {
+                    Some(x) if x.is_finite() => Ok(NotNan(x)),
+                    _ => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+                }
+            }
+
+            fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+                match T::from(v) {
+                    Some(x) if x.is_finite() => Ok(NotNan(x)),
+                    _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+                }
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+                Err(E::invalid_type(Unexpected::Str(v), &self))
+            }
+        }
+
+        /// Strict (de)serialization of `Option<NotNan<T>>` fields.
+        ///
+        /// `None` is written as a unit (`null` in JSON), and a unit or `null` reads back as
+        /// `None`. `Some` values follow the same grammar as the parent [`strict`](crate::serde::strict) module.
+        pub mod option {
+            use super::StrictVisitor;
+            use crate::NotNan;
+            use ::serde::de::{Deserializer, Error, Visitor};
+            use ::serde::ser::{Serialize, Serializer};
+            use core::fmt;
+            use core::marker::PhantomData;
+            use num_traits::float::FloatCore;
+
+            /// Serializes an optional finite `NotNan`. Returns an error for infinite values.
+            pub fn serialize<S: Serializer, T: FloatCore + Serialize>(
+                value: &Option<NotNan<T>>,
+                s: S,
+            ) -> Result<S::Ok, S::Error> {
+                match value {
+                    Some(v) => super::serialize(v, s),
+                    None => s.serialize_none(),
+                }
+            }
+
+            /// Deserializes `null` as `None` and a finite number as `Some`, rejecting everything
+            /// else.
+            pub fn deserialize<'de, D: Deserializer<'de>, T: FloatCore>(
+                d: D,
+            ) -> Result<Option<NotNan<T>>, D::Error> {
+                d.deserialize_option(OptionVisitor(PhantomData))
+            }
+
+            struct OptionVisitor<T>(PhantomData<T>);
+
+            impl<'de, T: FloatCore> Visitor<'de> for OptionVisitor<T> {
+                type Value = Option<NotNan<T>>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("null or a finite number")
+                }
+
+                fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+                    Ok(None)
+                }
+
+                fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+                    Ok(None)
+                }
+
+                fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+                    d.deserialize_f64(StrictVisitor(PhantomData)).map(Some)
+                }
+            }
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use crate::NotNan;
+            use std::string::ToString;
+
+            fn from_json<T: num_traits::float::FloatCore>(
+                json: &str,
+            ) -> Result<Option<NotNan<T>>, serde_json::Error> {
+                super::option::deserialize(&mut serde_json::Deserializer::from_str(json))
+            }
+
+            #[test]
+            fn test_option_null() {
+                assert_eq!(from_json::<f64>("null").unwrap(), None);
+            }
+
+            #[test]
+            fn test_option_number() {
+                assert_eq!(from_json::<f64>("1.5").unwrap(), Some(NotNan(1.5)));
+                assert_eq!(from_json::<f64>("-3").unwrap(), Some(NotNan(-3.0)));
+                assert_eq!(from_json::<f32>("7").unwrap(), Some(NotNan(7.0)));
+            }
+
+            #[test]
+            fn test_option_rejects_non_finite() {
+                for json in [r#""NaN""#, r#""Infinity""#, r#""-Infinity""#, r#""1.5""#] {
+                    let err = from_json::<f64>(json).unwrap_err();
+                    assert!(
+                        err.to_string().contains("expected a finite number"),
+                        "{}",
+                        err
+                    );
+                }
+                assert!(from_json::<f32>("1e300").is_err());
+            }
+
+            #[test]
+            fn test_serialize() {
+                let mut out = std::vec::Vec::new();
+                let mut ser = serde_json::Serializer::new(&mut out);
+                super::option::serialize(&Some(NotNan(2.5f64)), &mut ser).unwrap();
+                assert_eq!(out, b"2.5");
+
+                let mut out = std::vec::Vec::new();
+                let mut ser = serde_json::Serializer::new(&mut out);
+                super::option::serialize::<_, f64>(&None, &mut ser).unwrap();
+                assert_eq!(out, b"null");
+
+                let mut ser = serde_json::Serializer::new(std::vec::Vec::new());
+                assert!(super::serialize(&NotNan(f64::INFINITY), &mut ser).is_err());
+            }
+        }
     }
-}
 
-impl From<NotNan<f64>> for f64 {
-    #[inline]
-    fn from(value: NotNan<f64>) -> Self {
-        value.0
+    /// (De)serialization of maps keyed by [`NotNan`](crate::NotNan), writing the keys as strings.
+    ///
+    /// This is specifically for formats whose map keys must be strings, such as JSON objects. Use
+    /// it with `#[serde(with = "ordered_float::serde::map_key")]` on the map field; it works with
+    /// any map type that can be iterated by reference and built with `Default` and `Extend`, such
+    /// as `HashMap` and `BTreeMap`.
+    ///
+    /// Keys are written with `Display`, which gives the shortest decimal string that parses back
+    /// to the same value (infinities are written as `inf` and `-inf`). Keys are read back with
+    /// `FromStr`, and a key that parses as NaN is rejected with an error.
+    ///
+    /// ```
+    /// use ordered_float::serde::map_key;
+    /// use ordered_float::NotNan;
+    /// use std::collections::BTreeMap;
+    ///
+    /// # fn main() -> Result<(), serde_json::Error> {
+    /// let mut bins = BTreeMap::new();
+    /// bins.insert(NotNan::new(0.5).unwrap(), 3);
+    /// bins.insert(NotNan::new(-2.0).unwrap(), 1);
+    ///
+    /// let mut json = Vec::new();
+    /// map_key::serialize(&bins, &mut serde_json::Serializer::new(&mut json))?;
+    /// assert_eq!(json, br#"{"-2":1,"0.5":3}"#);
+    ///
+    /// let mut de = serde_json::Deserializer::from_slice(&json);
+    /// let back: BTreeMap<NotNan<f64>, u32> = map_key::deserialize(&mut de)?;
+    /// assert_eq!(back, bins);
+    ///
+    /// let mut de = serde_json::Deserializer::from_str(r#"{"NaN":1}"#);
+    /// let bad: Result<BTreeMap<NotNan<f64>, u32>, _> = map_key::deserialize(&mut de);
+    /// assert!(bad.is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub mod map_key {
+        use crate::NotNan;
+        use ::serde::de::{Deserialize, Deserializer, Error, MapAccess, Visitor};
+        use ::serde::ser::{Serialize, Serializer};
+        use core::fmt;
+        use core::marker::PhantomData;
+        use core::str::FromStr;
+        use num_traits::float::FloatCore;
+
+        /// Serializes a map, writing each `NotNan` key as a string.
+        pub fn serialize<'a, S, M, T, V>(map: &'a M, s: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+            &'a M: IntoIterator<Item = (&'a NotNan<T>, &'a V)>,
+            T: FloatCore + fmt::Display + 'a,
+            V: Serialize + 'a,
+        {
+            s.collect_map(map.into_iter().map(|(k, v)| (KeyStr(k), v)))
+        }
+
+        /// Deserializes a map whose keys are strings holding non-NaN numbers.
+        pub fn deserialize<'de, D, M, T, V>(d: D) -> Result<M, D::Error>
+        where
+            D: Deserializer<'de>,
+            M: Default + Extend<(NotNan<T>, V)>,
+            T: FloatCore + FromStr,
+            T::Err: fmt::Display,
+            V: Deserialize<'de>,
+        {
+            d.deserialize_map(MapVisitor(PhantomData))
+        }
+
+        struct KeyStr<'a, T>(&'a NotNan<T>);
+
+        impl<T: FloatCore + fmt::Display> Serialize for KeyStr<'_, T> {
+            fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                s.collect_str(&self.0 .0)
+            }
+        }
+
+        struct Key<T>(NotNan<T>);
+
+        impl<'de, T> Deserialize<'de> for Key<T>
+        where
+            T: FloatCore + FromStr,
+            T::Err: fmt::Display,
+        {
+            fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                d.deserialize_str(KeyVisitor(PhantomData))
+            }
+        }
+
+        struct KeyVisitor<T>(PhantomData<T>);
+
+        impl<'de, T> Visitor<'de> for KeyVisitor<T>
+        where
+            T: FloatCore + FromStr,
+            T::Err: fmt::Display,
+        {
+            type Value = Key<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a string holding a number that is not NaN")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse::<NotNan<T>>().map(Key).map_err(E::custom)
+            }
+        }
+
+        struct MapVisitor<M, T, V>(PhantomData<(M, T, V)>);
+
+        impl<'de, M, T, V> Visitor<'de> for MapVisitor<M, T, V>
+        where
+            M: Default + Extend<(NotNan<T>, V)>,
+            T: FloatCore + FromStr,
+            T::Err: fmt::Display,
+            V: Deserialize<'de>,
+        {
+            type Value = M;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map with numeric string keys")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<M, A::Error> {
+                let mut map = M::default();
+                while let Some((Key(k), v)) = access.next_entry::<Key<T>, V>()? {
+                    map.extend(core::iter::once((k, v)));
+                }
+                Ok(map)
+            }
+        }
+
+        #[cfg(all(test, feature = "std"))]
+        mod tests {
+            use crate::NotNan;
+            use std::collections::HashMap;
+
+            fn to_json(map: &HashMap<NotNan<f64>, i32>) -> std::vec::Vec<u8> {
+                let mut out = std::vec::Vec::new();
+                super::serialize(map, &mut serde_json::Serializer::new(&mut out)).unwrap();
+                out
+            }
+
+            fn from_json(json: &[u8]) -> Result<HashMap<NotNan<f64>, i32>, serde_json::Error> {
+                super::deserialize(&mut serde_json::Deserializer::from_slice(json))
+            }
+
+            #[test]
+            fn test_hash_map_round_trip() {
+                let mut map = HashMap::new();
+                map.insert(NotNan(0.1f64), 1);
+                map.insert(NotNan(-3.0f64), 2);
+                map.insert(NotNan(f64::INFINITY), 3);
+
+                let json = to_json(&map);
+                let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+                assert_eq!(value["0.1"], 1);
+                assert_eq!(value["-3"], 2);
+                assert_eq!(value["inf"], 3);
+
+                assert_eq!(from_json(&json).unwrap(), map);
+            }
+
+            #[test]
+            fn test_rejects_bad_keys() {
+                for json in [r#"{"NaN":1}"#, r#"{"abc":1}"#, r#"[1]"#] {
+                    assert!(from_json(json.as_bytes()).is_err());
+                }
+            }
+        }
     }
 }
 
-impl TryFrom<f32> for NotNan<f32> {
-    type Error = FloatIsNan;
-    #[inline]
-    fn try_from(v: f32) -> Result<Self, Self::Error> {
-        NotNan::new(v)
+#[cfg(any(feature = "rkyv_16", feature = "rkyv_32", feature = "rkyv_64"))]
+mod impl_rkyv {
+    use super::{NotNan, OrderedFloat};
+    use num_traits::float::FloatCore;
+    #[cfg(test)]
+    use rkyv::{archived_root, ser::Serializer};
+    use rkyv::{Archive, Deserialize, Fallible, Serialize};
+
+    #[cfg(test)]
+    type DefaultSerializer = rkyv::ser::serializers::CoreSerializer<16, 16>;
+    #[cfg(test)]
+    type DefaultDeserializer = rkyv::Infallible;
+
+    impl<T: FloatCore + Archive> Archive for OrderedFloat<T> {
+        type Archived = OrderedFloat<T::Archived>;
+
+        type Resolver = T::Resolver;
+
+        unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+            self.0.resolve(pos, resolver, out.cast())
+        }
     }
-}
 
-impl TryFrom<f64> for NotNan<f64> {
-    type Error = FloatIsNan;
-    #[inline]
-    fn try_from(v: f64) -> Result<Self, Self::Error> {
-        NotNan::new(v)
+    impl<T: FloatCore + Serialize<S>, S: Fallible + ?Sized> Serialize<S> for OrderedFloat<T> {
+        fn serialize(&self, s: &mut S) -> Result<Self::Resolver, S::Error> {
+            self.0.serialize(s)
+        }
     }
-}
 
-macro_rules! impl_from_int_primitive {
-    ($primitive:ty, $inner:ty) => {
-        impl From<$primitive> for NotNan<$inner> {
-            fn from(source: $primitive) -> Self {
-                // the primitives with which this macro will be called cannot hold a value that
-                // f64::from would convert to NaN, so this does not hurt invariants
-                NotNan(<$inner as From<$primitive>>::from(source))
-            }
+    impl<T: FloatCore, AT: Deserialize<T, D>, D: Fallible + ?Sized> Deserialize<OrderedFloat<T>, D>
+        for OrderedFloat<AT>
+    {
+        fn deserialize(&self, d: &mut D) -> Result<OrderedFloat<T>, D::Error> {
+            self.0.deserialize(d).map(OrderedFloat)
         }
-    };
-}
+    }
 
-impl_from_int_primitive!(i8, f64);
-impl_from_int_primitive!(i16, f64);
-impl_from_int_primitive!(i32, f64);
-impl_from_int_primitive!(u8, f64);
-impl_from_int_primitive!(u16, f64);
-impl_from_int_primitive!(u32, f64);
+    impl<T: FloatCore + Archive> Archive for NotNan<T> {
+        type Archived = NotNan<T::Archived>;
 
-impl_from_int_primitive!(i8, f32);
-impl_from_int_primitive!(i16, f32);
-impl_from_int_primitive!(u8, f32);
-impl_from_int_primitive!(u16, f32);
+        type Resolver = T::Resolver;
 
-impl From<NotNan<f32>> for NotNan<f64> {
-    #[inline]
-    fn from(v: NotNan<f32>) -> NotNan<f64> {
-        unsafe { NotNan::new_unchecked(v.0 as f64) }
+        unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+            self.0.resolve(pos, resolver, out.cast())
+        }
     }
-}
-
-impl<T: FloatCore> Deref for NotNan<T> {
-    type Target = T;
 
-    #[inline]
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    impl<T: FloatCore + Serialize<S>, S: Fallible + ?Sized> Serialize<S> for NotNan<T> {
+        fn serialize(&self, s: &mut S) -> Result<Self::Resolver, S::Error> {
+            self.0.serialize(s)
+        }
     }
-}
 
-impl<T: FloatCore + PartialEq> Eq for NotNan<T> {}
+    /// Deserialization re-checks the value, independently of `rkyv_ck`, so that an archive which
+    /// was not validated cannot produce a `NotNan` holding NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the archived value is NaN. The deserializer's error type is opaque here (it is
+    /// `Infallible` for `rkyv::Infallible`), so there is no error value to return instead. Use
+    /// [`NotNan::try_deserialize`] to get an error, or enable `rkyv_ck` and use
+    /// `check_archived_root` to reject such archives up front.
+    impl<T: FloatCore, AT: Deserialize<T, D>, D: Fallible + ?Sized> Deserialize<NotNan<T>, D>
+        for NotNan<AT>
+    {
+        fn deserialize(&self, d: &mut D) -> Result<NotNan<T>, D::Error> {
+            self.0
+                .deserialize(d)
+                .map(|v| NotNan::new(v).expect("archived NotNan is NaN"))
+        }
+    }
 
-impl<T: FloatCore> PartialEq<T> for NotNan<T> {
-    #[inline]
-    fn eq(&self, other: &T) -> bool {
-        self.0 == *other
+    impl<AT> NotNan<AT> {
+        /// Deserializes an archived `NotNan` like its `Deserialize` impl, but reports a NaN as an
+        /// error instead of panicking. The outer `Result` holds the deserializer's error, and the
+        /// inner one `Err(FloatIsNan)` if the archived value is NaN, which can only happen for an
+        /// archive that wasn't validated with `rkyv_ck`.
+        ///
+        /// ```
+        /// use ordered_float::NotNan;
+        /// use rkyv::ser::{serializers::CoreSerializer, Serializer};
+        /// use rkyv::{archived_root, Infallible};
+        ///
+        /// let mut serializer = CoreSerializer::<16, 0>::default();
+        /// serializer.serialize_value(&NotNan::new(1.5f64).unwrap()).unwrap();
+        /// let len = serializer.pos();
+        /// let bytes = serializer.into_serializer().into_inner();
+        /// let archived = unsafe { archived_root::<NotNan<f64>>(&bytes[..len]) };
+        /// let value: NotNan<f64> = archived.try_deserialize(&mut Infallible).unwrap().unwrap();
+        /// assert_eq!(value, 1.5);
+        /// ```
+        #[allow(clippy::type_complexity)]
+        pub fn try_deserialize<T, D>(
+            &self,
+            d: &mut D,
+        ) -> Result<Result<NotNan<T>, crate::FloatIsNan>, D::Error>
+        where
+            T: FloatCore,
+            AT: Deserialize<T, D>,
+            D: Fallible + ?Sized,
+        {
+            self.0.deserialize(d).map(NotNan::new)
+        }
     }
-}
 
-/// Adds a float directly.
-///
-/// Panics if the provided value is NaN or the computation results in NaN
-impl<T: FloatCore> Add<T> for NotNan<T> {
-    type Output = Self;
+    macro_rules! rkyv_eq_ord {
+        ($main:ident, $float:ty, $rend:ty) => {
+            impl $main<$rend> {
+                /// Get the archived value as a native-endian float, without deserializing.
+                ///
+                /// Archived floats use these endian-specific representations when `rkyv`'s
+                /// `archive_le` or `archive_be` feature is enabled.
+                #[inline]
+                pub fn get(&self) -> $float {
+                    self.0.value()
+                }
+            }
 
-    #[inline]
-    fn add(self, other: T) -> Self {
-        NotNan::new(self.0 + other).expect("Addition resulted in NaN")
-    }
-}
+            // Compare as the wrapper rather than as the inner floats, so that an archived
+            // `OrderedFloat` holding NaN equals a native one, as it would after deserializing.
+            impl PartialEq<$main<$float>> for $main<$rend> {
+                fn eq(&self, other: &$main<$float>) -> bool {
+                    $main(self.0.value()) == *other
+                }
+            }
+            impl PartialEq<$main<$rend>> for $main<$float> {
+                fn eq(&self, other: &$main<$rend>) -> bool {
+                    *self == $main(other.0.value())
+                }
+            }
 
-/// Adds a float directly.
-///
-/// Panics if the provided value is NaN.
-impl<T: FloatCore + Sum> Sum for NotNan<T> {
-    fn sum<I: Iterator<Item = NotNan<T>>>(iter: I) -> Self {
-        NotNan::new(iter.map(|v| v.0).sum()).expect("Sum resulted in NaN")
+            impl PartialOrd<$main<$float>> for $main<$rend> {
+                fn partial_cmp(&self, other: &$main<$float>) -> Option<core::cmp::Ordering> {
+                    $main(self.0.value()).partial_cmp(other)
+                }
+            }
+
+            impl PartialOrd<$main<$rend>> for $main<$float> {
+                fn partial_cmp(&self, other: &$main<$rend>) -> Option<core::cmp::Ordering> {
+                    self.partial_cmp(&$main(other.0.value()))
+                }
+            }
+        };
     }
-}
 
-impl<'a, T: FloatCore + Sum + 'a> Sum<&'a NotNan<T>> for NotNan<T> {
-    #[inline]
-    fn sum<I: Iterator<Item = &'a NotNan<T>>>(iter: I) -> Self {
-        iter.cloned().sum()
+    // `rkyv` only re-exports `rend` with its `rend` feature, which our dependency always enables,
+    // so this path does not depend on `archive_le`/`archive_be` or `rkyv_ck`. The endian types
+    // are named only here.
+    use rkyv::rend::{f32_be, f32_le, f64_be, f64_le};
+
+    rkyv_eq_ord! { OrderedFloat, f32, f32_le }
+    rkyv_eq_ord! { OrderedFloat, f32, f32_be }
+    rkyv_eq_ord! { OrderedFloat, f64, f64_le }
+    rkyv_eq_ord! { OrderedFloat, f64, f64_be }
+    rkyv_eq_ord! { NotNan, f32, f32_le }
+    rkyv_eq_ord! { NotNan, f32, f32_be }
+    rkyv_eq_ord! { NotNan, f64, f64_le }
+    rkyv_eq_ord! { NotNan, f64, f64_be }
+
+    macro_rules! rkyv_native_get {
+        ($main:ident, $float:ty) => {
+            impl $main<$float> {
+                /// Get the archived value as a float, without deserializing.
+                ///
+                /// Without `rkyv`'s `archive_le` or `archive_be` feature, archived floats are the
+                /// native types, so this is the same as reading the inner value.
+                #[inline]
+                pub fn get(&self) -> $float {
+                    self.0
+                }
+            }
+        };
     }
-}
 
-/// Subtracts a float directly.
-///
-/// Panics if the provided value is NaN or the computation results in NaN
-impl<T: FloatCore> Sub<T> for NotNan<T> {
-    type Output = Self;
+    rkyv_native_get! { OrderedFloat, f32 }
+    rkyv_native_get! { OrderedFloat, f64 }
+    rkyv_native_get! { NotNan, f32 }
+    rkyv_native_get! { NotNan, f64 }
 
-    #[inline]
-    fn sub(self, other: T) -> Self {
-        NotNan::new(self.0 - other).expect("Subtraction resulted in NaN")
-    }
-}
+    #[cfg(feature = "rkyv_ck")]
+    use super::FloatIsNan;
+    #[cfg(feature = "rkyv_ck")]
+    use core::convert::Infallible;
+    #[cfg(feature = "rkyv_ck")]
+    use rkyv::bytecheck::CheckBytes;
 
-/// Multiplies a float directly.
-///
-/// Panics if the provided value is NaN or the computation results in NaN
-impl<T: FloatCore> Mul<T> for NotNan<T> {
-    type Output = Self;
+    // No `FloatCore` bound: with `rkyv`'s `archive_le` or `archive_be` feature the archived
+    // inner type is an endian wrapper from `rend`, not a float.
+    #[cfg(feature = "rkyv_ck")]
+    impl<C: ?Sized, T: CheckBytes<C>> CheckBytes<C> for OrderedFloat<T> {
+        type Error = Infallible;
 
-    #[inline]
-    fn mul(self, other: T) -> Self {
-        NotNan::new(self.0 * other).expect("Multiplication resulted in NaN")
+        #[inline]
+        unsafe fn check_bytes<'a>(value: *const Self, _: &mut C) -> Result<&'a Self, Self::Error> {
+            Ok(&*value)
+        }
     }
-}
 
-impl<T: FloatCore + Product> Product for NotNan<T> {
-    fn product<I: Iterator<Item = NotNan<T>>>(iter: I) -> Self {
-        NotNan::new(iter.map(|v| v.0).product()).expect("Product resulted in NaN")
+    // Checking for NaN needs the native value, so this is implemented for each archived float
+    // type: the primitives (the default) and the `rend` types (`archive_le`/`archive_be`).
+    #[cfg(feature = "rkyv_ck")]
+    macro_rules! not_nan_check_bytes {
+        ($($archived:ty => $is_nan:expr),*) => {$(
+            impl<C: ?Sized> CheckBytes<C> for NotNan<$archived> {
+                type Error = FloatIsNan;
+
+                #[inline]
+                unsafe fn check_bytes<'a>(
+                    value: *const Self,
+                    _: &mut C,
+                ) -> Result<&'a Self, Self::Error> {
+                    let is_nan: fn(&$archived) -> bool = $is_nan;
+                    if is_nan(&*(value as *const $archived)) {
+                        Err(FloatIsNan)
+                    } else {
+                        Ok(&*value)
+                    }
+                }
+            }
+        )*};
     }
-}
 
-impl<'a, T: FloatCore + Product + 'a> Product<&'a NotNan<T>> for NotNan<T> {
-    #[inline]
-    fn product<I: Iterator<Item = &'a NotNan<T>>>(iter: I) -> Self {
-        iter.cloned().product()
+    #[cfg(feature = "rkyv_ck")]
+    not_nan_check_bytes! {
+        f32 => |x| x.is_nan(),
+        f64 => |x| x.is_nan(),
+        f32_le => |x| x.value().is_nan(),
+        f32_be => |x| x.value().is_nan(),
+        f64_le => |x| x.value().is_nan(),
+        f64_be => |x| x.value().is_nan()
     }
-}
 
-/// Divides a float directly.
-///
-/// Panics if the provided value is NaN or the computation results in NaN
-impl<T: FloatCore> Div<T> for NotNan<T> {
-    type Output = Self;
+    #[test]
+    fn test_ordered_float() {
+        let float = OrderedFloat(1.0f64);
+        let mut serializer = DefaultSerializer::default();
+        serializer
+            .serialize_value(&float)
+            .expect("failed to archive value");
+        let len = serializer.pos();
+        let buffer = serializer.into_serializer().into_inner();
 
-    #[inline]
-    fn div(self, other: T) -> Self {
-        NotNan::new(self.0 / other).expect("Division resulted in NaN")
+        let archived_value = unsafe { archived_root::<OrderedFloat<f64>>(&buffer[0..len]) };
+        assert_eq!(archived_value, &float);
+        let mut deserializer = DefaultDeserializer::default();
+        let deser_float: OrderedFloat<f64> = archived_value.deserialize(&mut deserializer).unwrap();
+        assert_eq!(deser_float, float);
     }
-}
 
-/// Calculates `%` with a float directly.
-///
-/// Panics if the provided value is NaN or the computation results in NaN
-impl<T: FloatCore> Rem<T> for NotNan<T> {
-    type Output = Self;
+    #[test]
+    fn test_not_nan() {
+        let float = NotNan(1.0f64);
+        let mut serializer = DefaultSerializer::default();
+        serializer
+            .serialize_value(&float)
+            .expect("failed to archive value");
+        let len = serializer.pos();
+        let buffer = serializer.into_serializer().into_inner();
 
-    #[inline]
-    fn rem(self, other: T) -> Self {
-        NotNan::new(self.0 % other).expect("Rem resulted in NaN")
+        let archived_value = unsafe { archived_root::<NotNan<f64>>(&buffer[0..len]) };
+        assert_eq!(archived_value, &float);
+        let mut deserializer = DefaultDeserializer::default();
+        let deser_float: NotNan<f64> = archived_value.deserialize(&mut deserializer).unwrap();
+        assert_eq!(deser_float, float);
+        let deser_float: NotNan<f64> = archived_value
+            .try_deserialize(&mut deserializer)
+            .unwrap()
+            .unwrap();
+        assert_eq!(deser_float, float);
+    }
+
+    // Crafts a `NotNan` archive holding NaN by archiving an `OrderedFloat` with the same layout.
+    #[cfg(test)]
+    fn archive_nan() -> rkyv::AlignedBytes<16> {
+        let mut serializer = DefaultSerializer::default();
+        serializer
+            .serialize_value(&OrderedFloat(f64::NAN))
+            .expect("failed to archive value");
+        serializer.into_serializer().into_inner()
+    }
+
+    #[test]
+    #[should_panic(expected = "archived NotNan is NaN")]
+    fn test_not_nan_deserialize_panics_on_nan() {
+        let buffer = archive_nan();
+        let archived_value = unsafe { archived_root::<NotNan<f64>>(&buffer[..8]) };
+        let mut deserializer = DefaultDeserializer::default();
+        let _: Result<NotNan<f64>, _> = archived_value.deserialize(&mut deserializer);
     }
-}
 
-macro_rules! impl_not_nan_binop {
-    ($imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident) => {
-        impl<T: FloatCore> $imp for NotNan<T> {
-            type Output = Self;
+    #[test]
+    fn test_not_nan_try_deserialize_rejects_nan() {
+        let buffer = archive_nan();
+        let archived_value = unsafe { archived_root::<NotNan<f64>>(&buffer[..8]) };
+        let result: Result<NotNan<f64>, _> = archived_value
+            .try_deserialize(&mut DefaultDeserializer::default())
+            .unwrap();
+        assert_eq!(result, Err(crate::FloatIsNan));
+    }
 
-            #[inline]
-            fn $method(self, other: Self) -> Self {
-                self.$method(other.0)
-            }
-        }
+    #[cfg(test)]
+    fn archive<T: Serialize<DefaultSerializer>>(value: &T) -> rkyv::AlignedBytes<16> {
+        let mut serializer = DefaultSerializer::default();
+        serializer
+            .serialize_value(value)
+            .expect("failed to archive value");
+        serializer.into_serializer().into_inner()
+    }
 
-        impl<T: FloatCore> $imp<&T> for NotNan<T> {
-            type Output = NotNan<T>;
+    #[test]
+    fn test_archived_get() {
+        let buffer = archive(&OrderedFloat(1.5f64));
+        let archived = unsafe { archived_root::<OrderedFloat<f64>>(&buffer[..8]) };
+        assert_eq!(archived.get(), 1.5f64);
 
-            #[inline]
-            fn $method(self, other: &T) -> Self::Output {
-                self.$method(*other)
-            }
-        }
+        let buffer = archive(&OrderedFloat(1.5f32));
+        let archived = unsafe { archived_root::<OrderedFloat<f32>>(&buffer[..4]) };
+        assert_eq!(archived.get(), 1.5f32);
 
-        impl<T: FloatCore> $imp<&Self> for NotNan<T> {
-            type Output = NotNan<T>;
+        let buffer = archive(&NotNan(-2.5f64));
+        let archived = unsafe { archived_root::<NotNan<f64>>(&buffer[..8]) };
+        assert_eq!(archived.get(), -2.5f64);
 
-            #[inline]
-            fn $method(self, other: &Self) -> Self::Output {
-                self.$method(other.0)
-            }
-        }
+        let buffer = archive(&NotNan(-2.5f32));
+        let archived = unsafe { archived_root::<NotNan<f32>>(&buffer[..4]) };
+        assert_eq!(archived.get(), -2.5f32);
+    }
 
-        impl<T: FloatCore> $imp for &NotNan<T> {
-            type Output = NotNan<T>;
+    #[test]
+    fn test_archived_cmp_follows_wrapper() {
+        use core::cmp::Ordering;
 
-            #[inline]
-            fn $method(self, other: Self) -> Self::Output {
-                (*self).$method(other.0)
-            }
-        }
+        let nan = OrderedFloat(f64::NAN);
+        assert_eq!(OrderedFloat(f64_le::new(f64::NAN)), nan);
+        assert_eq!(nan, OrderedFloat(f64_be::new(f64::NAN)));
+        assert_eq!(
+            OrderedFloat(f32_le::new(f32::NAN)).partial_cmp(&OrderedFloat(f32::INFINITY)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            OrderedFloat(1.0f32).partial_cmp(&OrderedFloat(f32_be::new(f32::NAN))),
+            Some(Ordering::Less)
+        );
+        assert_eq!(NotNan(f64_be::new(-0.0)), NotNan(0.0f64));
+    }
 
-        impl<T: FloatCore> $imp<NotNan<T>> for &NotNan<T> {
-            type Output = NotNan<T>;
+    // Archives a `(u32, usize, T)` tuple, so the `usize` exercises the selected `rkyv_*` size
+    // feature alongside the float.
+    #[cfg(test)]
+    fn archive_with_usize<T>(value: T) -> rkyv::AlignedBytes<32>
+    where
+        T: Serialize<rkyv::ser::serializers::CoreSerializer<32, 16>>,
+    {
+        let mut serializer = rkyv::ser::serializers::CoreSerializer::<32, 16>::default();
+        serializer
+            .serialize_value(&(7u32, 11usize, value))
+            .expect("failed to archive value");
+        serializer.into_serializer().into_inner()
+    }
 
-            #[inline]
-            fn $method(self, other: NotNan<T>) -> Self::Output {
-                (*self).$method(other.0)
+    #[test]
+    fn test_archive_next_to_usize() {
+        let buffer = archive_with_usize(OrderedFloat(2.5f64));
+        let len = core::mem::size_of::<rkyv::Archived<(u32, usize, OrderedFloat<f64>)>>();
+        let archived = unsafe { archived_root::<(u32, usize, OrderedFloat<f64>)>(&buffer[..len]) };
+        assert_eq!(archived.1, 11);
+        assert_eq!(archived.2, OrderedFloat(2.5f64));
+        assert_eq!(
+            core::mem::size_of::<rkyv::Archived<usize>>(),
+            if cfg!(feature = "rkyv_16") {
+                2
+            } else if cfg!(feature = "rkyv_64") {
+                8
+            } else {
+                4
             }
-        }
-
-        impl<T: FloatCore> $imp<T> for &NotNan<T> {
-            type Output = NotNan<T>;
+        );
+    }
 
-            #[inline]
-            fn $method(self, other: T) -> Self::Output {
-                (*self).$method(other)
-            }
-        }
+    #[cfg(feature = "rkyv_ck")]
+    #[test]
+    fn test_check_bytes() {
+        type Tuple<T> = (u32, usize, T);
+
+        let buffer = archive_with_usize(NotNan(-1.5f32));
+        let len = core::mem::size_of::<rkyv::Archived<Tuple<NotNan<f32>>>>();
+        let archived = rkyv::check_archived_root::<Tuple<NotNan<f32>>>(&buffer[..len]).unwrap();
+        assert_eq!(archived.2, NotNan(-1.5f32));
+
+        let buffer = archive_with_usize(OrderedFloat(f64::NAN));
+        let len = core::mem::size_of::<rkyv::Archived<Tuple<OrderedFloat<f64>>>>();
+        let archived =
+            rkyv::check_archived_root::<Tuple<OrderedFloat<f64>>>(&buffer[..len]).unwrap();
+        assert_eq!(archived.2, OrderedFloat(f64::NAN));
+        // The same bytes are rejected as an archived `NotNan`.
+        assert!(rkyv::check_archived_root::<Tuple<NotNan<f64>>>(&buffer[..len]).is_err());
+    }
+}
 
-        impl<T: FloatCore> $imp<&T> for &NotNan<T> {
-            type Output = NotNan<T>;
+#[cfg(feature = "speedy")]
+mod impl_speedy {
+    use super::{NotNan, OrderedFloat};
+    use num_traits::float::FloatCore;
+    use speedy::{Context, Readable, Reader, Writable, Writer};
 
-            #[inline]
-            fn $method(self, other: &T) -> Self::Output {
-                (*self).$method(*other)
-            }
+    impl<C, T> Writable<C> for OrderedFloat<T>
+    where
+        C: Context,
+        T: Writable<C>,
+    {
+        fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+            self.0.write_to(writer)
         }
 
-        impl<T: FloatCore + $assign_imp> $assign_imp<T> for NotNan<T> {
-            #[inline]
-            fn $assign_method(&mut self, other: T) {
-                *self = (*self).$method(other);
-            }
+        fn bytes_needed(&self) -> Result<usize, C::Error> {
+            self.0.bytes_needed()
         }
+    }
 
-        impl<T: FloatCore + $assign_imp> $assign_imp<&T> for NotNan<T> {
-            #[inline]
-            fn $assign_method(&mut self, other: &T) {
-                *self = (*self).$method(*other);
-            }
+    impl<C, T> Writable<C> for NotNan<T>
+    where
+        C: Context,
+        T: Writable<C>,
+    {
+        fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+            self.0.write_to(writer)
         }
 
-        impl<T: FloatCore + $assign_imp> $assign_imp for NotNan<T> {
-            #[inline]
-            fn $assign_method(&mut self, other: Self) {
-                (*self).$assign_method(other.0);
-            }
+        fn bytes_needed(&self) -> Result<usize, C::Error> {
+            self.0.bytes_needed()
         }
+    }
 
-        impl<T: FloatCore + $assign_imp> $assign_imp<&Self> for NotNan<T> {
-            #[inline]
-            fn $assign_method(&mut self, other: &Self) {
-                (*self).$assign_method(other.0);
-            }
+    impl<'a, T, C: Context> Readable<'a, C> for OrderedFloat<T>
+    where
+        T: Readable<'a, C>,
+    {
+        fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+            T::read_from(reader).map(OrderedFloat)
         }
-    };
-}
 
-impl_not_nan_binop! {Add, add, AddAssign, add_assign}
-impl_not_nan_binop! {Sub, sub, SubAssign, sub_assign}
-impl_not_nan_binop! {Mul, mul, MulAssign, mul_assign}
-impl_not_nan_binop! {Div, div, DivAssign, div_assign}
-impl_not_nan_binop! {Rem, rem, RemAssign, rem_assign}
-
-// Will panic if NaN value is return from the operation
-macro_rules! impl_not_nan_pow {
-    ($inner:ty, $rhs:ty) => {
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl Pow<$rhs> for NotNan<$inner> {
-            type Output = NotNan<$inner>;
-            #[inline]
-            fn pow(self, rhs: $rhs) -> NotNan<$inner> {
-                NotNan::new(<$inner>::pow(self.0, rhs)).expect("Pow resulted in NaN")
-            }
+        fn minimum_bytes_needed() -> usize {
+            T::minimum_bytes_needed()
         }
+    }
 
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl<'a> Pow<&'a $rhs> for NotNan<$inner> {
-            type Output = NotNan<$inner>;
-            #[inline]
-            fn pow(self, rhs: &'a $rhs) -> NotNan<$inner> {
-                NotNan::new(<$inner>::pow(self.0, *rhs)).expect("Pow resulted in NaN")
-            }
+    impl<'a, T: FloatCore, C: Context> Readable<'a, C> for NotNan<T>
+    where
+        T: Readable<'a, C>,
+    {
+        fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+            let value: T = reader.read_value()?;
+            Self::new(value).map_err(|error| {
+                speedy::Error::custom(std::format!("failed to read NotNan: {}", error)).into()
+            })
         }
 
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl<'a> Pow<$rhs> for &'a NotNan<$inner> {
-            type Output = NotNan<$inner>;
-            #[inline]
-            fn pow(self, rhs: $rhs) -> NotNan<$inner> {
-                NotNan::new(<$inner>::pow(self.0, rhs)).expect("Pow resulted in NaN")
-            }
+        fn minimum_bytes_needed() -> usize {
+            T::minimum_bytes_needed()
         }
+    }
 
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl<'a, 'b> Pow<&'a $rhs> for &'b NotNan<$inner> {
-            type Output = NotNan<$inner>;
-            #[inline]
-            fn pow(self, rhs: &'a $rhs) -> NotNan<$inner> {
-                NotNan::new(<$inner>::pow(self.0, *rhs)).expect("Pow resulted in NaN")
-            }
-        }
-    };
+    #[test]
+    fn test_ordered_float() {
+        let float = OrderedFloat(1.0f64);
+        let buffer = float.write_to_vec().unwrap();
+        let deser_float: OrderedFloat<f64> = OrderedFloat::read_from_buffer(&buffer).unwrap();
+        assert_eq!(deser_float, float);
+    }
+
+    #[test]
+    fn test_not_nan() {
+        let float = NotNan(1.0f64);
+        let buffer = float.write_to_vec().unwrap();
+        let deser_float: NotNan<f64> = NotNan::read_from_buffer(&buffer).unwrap();
+        assert_eq!(deser_float, float);
+    }
+
+    #[test]
+    fn test_not_nan_with_nan() {
+        let nan_buf = f64::nan().write_to_vec().unwrap();
+        let nan_err: Result<NotNan<f64>, _> = NotNan::read_from_buffer(&nan_buf);
+        assert!(nan_err.is_err());
+    }
 }
 
-impl_not_nan_pow! {f32, i8}
-impl_not_nan_pow! {f32, i16}
-impl_not_nan_pow! {f32, u8}
-impl_not_nan_pow! {f32, u16}
-impl_not_nan_pow! {f32, i32}
-impl_not_nan_pow! {f64, i8}
-impl_not_nan_pow! {f64, i16}
-impl_not_nan_pow! {f64, u8}
-impl_not_nan_pow! {f64, u16}
-impl_not_nan_pow! {f64, i32}
-impl_not_nan_pow! {f32, f32}
-impl_not_nan_pow! {f64, f32}
-impl_not_nan_pow! {f64, f64}
+#[cfg(feature = "borsh")]
+mod impl_borsh {
+    extern crate borsh;
+    use super::{NotNan, OrderedFloat};
+    use num_traits::float::FloatCore;
 
-// This also should panic on NaN
-macro_rules! impl_not_nan_self_pow {
-    ($base:ty, $exp:ty) => {
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl Pow<NotNan<$exp>> for NotNan<$base> {
-            type Output = NotNan<$base>;
-            #[inline]
-            fn pow(self, rhs: NotNan<$exp>) -> NotNan<$base> {
-                NotNan::new(self.0.pow(rhs.0)).expect("Pow resulted in NaN")
-            }
+    impl<T> borsh::BorshSerialize for OrderedFloat<T>
+    where
+        T: borsh::BorshSerialize,
+    {
+        #[inline]
+        fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+            <T as borsh::BorshSerialize>::serialize(&self.0, writer)
         }
+    }
 
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl<'a> Pow<&'a NotNan<$exp>> for NotNan<$base> {
-            type Output = NotNan<$base>;
-            #[inline]
-            fn pow(self, rhs: &'a NotNan<$exp>) -> NotNan<$base> {
-                NotNan::new(self.0.pow(rhs.0)).expect("Pow resulted in NaN")
-            }
+    impl<T> borsh::BorshDeserialize for OrderedFloat<T>
+    where
+        T: borsh::BorshDeserialize,
+    {
+        #[inline]
+        fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+            <T as borsh::BorshDeserialize>::deserialize_reader(reader).map(Self)
         }
+    }
 
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl<'a> Pow<NotNan<$exp>> for &'a NotNan<$base> {
-            type Output = NotNan<$base>;
-            #[inline]
-            fn pow(self, rhs: NotNan<$exp>) -> NotNan<$base> {
-                NotNan::new(self.0.pow(rhs.0)).expect("Pow resulted in NaN")
-            }
+    impl<T> borsh::BorshSerialize for NotNan<T>
+    where
+        T: borsh::BorshSerialize,
+    {
+        #[inline]
+        fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+            <T as borsh::BorshSerialize>::serialize(&self.0, writer)
         }
+    }
 
-        #[cfg(any(feature = "std", feature = "libm"))]
-        impl<'a, 'b> Pow<&'a NotNan<$exp>> for &'b NotNan<$base> {
-            type Output = NotNan<$base>;
-            #[inline]
-            fn pow(self, rhs: &'a NotNan<$exp>) -> NotNan<$base> {
-                NotNan::new(self.0.pow(rhs.0)).expect("Pow resulted in NaN")
-            }
+    impl<T> borsh::BorshDeserialize for NotNan<T>
+    where
+        T: FloatCore + borsh::BorshDeserialize,
+    {
+        #[inline]
+        fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+            let float = <T as borsh::BorshDeserialize>::deserialize_reader(reader)?;
+            NotNan::new(float).map_err(|_| {
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::InvalidData,
+                    "expected a non-NaN float",
+                )
+            })
         }
-    };
-}
-
-impl_not_nan_self_pow! {f32, f32}
-impl_not_nan_self_pow! {f64, f32}
-impl_not_nan_self_pow! {f64, f64}
+    }
 
-impl<T: FloatCore> Neg for NotNan<T> {
-    type Output = Self;
+    #[test]
+    fn test_ordered_float() {
+        let float = crate::OrderedFloat(1.0f64);
+        let buffer = borsh::to_vec(&float).expect("failed to serialize value");
+        let deser_float: crate::OrderedFloat<f64> =
+            borsh::from_slice(&buffer).expect("failed to deserialize value");
+        assert_eq!(deser_float, float);
+    }
 
-    #[inline]
-    fn neg(self) -> Self {
-        NotNan(-self.0)
+    #[test]
+    fn test_not_nan() {
+        let float = crate::NotNan(1.0f64);
+        let buffer = borsh::to_vec(&float).expect("failed to serialize value");
+        let deser_float: crate::NotNan<f64> =
+            borsh::from_slice(&buffer).expect("failed to deserialize value");
+        assert_eq!(deser_float, float);
     }
 }
 
-impl<T: FloatCore> Neg for &NotNan<T> {
-    type Output = NotNan<T>;
+#[cfg(all(feature = "std", feature = "schemars"))]
+mod impl_schemars {
+    extern crate schemars;
+    use self::schemars::gen::SchemaGenerator;
+    use self::schemars::schema::{InstanceType, Schema, SchemaObject};
+    use super::{NotNan, OrderedFloat};
 
-    #[inline]
-    fn neg(self) -> Self::Output {
-        NotNan(-self.0)
-    }
-}
+    macro_rules! primitive_float_impl {
+        ($type:ty, $schema_name:literal) => {
+            impl schemars::JsonSchema for $type {
+                fn is_referenceable() -> bool {
+                    false
+                }
 
-/// An error indicating an attempt to construct NotNan from a NaN
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
-pub struct FloatIsNan;
+                fn schema_name() -> std::string::String {
+                    std::string::String::from($schema_name)
+                }
 
-#[cfg(feature = "std")]
-impl Error for FloatIsNan {
-    fn description(&self) -> &str {
-        "NotNan constructed with NaN"
+                fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                    SchemaObject {
+                        instance_type: Some(InstanceType::Number.into()),
+                        format: Some(std::string::String::from($schema_name)),
+                        ..Default::default()
+                    }
+                    .into()
+                }
+            }
+        };
     }
-}
 
-impl fmt::Display for FloatIsNan {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "NotNan constructed with NaN")
-    }
-}
+    primitive_float_impl!(OrderedFloat<f32>, "float");
+    primitive_float_impl!(OrderedFloat<f64>, "double");
+    primitive_float_impl!(NotNan<f32>, "float");
+    primitive_float_impl!(NotNan<f64>, "double");
 
-#[cfg(feature = "std")]
-impl From<FloatIsNan> for std::io::Error {
-    #[inline]
-    fn from(e: FloatIsNan) -> std::io::Error {
-        std::io::Error::new(std::io::ErrorKind::InvalidInput, e)
+    #[test]
+    fn schema_generation_does_not_panic_for_common_floats() {
+        {
+            let schema = schemars::gen::SchemaGenerator::default()
+                .into_root_schema_for::<OrderedFloat<f32>>();
+            assert_eq!(
+                schema.schema.instance_type,
+                Some(schemars::schema::SingleOrVec::Single(std::boxed::Box::new(
+                    schemars::schema::InstanceType::Number
+                )))
+            );
+            assert_eq!(
+                schema.schema.metadata.unwrap().title.unwrap(),
+                std::string::String::from("float")
+            );
+        }
+        {
+            let schema = schemars::gen::SchemaGenerator::default()
+                .into_root_schema_for::<OrderedFloat<f64>>();
+            assert_eq!(
+                schema.schema.instance_type,
+                Some(schemars::schema::SingleOrVec::Single(std::boxed::Box::new(
+                    schemars::schema::InstanceType::Number
+                )))
+            );
+            assert_eq!(
+                schema.schema.metadata.unwrap().title.unwrap(),
+                std::string::String::from("double")
+            );
+        }
+        {
+            let schema =
+                schemars::gen::SchemaGenerator::default().into_root_schema_for::<NotNan<f32>>();
+            assert_eq!(
+                schema.schema.instance_type,
+                Some(schemars::schema::SingleOrVec::Single(std::boxed::Box::new(
+                    schemars::schema::InstanceType::Number
+                )))
+            );
+            assert_eq!(
+                schema.schema.metadata.unwrap().title.unwrap(),
+                std::string::String::from("float")
+            );
+        }
+        {
+            let schema =
+                schemars::gen::SchemaGenerator::default().into_root_schema_for::<NotNan<f64>>();
+            assert_eq!(
+                schema.schema.instance_type,
+                Some(schemars::schema::SingleOrVec::Single(std::boxed::Box::new(
+                    schemars::schema::InstanceType::Number
+                )))
+            );
+            assert_eq!(
+                schema.schema.metadata.unwrap().title.unwrap(),
+                std::string::String::from("double")
+            );
+        }
+    }
+    #[test]
+    fn ordered_float_schema_match_primitive_schema() {
+        {
+            let of_schema = schemars::gen::SchemaGenerator::default()
+                .into_root_schema_for::<OrderedFloat<f32>>();
+            let prim_schema =
+                schemars::gen::SchemaGenerator::default().into_root_schema_for::<f32>();
+            assert_eq!(of_schema, prim_schema);
+        }
+        {
+            let of_schema = schemars::gen::SchemaGenerator::default()
+                .into_root_schema_for::<OrderedFloat<f64>>();
+            let prim_schema =
+                schemars::gen::SchemaGenerator::default().into_root_schema_for::<f64>();
+            assert_eq!(of_schema, prim_schema);
+        }
+        {
+            let of_schema =
+                schemars::gen::SchemaGenerator::default().into_root_schema_for::<NotNan<f32>>();
+            let prim_schema =
+                schemars::gen::SchemaGenerator::default().into_root_schema_for::<f32>();
+            assert_eq!(of_schema, prim_schema);
+        }
+        {
+            let of_schema =
+                schemars::gen::SchemaGenerator::default().into_root_schema_for::<NotNan<f64>>();
+            let prim_schema =
+                schemars::gen::SchemaGenerator::default().into_root_schema_for::<f64>();
+            assert_eq!(of_schema, prim_schema);
+        }
     }
-}
 
-#[inline]
-/// Used for hashing. Input must not be zero or NaN.
-fn raw_double_bits<F: FloatCore>(f: &F) -> u64 {
-    let (man, exp, sign) = f.integer_decode();
-    let exp_u64 = exp as u16 as u64;
-    let sign_u64 = (sign > 0) as u64;
-    (man & MAN_MASK) | ((exp_u64 << 52) & EXP_MASK) | ((sign_u64 << 63) & SIGN_MASK)
-}
+    #[test]
+    fn transparent_newtype_schema_matches_primitive_schema() {
+        use self::schemars::schema::{Schema, SingleOrVec};
+        use self::schemars::JsonSchema;
+        // The derive's expansion assumes the std prelude, which this `no_std` crate lacks.
+        use std::borrow::ToOwned;
+
+        #[derive(JsonSchema)]
+        #[serde(transparent)]
+        #[allow(dead_code)]
+        struct Meters(NotNan<f64>);
+
+        #[derive(JsonSchema)]
+        #[serde(transparent)]
+        #[allow(dead_code)]
+        struct Distance {
+            meters: Meters,
+        }
 
-impl<T: FloatCore> Zero for NotNan<T> {
-    #[inline]
-    fn zero() -> Self {
-        NotNan(T::zero())
-    }
+        #[derive(JsonSchema)]
+        #[serde(transparent)]
+        #[allow(dead_code)]
+        struct Weight(OrderedFloat<f32>);
 
-    #[inline]
-    fn is_zero(&self) -> bool {
-        self.0.is_zero()
+        #[derive(JsonSchema)]
+        #[allow(dead_code)]
+        struct Route {
+            distance: Distance,
+            weight: Weight,
+        }
+
+        let root = |schema: schemars::schema::RootSchema| schema.schema;
+        let gen = schemars::gen::SchemaGenerator::default;
+        assert_eq!(
+            root(gen().into_root_schema_for::<Meters>()),
+            root(gen().into_root_schema_for::<f64>())
+        );
+        assert_eq!(
+            root(gen().into_root_schema_for::<Distance>()),
+            root(gen().into_root_schema_for::<f64>())
+        );
+        assert_eq!(
+            root(gen().into_root_schema_for::<Weight>()),
+            root(gen().into_root_schema_for::<f32>())
+        );
+
+        let route = root(gen().into_root_schema_for::<Route>());
+        let properties = &route.object.as_ref().unwrap().properties;
+        for (field, format) in [("distance", "double"), ("weight", "float")] {
+            match &properties[field] {
+                Schema::Object(schema) => {
+                    assert_eq!(
+                        schema.instance_type,
+                        Some(SingleOrVec::Single(std::boxed::Box::new(
+                            InstanceType::Number
+                        )))
+                    );
+                    assert_eq!(schema.format.as_deref(), Some(format));
+                    assert!(schema.reference.is_none());
+                }
+                other => panic!("unexpected schema for {}: {:?}", field, other),
+            }
+        }
     }
 }
 
-impl<T: FloatCore> One for NotNan<T> {
-    #[inline]
-    fn one() -> Self {
-        NotNan(T::one())
+#[cfg(feature = "rand")]
+mod impl_rand {
+    use super::{FloatCore, NotNan, OrderedFloat};
+    use core::hash::{Hash, Hasher};
+    use rand::distributions::uniform::*;
+    use rand::distributions::{Distribution, Open01, OpenClosed01, Standard};
+    use rand::rngs::mock::StepRng;
+    use rand::Rng;
+
+    macro_rules! impl_distribution {
+        ($dist:ident, $($f:ty),+) => {
+            $(
+            impl Distribution<NotNan<$f>> for $dist {
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NotNan<$f> {
+                    // 'rand' never generates NaN values in the Standard, Open01, or
+                    // OpenClosed01 distributions. Using 'new_unchecked' is therefore
+                    // safe.
+                    unsafe { NotNan::new_unchecked(self.sample(rng)) }
+                }
+            }
+
+            impl Distribution<OrderedFloat<$f>> for $dist {
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> OrderedFloat<$f> {
+                    OrderedFloat(self.sample(rng))
+                }
+            }
+            )*
+        }
     }
-}
 
-impl<T: FloatCore> Bounded for NotNan<T> {
-    #[inline]
-    fn min_value() -> Self {
-        NotNan(T::min_value())
-    }
+    impl_distribution! { Standard, f32, f64 }
+    impl_distribution! { Open01, f32, f64 }
+    impl_distribution! { OpenClosed01, f32, f64 }
 
-    #[inline]
-    fn max_value() -> Self {
-        NotNan(T::max_value())
-    }
-}
+    // The `serde` feature also enables `rand/serde1`, which is what makes `UniformFloat`
+    // serializable, so these derives always have the impls they need.
 
-impl<T: FloatCore + FromStr> FromStr for NotNan<T> {
-    type Err = ParseNotNanError<T::Err>;
+    // `UniformFloat` keeps its `low` and `scale` private, so hash the values it produces for the
+    // smallest and largest random words instead. These are fixed by `low` and `scale`, and hashing
+    // them as `OrderedFloat` folds away the sign of zero, so samplers that compare equal hash
+    // equally.
+    fn hash_uniform_float<U, H>(sampler: &U, state: &mut H)
+    where
+        U: UniformSampler,
+        U::X: FloatCore,
+        H: Hasher,
+    {
+        for word in [0, u64::MAX] {
+            OrderedFloat(sampler.sample(&mut StepRng::new(word, 0))).hash(state);
+        }
+    }
 
-    /// Convert a &str to `NotNan`. Returns an error if the string fails to parse,
-    /// or if the resulting value is NaN
-    ///
-    /// ```
-    /// use ordered_float::NotNan;
+    /// A sampler for a uniform distribution
     ///
-    /// assert!("-10".parse::<NotNan<f32>>().is_ok());
-    /// assert!("abc".parse::<NotNan<f32>>().is_err());
-    /// assert!("NaN".parse::<NotNan<f32>>().is_err());
-    /// ```
-    fn from_str(src: &str) -> Result<Self, Self::Err> {
-        src.parse()
-            .map_err(ParseNotNanError::ParseFloatError)
-            .and_then(|f| NotNan::new(f).map_err(|_| ParseNotNanError::IsNaN))
+    /// Implements `Eq` and `Hash` by the internal `UniformFloat` state (its lower bound and
+    /// scale), consistently with `PartialEq`.
+    #[derive(Clone, Copy, Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct UniformNotNan<T>(UniformFloat<T>);
+    impl SampleUniform for NotNan<f32> {
+        type Sampler = UniformNotNan<f32>;
     }
-}
-
-impl<T: FloatCore + FromPrimitive> FromPrimitive for NotNan<T> {
-    fn from_i64(n: i64) -> Option<Self> {
-        T::from_i64(n).and_then(|n| NotNan::new(n).ok())
+    impl SampleUniform for NotNan<f64> {
+        type Sampler = UniformNotNan<f64>;
     }
-    fn from_u64(n: u64) -> Option<Self> {
-        T::from_u64(n).and_then(|n| NotNan::new(n).ok())
+    impl<T> PartialEq for UniformNotNan<T>
+    where
+        UniformFloat<T>: PartialEq,
+    {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
     }
 
-    fn from_isize(n: isize) -> Option<Self> {
-        T::from_isize(n).and_then(|n| NotNan::new(n).ok())
-    }
-    fn from_i8(n: i8) -> Option<Self> {
-        T::from_i8(n).and_then(|n| NotNan::new(n).ok())
-    }
-    fn from_i16(n: i16) -> Option<Self> {
-        T::from_i16(n).and_then(|n| NotNan::new(n).ok())
-    }
-    fn from_i32(n: i32) -> Option<Self> {
-        T::from_i32(n).and_then(|n| NotNan::new(n).ok())
-    }
-    fn from_usize(n: usize) -> Option<Self> {
-        T::from_usize(n).and_then(|n| NotNan::new(n).ok())
-    }
-    fn from_u8(n: u8) -> Option<Self> {
-        T::from_u8(n).and_then(|n| NotNan::new(n).ok())
-    }
-    fn from_u16(n: u16) -> Option<Self> {
-        T::from_u16(n).and_then(|n| NotNan::new(n).ok())
-    }
-    fn from_u32(n: u32) -> Option<Self> {
-        T::from_u32(n).and_then(|n| NotNan::new(n).ok())
+    /// A sampler for a uniform distribution
+    ///
+    /// Implements `Eq` and `Hash` by the internal `UniformFloat` state (its lower bound and
+    /// scale), consistently with `PartialEq`.
+    #[derive(Clone, Copy, Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct UniformOrdered<T>(UniformFloat<T>);
+    impl SampleUniform for OrderedFloat<f32> {
+        type Sampler = UniformOrdered<f32>;
     }
-    fn from_f32(n: f32) -> Option<Self> {
-        T::from_f32(n).and_then(|n| NotNan::new(n).ok())
+    impl SampleUniform for OrderedFloat<f64> {
+        type Sampler = UniformOrdered<f64>;
     }
-    fn from_f64(n: f64) -> Option<Self> {
-        T::from_f64(n).and_then(|n| NotNan::new(n).ok())
+    impl<T> PartialEq for UniformOrdered<T>
+    where
+        UniformFloat<T>: PartialEq,
+    {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
     }
-}
 
-impl<T: FloatCore> ToPrimitive for NotNan<T> {
-    fn to_i64(&self) -> Option<i64> {
-        self.0.to_i64()
-    }
-    fn to_u64(&self) -> Option<u64> {
-        self.0.to_u64()
-    }
+    macro_rules! impl_uniform_sampler {
+        ($f:ty) => {
+            impl UniformSampler for UniformNotNan<$f> {
+                type X = NotNan<$f>;
+                fn new<B1, B2>(low: B1, high: B2) -> Self
+                where
+                    B1: SampleBorrow<Self::X> + Sized,
+                    B2: SampleBorrow<Self::X> + Sized,
+                {
+                    UniformNotNan(UniformFloat::<$f>::new(low.borrow().0, high.borrow().0))
+                }
+                fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+                where
+                    B1: SampleBorrow<Self::X> + Sized,
+                    B2: SampleBorrow<Self::X> + Sized,
+                {
+                    UniformSampler::new(low, high)
+                }
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                    // UniformFloat.sample() will never return NaN.
+                    unsafe { NotNan::new_unchecked(self.0.sample(rng)) }
+                }
+            }
 
-    fn to_isize(&self) -> Option<isize> {
-        self.0.to_isize()
-    }
-    fn to_i8(&self) -> Option<i8> {
-        self.0.to_i8()
-    }
-    fn to_i16(&self) -> Option<i16> {
-        self.0.to_i16()
-    }
-    fn to_i32(&self) -> Option<i32> {
-        self.0.to_i32()
-    }
-    fn to_usize(&self) -> Option<usize> {
-        self.0.to_usize()
-    }
-    fn to_u8(&self) -> Option<u8> {
-        self.0.to_u8()
-    }
-    fn to_u16(&self) -> Option<u16> {
-        self.0.to_u16()
-    }
-    fn to_u32(&self) -> Option<u32> {
-        self.0.to_u32()
+            impl UniformSampler for UniformOrdered<$f> {
+                type X = OrderedFloat<$f>;
+                fn new<B1, B2>(low: B1, high: B2) -> Self
+                where
+                    B1: SampleBorrow<Self::X> + Sized,
+                    B2: SampleBorrow<Self::X> + Sized,
+                {
+                    UniformOrdered(UniformFloat::<$f>::new(low.borrow().0, high.borrow().0))
+                }
+                fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+                where
+                    B1: SampleBorrow<Self::X> + Sized,
+                    B2: SampleBorrow<Self::X> + Sized,
+                {
+                    UniformSampler::new(low, high)
+                }
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                    OrderedFloat(self.0.sample(rng))
+                }
+            }
+
+            // `UniformFloat::new` requires finite bounds, so the state never holds NaN and
+            // `PartialEq` is a full equivalence.
+            impl Eq for UniformNotNan<$f> {}
+            impl Eq for UniformOrdered<$f> {}
+
+            impl Hash for UniformNotNan<$f> {
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    hash_uniform_float(&self.0, state)
+                }
+            }
+
+            impl Hash for UniformOrdered<$f> {
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    hash_uniform_float(&self.0, state)
+                }
+            }
+        };
     }
-    fn to_f32(&self) -> Option<f32> {
-        self.0.to_f32()
+
+    impl_uniform_sampler! { f32 }
+    impl_uniform_sampler! { f64 }
+
+    #[cfg(all(test, feature = "serde"))]
+    #[test]
+    fn uniform_sampler_serde_round_trip() {
+        let low = NotNan::new(1.0f64).unwrap();
+        let high = NotNan::new(2.0f64).unwrap();
+        let sampler = UniformNotNan::<f64>::new(low, high);
+        let json = serde_json::to_string(&sampler).unwrap();
+        let deser: UniformNotNan<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deser, sampler);
+
+        let sampler = UniformOrdered::<f64>::new(OrderedFloat(1.0), OrderedFloat(2.0));
+        let json = serde_json::to_string(&sampler).unwrap();
+        let deser: UniformOrdered<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deser, sampler);
     }
-    fn to_f64(&self) -> Option<f64> {
-        self.0.to_f64()
+
+    #[cfg(all(test, feature = "std"))]
+    #[test]
+    fn uniform_sampler_hash() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        set.insert(UniformNotNan::<f64>::new(NotNan(0.0), NotNan(1.0)));
+        set.insert(UniformNotNan::<f64>::new(NotNan(0.0), NotNan(2.0)));
+        assert!(set.contains(&UniformNotNan::<f64>::new(NotNan(0.0), NotNan(1.0))));
+        assert!(set.contains(&UniformNotNan::<f64>::new(NotNan(-0.0), NotNan(1.0))));
+        assert!(!set.contains(&UniformNotNan::<f64>::new(NotNan(1.0), NotNan(2.0))));
+
+        let mut set = HashSet::new();
+        set.insert(UniformOrdered::<f32>::new(
+            OrderedFloat(-1.0),
+            OrderedFloat(1.0),
+        ));
+        assert!(set.contains(&UniformOrdered::<f32>::new(
+            OrderedFloat(-1.0),
+            OrderedFloat(1.0)
+        )));
+        assert!(!set.contains(&UniformOrdered::<f32>::new(
+            OrderedFloat(-1.0),
+            OrderedFloat(0.5)
+        )));
     }
-}
 
-/// An error indicating a parse error from a string for `NotNan`.
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
-pub enum ParseNotNanError<E> {
-    /// A plain parse error from the underlying float type.
-    ParseFloatError(E),
-    /// The parsed float value resulted in a NaN.
-    IsNaN,
-}
+    #[cfg(all(test, feature = "randtest"))]
+    mod tests {
+        use super::*;
+
+        fn sample_fuzz<T>()
+        where
+            Standard: Distribution<NotNan<T>>,
+            Open01: Distribution<NotNan<T>>,
+            OpenClosed01: Distribution<NotNan<T>>,
+            Standard: Distribution<OrderedFloat<T>>,
+            Open01: Distribution<OrderedFloat<T>>,
+            OpenClosed01: Distribution<OrderedFloat<T>>,
+            T: crate::Float,
+        {
+            let mut rng = rand::thread_rng();
+            let f1: NotNan<T> = rng.sample(Standard);
+            let f2: NotNan<T> = rng.sample(Open01);
+            let f3: NotNan<T> = rng.sample(OpenClosed01);
+            let _: OrderedFloat<T> = rng.sample(Standard);
+            let _: OrderedFloat<T> = rng.sample(Open01);
+            let _: OrderedFloat<T> = rng.sample(OpenClosed01);
+            assert!(!f1.into_inner().is_nan());
+            assert!(!f2.into_inner().is_nan());
+            assert!(!f3.into_inner().is_nan());
+        }
+
+        #[test]
+        fn sampling_f32_does_not_panic() {
+            sample_fuzz::<f32>();
+        }
+
+        #[test]
+        fn sampling_f64_does_not_panic() {
+            sample_fuzz::<f64>();
+        }
+
+        #[test]
+        fn uniform_sampler_eq_matches_samples() {
+            use rand::rngs::StdRng;
+            use rand::SeedableRng;
+            use std::collections::hash_map::DefaultHasher;
+            use std::vec::Vec;
+
+            fn samples<U: UniformSampler>(sampler: &U) -> Vec<U::X> {
+                let mut rng = StdRng::seed_from_u64(42);
+                (0..100).map(|_| sampler.sample(&mut rng)).collect()
+            }
+
+            fn hash<T: Hash>(value: &T) -> u64 {
+                let mut hasher = DefaultHasher::new();
+                value.hash(&mut hasher);
+                hasher.finish()
+            }
+
+            let a = UniformNotNan::<f64>::new(NotNan(0.0), NotNan(1.0));
+            let b = UniformNotNan::<f64>::new(NotNan(-0.0), NotNan(1.0));
+            let c = UniformNotNan::<f64>::new(NotNan(0.0), NotNan(2.0));
+            assert_eq!(a, b);
+            assert_eq!(samples(&a), samples(&b));
+            assert_eq!(hash(&a), hash(&b));
+            assert_ne!(a, c);
+            assert_ne!(samples(&a), samples(&c));
+            assert_ne!(hash(&a), hash(&c));
+
+            let a = UniformOrdered::<f32>::new(OrderedFloat(-1.0), OrderedFloat(1.0));
+            let b = UniformOrdered::<f32>::new(OrderedFloat(-1.0), OrderedFloat(1.0));
+            let c = UniformOrdered::<f32>::new(OrderedFloat(-1.0), OrderedFloat(0.5));
+            assert_eq!(a, b);
+            assert_eq!(samples(&a), samples(&b));
+            assert_eq!(hash(&a), hash(&b));
+            assert_ne!(a, c);
+            assert_ne!(samples(&a), samples(&c));
+            assert_ne!(hash(&a), hash(&c));
+        }
 
-#[cfg(feature = "std")]
-impl<E: fmt::Debug + Error + 'static> Error for ParseNotNanError<E> {
-    fn description(&self) -> &str {
-        "Error parsing a not-NaN floating point value"
-    }
+        #[test]
+        #[should_panic]
+        fn uniform_sampling_panic_on_infinity_notnan() {
+            let (low, high) = (
+                NotNan::new(0f64).unwrap(),
+                NotNan::new(f64::INFINITY).unwrap(),
+            );
+            let uniform = Uniform::new(low, high);
+            let _ = uniform.sample(&mut rand::thread_rng());
+        }
 
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            ParseNotNanError::ParseFloatError(e) => Some(e),
-            ParseNotNanError::IsNaN => None,
+        #[test]
+        #[should_panic]
+        fn uniform_sampling_panic_on_infinity_ordered() {
+            let (low, high) = (OrderedFloat(0f64), OrderedFloat(f64::INFINITY));
+            let uniform = Uniform::new(low, high);
+            let _ = uniform.sample(&mut rand::thread_rng());
         }
-    }
-}
 
-impl<E: fmt::Display> fmt::Display for ParseNotNanError<E> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            ParseNotNanError::ParseFloatError(e) => write!(f, "Parse error: {}", e),
-            ParseNotNanError::IsNaN => write!(f, "NotNan parser encounter a NaN"),
+        #[test]
+        #[should_panic]
+        fn uniform_sampling_panic_on_nan_ordered() {
+            let (low, high) = (OrderedFloat(0f64), OrderedFloat(f64::NAN));
+            let uniform = Uniform::new(low, high);
+            let _ = uniform.sample(&mut rand::thread_rng());
         }
     }
 }
 
-impl<T: FloatCore> Num for NotNan<T> {
-    type FromStrRadixErr = ParseNotNanError<T::FromStrRadixErr>;
+#[cfg(feature = "proptest")]
+mod impl_proptest {
+    use super::{NotNan, OrderedFloat};
+    use proptest::arbitrary::{Arbitrary, StrategyFor};
+    use proptest::num::{f32, f64};
+    use proptest::strategy::{BoxedStrategy, FilterMap, Map, Strategy};
+    #[cfg(test)]
+    use proptest::test_runner::TestRunner;
+    use std::convert::TryFrom;
 
-    fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        T::from_str_radix(src, radix)
-            .map_err(ParseNotNanError::ParseFloatError)
-            .and_then(|n| NotNan::new(n).map_err(|_| ParseNotNanError::IsNaN))
-    }
-}
+    macro_rules! impl_arbitrary {
+        ($($f:ident),+) => {
+            $(
+                impl Arbitrary for NotNan<$f> {
+                    type Strategy = FilterMap<StrategyFor<$f>, fn(_: $f) -> Option<NotNan<$f>>>;
+                    type Parameters = <$f as Arbitrary>::Parameters;
+                    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+                        <$f>::arbitrary_with(params)
+                            .prop_filter_map("filter nan values", |f| NotNan::try_from(f).ok())
+                    }
+                }
 
-impl<T: FloatCore + Signed> Signed for NotNan<T> {
-    #[inline]
-    fn abs(&self) -> Self {
-        NotNan(self.0.abs())
+                impl Arbitrary for OrderedFloat<$f> {
+                    type Strategy = Map<StrategyFor<$f>, fn(_: $f) -> OrderedFloat<$f>>;
+                    type Parameters = <$f as Arbitrary>::Parameters;
+                    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+                        <$f>::arbitrary_with(params).prop_map(|f| OrderedFloat::from(f))
+                    }
+                }
+            )*
+        }
     }
+    impl_arbitrary! { f32, f64 }
 
-    fn abs_sub(&self, other: &Self) -> Self {
-        NotNan::new(Signed::abs_sub(&self.0, &other.0)).expect("Subtraction resulted in NaN")
-    }
+    macro_rules! impl_strategies {
+        ($($f:ident),+) => {
+            $(
+                impl NotNan<$f> {
+                    /// A strategy producing only finite, non-subnormal values (zeros included).
+                    ///
+                    /// This is intended for targeted numeric testing, where infinities and
+                    /// subnormals would otherwise dominate edge cases.
+                    pub fn finite_strategy() -> BoxedStrategy<NotNan<$f>> {
+                        <$f>::arbitrary()
+                            .prop_filter_map("filter non-finite and subnormal values", |f| {
+                                if f.is_finite() && !f.is_subnormal() {
+                                    NotNan::try_from(f).ok()
+                                } else {
+                                    None
+                                }
+                            })
+                            .boxed()
+                    }
 
-    #[inline]
-    fn signum(&self) -> Self {
-        NotNan(self.0.signum())
-    }
-    #[inline]
-    fn is_positive(&self) -> bool {
-        self.0.is_positive()
+                    /// A strategy producing only finite, normal values greater than zero.
+                    ///
+                    /// This is intended for targeted numeric testing, e.g. of functions that are
+                    /// only defined for positive inputs.
+                    pub fn positive_strategy() -> BoxedStrategy<NotNan<$f>> {
+                        <$f>::arbitrary()
+                            .prop_filter_map("filter non-positive and non-normal values", |f| {
+                                if f.is_normal() && f > 0.0 {
+                                    NotNan::try_from(f).ok()
+                                } else {
+                                    None
+                                }
+                            })
+                            .boxed()
+                    }
+                }
+            )*
+        }
     }
-    #[inline]
-    fn is_negative(&self) -> bool {
-        self.0.is_negative()
+    impl_strategies! { f32, f64 }
+
+    #[test]
+    fn test_finite_strategy() {
+        let mut runner = TestRunner::default();
+        runner
+            .run(&NotNan::<f64>::finite_strategy(), |f| {
+                assert!(f.is_finite() && !f.is_subnormal());
+                Ok(())
+            })
+            .unwrap();
     }
-}
 
-impl<T: FloatCore> NumCast for NotNan<T> {
-    fn from<F: ToPrimitive>(n: F) -> Option<Self> {
-        T::from(n).and_then(|n| NotNan::new(n).ok())
+    #[test]
+    fn test_positive_strategy() {
+        let mut runner = TestRunner::default();
+        runner
+            .run(&NotNan::<f32>::positive_strategy(), |f| {
+                assert!(f.is_normal() && *f > 0.0);
+                Ok(())
+            })
+            .unwrap();
     }
 }
 
-macro_rules! impl_float_const_method {
-    ($wrapper:expr, $method:ident) => {
-        #[allow(non_snake_case)]
-        #[allow(clippy::redundant_closure_call)]
-        fn $method() -> Self {
-            $wrapper(T::$method())
-        }
-    };
-}
+#[cfg(feature = "arbitrary")]
+mod impl_arbitrary {
+    use super::{FloatIsNan, NotNan, OrderedFloat};
+    use arbitrary::{Arbitrary, Unstructured};
+    use num_traits::FromPrimitive;
 
-macro_rules! impl_float_const {
-    ($type:ident, $wrapper:expr) => {
-        impl<T: FloatConst> FloatConst for $type<T> {
-            impl_float_const_method!($wrapper, E);
-            impl_float_const_method!($wrapper, FRAC_1_PI);
-            impl_float_const_method!($wrapper, FRAC_1_SQRT_2);
-            impl_float_const_method!($wrapper, FRAC_2_PI);
-            impl_float_const_method!($wrapper, FRAC_2_SQRT_PI);
-            impl_float_const_method!($wrapper, FRAC_PI_2);
-            impl_float_const_method!($wrapper, FRAC_PI_3);
-            impl_float_const_method!($wrapper, FRAC_PI_4);
-            impl_float_const_method!($wrapper, FRAC_PI_6);
-            impl_float_const_method!($wrapper, FRAC_PI_8);
-            impl_float_const_method!($wrapper, LN_10);
-            impl_float_const_method!($wrapper, LN_2);
-            impl_float_const_method!($wrapper, LOG10_E);
-            impl_float_const_method!($wrapper, LOG2_E);
-            impl_float_const_method!($wrapper, PI);
-            impl_float_const_method!($wrapper, SQRT_2);
-        }
-    };
-}
+    macro_rules! impl_arbitrary {
+        ($($f:ident),+) => {
+            $(
+                impl<'a> Arbitrary<'a> for NotNan<$f> {
+                    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+                        let float: $f = u.arbitrary()?;
+                        match NotNan::new(float) {
+                            Ok(notnan_value) => Ok(notnan_value),
+                            Err(FloatIsNan) => {
+                                // If our arbitrary float input was a NaN (encoded by exponent = max
+                                // value), then replace it with a finite float, reusing the mantissa
+                                // bits.
+                                //
+                                // This means the output is not uniformly distributed among all
+                                // possible float values, but Arbitrary makes no promise that that
+                                // is true.
+                                //
+                                // An alternative implementation would be to return an
+                                // `arbitrary::Error`, but that is not as useful since it forces the
+                                // caller to retry with new random/fuzzed data; and the precendent of
+                                // `arbitrary`'s built-in implementations is to prefer the approach of
+                                // mangling the input bits to fit.
 
-impl_float_const!(OrderedFloat, OrderedFloat);
-// Float constants are not NaN.
-impl_float_const!(NotNan, |x| unsafe { NotNan::new_unchecked(x) });
+                                let (mantissa, _exponent, sign) =
+                                    num_traits::float::FloatCore::integer_decode(float);
+                                let revised_float = <$f>::from_i64(
+                                    i64::from(sign) * mantissa as i64
+                                ).unwrap();
 
-#[cfg(feature = "serde")]
-mod impl_serde {
-    extern crate serde;
-    use self::serde::de::{Error, Unexpected};
-    use self::serde::{Deserialize, Deserializer, Serialize, Serializer};
-    use super::{NotNan, OrderedFloat};
-    use core::f64;
-    use num_traits::float::FloatCore;
+                                // If this unwrap() fails, then there is a bug in the above code.
+                                Ok(NotNan::new(revised_float).unwrap())
+                            }
+                        }
+                    }
 
-    #[cfg(test)]
-    extern crate serde_test;
-    #[cfg(test)]
-    use self::serde_test::{assert_de_tokens_error, assert_tokens, Token};
+                    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                        <$f as Arbitrary>::size_hint(depth)
+                    }
+                }
 
-    impl<T: FloatCore + Serialize> Serialize for OrderedFloat<T> {
-        #[inline]
-        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
-            self.0.serialize(s)
-        }
-    }
+                impl<'a> Arbitrary<'a> for OrderedFloat<$f> {
+                    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+                        let float: $f = u.arbitrary()?;
+                        Ok(OrderedFloat::from(float))
+                    }
 
-    impl<'de, T: FloatCore + Deserialize<'de>> Deserialize<'de> for OrderedFloat<T> {
-        #[inline]
-        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
-            T::deserialize(d).map(OrderedFloat)
+                    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                        <$f as Arbitrary>::size_hint(depth)
+                    }
+                }
+            )*
         }
     }
+    impl_arbitrary! { f32, f64 }
+}
 
-    impl<T: FloatCore + Serialize> Serialize for NotNan<T> {
-        #[inline]
-        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
-            self.0.serialize(s)
-        }
-    }
+#[cfg(feature = "bytemuck")]
+mod impl_bytemuck {
+    use super::{FloatCore, NotNan, OrderedFloat};
+    use bytemuck::{AnyBitPattern, CheckedBitPattern, NoUninit, Pod, TransparentWrapper, Zeroable};
 
-    impl<'de, T: FloatCore + Deserialize<'de>> Deserialize<'de> for NotNan<T> {
-        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
-            let float = T::deserialize(d)?;
-            NotNan::new(float).map_err(|_| {
-                Error::invalid_value(Unexpected::Float(f64::NAN), &"float (but not NaN)")
-            })
-        }
-    }
+    unsafe impl<T: Zeroable> Zeroable for OrderedFloat<T> {}
 
-    #[test]
-    fn test_ordered_float() {
-        let float = OrderedFloat(1.0f64);
-        assert_tokens(&float, &[Token::F64(1.0)]);
-    }
+    // The zero bit pattern is indeed not a NaN bit pattern.
+    unsafe impl<T: Zeroable> Zeroable for NotNan<T> {}
 
-    #[test]
-    fn test_not_nan() {
-        let float = NotNan(1.0f64);
-        assert_tokens(&float, &[Token::F64(1.0)]);
+    unsafe impl<T: Pod> Pod for OrderedFloat<T> {}
+
+    // `NotNan<T>` can only implement `NoUninit` and not `Pod`, since not every bit pattern is
+    // valid (NaN bit patterns are invalid). `NoUninit` guarantees that we can read any bit pattern
+    // from the value, which is fine in this case.
+    unsafe impl<T: NoUninit> NoUninit for NotNan<T> {}
+
+    unsafe impl<T: FloatCore + AnyBitPattern> CheckedBitPattern for NotNan<T> {
+        type Bits = T;
+
+        fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+            !bits.is_nan()
+        }
     }
 
+    // OrderedFloat allows any value of the contained type, so it is a TransparentWrapper.
+    // NotNan does not, so it is not.
+    unsafe impl<T> TransparentWrapper<T> for OrderedFloat<T> {}
+
     #[test]
-    fn test_fail_on_nan() {
-        assert_de_tokens_error::<NotNan<f64>>(
-            &[Token::F64(f64::NAN)],
-            "invalid value: floating point `NaN`, expected float (but not NaN)",
+    fn test_not_nan_bit_pattern() {
+        use bytemuck::checked::{try_cast, CheckedCastError};
+
+        let nan = f64::NAN;
+        assert_eq!(
+            try_cast::<f64, NotNan<f64>>(nan),
+            Err(CheckedCastError::InvalidBitPattern),
         );
+
+        let pi = core::f64::consts::PI;
+        assert!(try_cast::<f64, NotNan<f64>>(pi).is_ok());
     }
 }
 
-#[cfg(any(feature = "rkyv_16", feature = "rkyv_32", feature = "rkyv_64"))]
-mod impl_rkyv {
+#[cfg(feature = "musli")]
+mod impl_musli {
     use super::{NotNan, OrderedFloat};
+    use musli::{Context, Decode, Decoder, Encode, Encoder};
     use num_traits::float::FloatCore;
-    #[cfg(test)]
-    use rkyv::{archived_root, ser::Serializer};
-    use rkyv::{Archive, Deserialize, Fallible, Serialize};
-
-    #[cfg(test)]
-    type DefaultSerializer = rkyv::ser::serializers::CoreSerializer<16, 16>;
-    #[cfg(test)]
-    type DefaultDeserializer = rkyv::Infallible;
-
-    impl<T: FloatCore + Archive> Archive for OrderedFloat<T> {
-        type Archived = OrderedFloat<T::Archived>;
-
-        type Resolver = T::Resolver;
 
-        unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
-            self.0.resolve(pos, resolver, out.cast())
+    impl<M, T: Encode<M>> Encode<M> for OrderedFloat<T> {
+        #[inline]
+        fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+        where
+            E: Encoder<Mode = M>,
+        {
+            self.0.encode(cx, encoder)
         }
     }
 
-    impl<T: FloatCore + Serialize<S>, S: Fallible + ?Sized> Serialize<S> for OrderedFloat<T> {
-        fn serialize(&self, s: &mut S) -> Result<Self::Resolver, S::Error> {
-            self.0.serialize(s)
+    impl<'de, M, T: Decode<'de, M>> Decode<'de, M> for OrderedFloat<T> {
+        #[inline]
+        fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+        where
+            D: Decoder<'de, Mode = M>,
+        {
+            T::decode(cx, decoder).map(OrderedFloat)
         }
     }
 
-    impl<T: FloatCore, AT: Deserialize<T, D>, D: Fallible + ?Sized> Deserialize<OrderedFloat<T>, D>
-        for OrderedFloat<AT>
-    {
-        fn deserialize(&self, d: &mut D) -> Result<OrderedFloat<T>, D::Error> {
-            self.0.deserialize(d).map(OrderedFloat)
+    impl<M, T: Encode<M>> Encode<M> for NotNan<T> {
+        #[inline]
+        fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+        where
+            E: Encoder<Mode = M>,
+        {
+            self.0.encode(cx, encoder)
         }
     }
 
-    impl<T: FloatCore + Archive> Archive for NotNan<T> {
-        type Archived = NotNan<T::Archived>;
-
-        type Resolver = T::Resolver;
-
-        unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
-            self.0.resolve(pos, resolver, out.cast())
+    impl<'de, M, T: FloatCore + Decode<'de, M>> Decode<'de, M> for NotNan<T> {
+        #[inline]
+        fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+        where
+            D: Decoder<'de, Mode = M>,
+        {
+            let float = T::decode(cx, decoder)?;
+            NotNan::new(float).map_err(|_| cx.message("expected a non-NaN float"))
         }
     }
+}
 
-    impl<T: FloatCore + Serialize<S>, S: Fallible + ?Sized> Serialize<S> for NotNan<T> {
-        fn serialize(&self, s: &mut S) -> Result<Self::Resolver, S::Error> {
-            self.0.serialize(s)
-        }
-    }
+#[cfg(feature = "diesel")]
+mod impl_diesel {
+    extern crate std;
+    use super::{NotNan, OrderedFloat};
+    use diesel::backend::Backend;
+    use diesel::deserialize::{self, FromSql, Queryable};
+    use diesel::serialize::{self, Output, ToSql};
+    use diesel::sql_types::{Double, Float};
+    use num_traits::float::FloatCore;
 
-    impl<T: FloatCore, AT: Deserialize<T, D>, D: Fallible + ?Sized> Deserialize<NotNan<T>, D>
-        for NotNan<AT>
-    {
-        fn deserialize(&self, d: &mut D) -> Result<NotNan<T>, D::Error> {
-            self.0.deserialize(d).map(NotNan)
-        }
+    fn not_nan_from_sql<T: FloatCore>(float: T) -> deserialize::Result<NotNan<T>> {
+        NotNan::new(float).map_err(|_| "expected a non-NaN float".into())
     }
 
-    macro_rules! rkyv_eq_ord {
-        ($main:ident, $float:ty, $rend:ty) => {
-            impl PartialEq<$main<$float>> for $main<$rend> {
-                fn eq(&self, other: &$main<$float>) -> bool {
-                    other.eq(&self.0.value())
+    macro_rules! impl_diesel {
+        ($f:ty, $sql_type:ty) => {
+            impl<DB> ToSql<$sql_type, DB> for OrderedFloat<$f>
+            where
+                DB: Backend,
+                $f: ToSql<$sql_type, DB>,
+            {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+                    <$f as ToSql<$sql_type, DB>>::to_sql(&self.0, out)
                 }
             }
-            impl PartialEq<$main<$rend>> for $main<$float> {
-                fn eq(&self, other: &$main<$rend>) -> bool {
-                    self.eq(&other.0.value())
+
+            impl<DB> FromSql<$sql_type, DB> for OrderedFloat<$f>
+            where
+                DB: Backend,
+                $f: FromSql<$sql_type, DB>,
+            {
+                fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+                    <$f as FromSql<$sql_type, DB>>::from_sql(bytes).map(OrderedFloat)
                 }
             }
 
-            impl PartialOrd<$main<$float>> for $main<$rend> {
-                fn partial_cmp(&self, other: &$main<$float>) -> Option<core::cmp::Ordering> {
-                    self.0.value().partial_cmp(other)
+            impl<DB> ToSql<$sql_type, DB> for NotNan<$f>
+            where
+                DB: Backend,
+                $f: ToSql<$sql_type, DB>,
+            {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+                    <$f as ToSql<$sql_type, DB>>::to_sql(&self.0, out)
                 }
             }
 
-            impl PartialOrd<$main<$rend>> for $main<$float> {
-                fn partial_cmp(&self, other: &$main<$rend>) -> Option<core::cmp::Ordering> {
-                    other
-                        .0
-                        .value()
-                        .partial_cmp(self)
-                        .map(core::cmp::Ordering::reverse)
+            impl<DB> FromSql<$sql_type, DB> for NotNan<$f>
+            where
+                DB: Backend,
+                $f: FromSql<$sql_type, DB>,
+            {
+                fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+                    <$f as FromSql<$sql_type, DB>>::from_sql(bytes).and_then(not_nan_from_sql)
                 }
             }
-        };
-    }
-
-    rkyv_eq_ord! { OrderedFloat, f32, rkyv::rend::f32_le }
-    rkyv_eq_ord! { OrderedFloat, f32, rkyv::rend::f32_be }
-    rkyv_eq_ord! { OrderedFloat, f64, rkyv::rend::f64_le }
-    rkyv_eq_ord! { OrderedFloat, f64, rkyv::rend::f64_be }
-    rkyv_eq_ord! { NotNan, f32, rkyv::rend::f32_le }
-    rkyv_eq_ord! { NotNan, f32, rkyv::rend::f32_be }
-    rkyv_eq_ord! { NotNan, f64, rkyv::rend::f64_le }
-    rkyv_eq_ord! { NotNan, f64, rkyv::rend::f64_be }
 
-    #[cfg(feature = "rkyv_ck")]
-    use super::FloatIsNan;
-    #[cfg(feature = "rkyv_ck")]
-    use core::convert::Infallible;
-    #[cfg(feature = "rkyv_ck")]
-    use rkyv::bytecheck::CheckBytes;
+            // Diesel has no blanket `Queryable` impl for `FromSql` types, so without these the
+            // wrappers could be bound as parameters but not loaded from query results.
+            impl<DB> Queryable<$sql_type, DB> for OrderedFloat<$f>
+            where
+                DB: Backend,
+                Self: FromSql<$sql_type, DB>,
+            {
+                type Row = Self;
+
+                fn build(row: Self) -> deserialize::Result<Self> {
+                    Ok(row)
+                }
+            }
 
-    #[cfg(feature = "rkyv_ck")]
-    impl<C: ?Sized, T: FloatCore + CheckBytes<C>> CheckBytes<C> for OrderedFloat<T> {
-        type Error = Infallible;
+            impl<DB> Queryable<$sql_type, DB> for NotNan<$f>
+            where
+                DB: Backend,
+                Self: FromSql<$sql_type, DB>,
+            {
+                type Row = Self;
 
-        #[inline]
-        unsafe fn check_bytes<'a>(value: *const Self, _: &mut C) -> Result<&'a Self, Self::Error> {
-            Ok(&*value)
-        }
+                fn build(row: Self) -> deserialize::Result<Self> {
+                    Ok(row)
+                }
+            }
+        };
     }
 
-    #[cfg(feature = "rkyv_ck")]
-    impl<C: ?Sized, T: FloatCore + CheckBytes<C>> CheckBytes<C> for NotNan<T> {
-        type Error = FloatIsNan;
+    impl_diesel! { f32, Float }
+    impl_diesel! { f64, Double }
 
-        #[inline]
-        unsafe fn check_bytes<'a>(value: *const Self, _: &mut C) -> Result<&'a Self, Self::Error> {
-            Self::new(*(value as *const T)).map(|_| &*value)
-        }
+    /// Compiling this checks that the wrappers can be used with any backend that supports the
+    /// underlying float type.
+    fn _wrappers_implement_sql_traits<DB: Backend>()
+    where
+        f32: ToSql<Float, DB> + FromSql<Float, DB>,
+        f64: ToSql<Double, DB> + FromSql<Double, DB>,
+    {
+        fn check<T: ToSql<ST, DB> + FromSql<ST, DB> + Queryable<ST, DB>, ST, DB: Backend>() {}
+        check::<OrderedFloat<f32>, Float, DB>();
+        check::<OrderedFloat<f64>, Double, DB>();
+        check::<NotNan<f32>, Float, DB>();
+        check::<NotNan<f64>, Double, DB>();
     }
 
     #[test]
-    fn test_ordered_float() {
-        let float = OrderedFloat(1.0f64);
-        let mut serializer = DefaultSerializer::default();
-        serializer
-            .serialize_value(&float)
-            .expect("failed to archive value");
-        let len = serializer.pos();
-        let buffer = serializer.into_serializer().into_inner();
-
-        let archived_value = unsafe { archived_root::<OrderedFloat<f64>>(&buffer[0..len]) };
-        assert_eq!(archived_value, &float);
-        let mut deserializer = DefaultDeserializer::default();
-        let deser_float: OrderedFloat<f64> = archived_value.deserialize(&mut deserializer).unwrap();
-        assert_eq!(deser_float, float);
+    fn test_not_nan_from_sql() {
+        assert_eq!(not_nan_from_sql(1.0f64).unwrap(), NotNan(1.0f64));
+        assert!(not_nan_from_sql(f64::NAN).is_err());
     }
 
-    #[test]
-    fn test_not_nan() {
-        let float = NotNan(1.0f64);
-        let mut serializer = DefaultSerializer::default();
-        serializer
-            .serialize_value(&float)
-            .expect("failed to archive value");
-        let len = serializer.pos();
-        let buffer = serializer.into_serializer().into_inner();
+    #[cfg(all(test, feature = "diesel_sqlite"))]
+    mod sqlite_tests {
+        use super::*;
+        use diesel::connection::SimpleConnection;
+        use diesel::deserialize::FromSqlRow;
+        use diesel::dsl::sql;
+        use diesel::query_builder::QueryId;
+        use diesel::sql_types::{HasSqlType, SingleValue};
+        use diesel::sqlite::Sqlite;
+        use diesel::{sql_query, Connection, RunQueryDsl, SqliteConnection};
+
+        // Writes `value` through a bind parameter and reads it back, so both `ToSql` and
+        // `FromSql` go through SQLite.
+        fn round_trip<T, ST>(value: T) -> T
+        where
+            T: ToSql<ST, Sqlite> + FromSqlRow<ST, Sqlite> + 'static,
+            ST: SingleValue + QueryId + 'static,
+            Sqlite: HasSqlType<ST>,
+        {
+            let mut conn = SqliteConnection::establish(":memory:").unwrap();
+            conn.batch_execute("CREATE TABLE floats (x DOUBLE NOT NULL)")
+                .unwrap();
+            sql_query("INSERT INTO floats VALUES (?)")
+                .bind::<ST, _>(value)
+                .execute(&mut conn)
+                .unwrap();
+            diesel::select(sql::<ST>("(SELECT x FROM floats)"))
+                .get_result(&mut conn)
+                .unwrap()
+        }
 
-        let archived_value = unsafe { archived_root::<NotNan<f64>>(&buffer[0..len]) };
-        assert_eq!(archived_value, &float);
-        let mut deserializer = DefaultDeserializer::default();
-        let deser_float: NotNan<f64> = archived_value.deserialize(&mut deserializer).unwrap();
-        assert_eq!(deser_float, float);
+        #[test]
+        fn test_round_trip() {
+            assert_eq!(
+                round_trip::<_, Double>(OrderedFloat(-2.5f64)),
+                OrderedFloat(-2.5)
+            );
+            assert_eq!(
+                round_trip::<_, Double>(OrderedFloat(f64::INFINITY)),
+                OrderedFloat(f64::INFINITY)
+            );
+            assert_eq!(
+                round_trip::<_, Float>(OrderedFloat(0.5f32)),
+                OrderedFloat(0.5)
+            );
+            assert_eq!(round_trip::<_, Double>(NotNan(1e300f64)), NotNan(1e300));
+            assert_eq!(round_trip::<_, Float>(NotNan(-1.5f32)), NotNan(-1.5));
+        }
     }
 }
 
-#[cfg(feature = "speedy")]
-mod impl_speedy {
+#[cfg(feature = "sqlx")]
+mod impl_sqlx {
     use super::{NotNan, OrderedFloat};
     use num_traits::float::FloatCore;
-    use speedy::{Context, Readable, Reader, Writable, Writer};
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::{Database, Decode, Encode, Type};
 
-    impl<C, T> Writable<C> for OrderedFloat<T>
-    where
-        C: Context,
-        T: Writable<C>,
-    {
-        fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
-            self.0.write_to(writer)
+    impl<DB: Database, T: FloatCore + Type<DB>> Type<DB> for OrderedFloat<T> {
+        fn type_info() -> DB::TypeInfo {
+            T::type_info()
         }
 
-        fn bytes_needed(&self) -> Result<usize, C::Error> {
-            self.0.bytes_needed()
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            T::compatible(ty)
         }
     }
 
-    impl<C, T> Writable<C> for NotNan<T>
-    where
-        C: Context,
-        T: Writable<C>,
-    {
-        fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
-            self.0.write_to(writer)
+    impl<'q, DB: Database, T: FloatCore + Encode<'q, DB>> Encode<'q, DB> for OrderedFloat<T> {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            self.0.encode_by_ref(buf)
         }
 
-        fn bytes_needed(&self) -> Result<usize, C::Error> {
-            self.0.bytes_needed()
+        fn size_hint(&self) -> usize {
+            self.0.size_hint()
         }
     }
 
-    impl<'a, T, C: Context> Readable<'a, C> for OrderedFloat<T>
-    where
-        T: Readable<'a, C>,
-    {
-        fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
-            T::read_from(reader).map(OrderedFloat)
+    impl<'r, DB: Database, T: FloatCore + Decode<'r, DB>> Decode<'r, DB> for OrderedFloat<T> {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            T::decode(value).map(OrderedFloat)
         }
+    }
 
-        fn minimum_bytes_needed() -> usize {
-            T::minimum_bytes_needed()
+    impl<DB: Database, T: FloatCore + Type<DB>> Type<DB> for NotNan<T> {
+        fn type_info() -> DB::TypeInfo {
+            T::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            T::compatible(ty)
         }
     }
 
-    impl<'a, T: FloatCore, C: Context> Readable<'a, C> for NotNan<T>
-    where
-        T: Readable<'a, C>,
-    {
-        fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
-            let value: T = reader.read_value()?;
-            Self::new(value).map_err(|error| {
-                speedy::Error::custom(std::format!("failed to read NotNan: {}", error)).into()
-            })
+    impl<'q, DB: Database, T: FloatCore + Encode<'q, DB>> Encode<'q, DB> for NotNan<T> {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            self.0.encode_by_ref(buf)
         }
 
-        fn minimum_bytes_needed() -> usize {
-            T::minimum_bytes_needed()
+        fn size_hint(&self) -> usize {
+            self.0.size_hint()
         }
     }
 
-    #[test]
-    fn test_ordered_float() {
-        let float = OrderedFloat(1.0f64);
-        let buffer = float.write_to_vec().unwrap();
-        let deser_float: OrderedFloat<f64> = OrderedFloat::read_from_buffer(&buffer).unwrap();
-        assert_eq!(deser_float, float);
+    impl<'r, DB: Database, T: FloatCore + Decode<'r, DB>> Decode<'r, DB> for NotNan<T> {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            T::decode(value).and_then(not_nan_from_db)
+        }
     }
 
-    #[test]
-    fn test_not_nan() {
-        let float = NotNan(1.0f64);
-        let buffer = float.write_to_vec().unwrap();
-        let deser_float: NotNan<f64> = NotNan::read_from_buffer(&buffer).unwrap();
-        assert_eq!(deser_float, float);
+    fn not_nan_from_db<T: FloatCore>(float: T) -> Result<NotNan<T>, BoxDynError> {
+        NotNan::new(float).map_err(|_| "expected a non-NaN float".into())
+    }
+
+    /// Compiling this checks that the wrappers can be used with any database that supports the
+    /// underlying float type.
+    fn _wrappers_implement_sqlx_traits<DB: Database>()
+    where
+        f64: Type<DB> + for<'q> Encode<'q, DB> + for<'r> Decode<'r, DB>,
+    {
+        fn check<T: Type<DB> + for<'q> Encode<'q, DB> + for<'r> Decode<'r, DB>, DB: Database>() {}
+        check::<OrderedFloat<f64>, DB>();
+        check::<NotNan<f64>, DB>();
     }
 
     #[test]
-    fn test_not_nan_with_nan() {
-        let nan_buf = f64::nan().write_to_vec().unwrap();
-        let nan_err: Result<NotNan<f64>, _> = NotNan::read_from_buffer(&nan_buf);
-        assert!(nan_err.is_err());
+    fn test_not_nan_decode_rejects_nan() {
+        assert_eq!(not_nan_from_db(1.0f64).unwrap(), NotNan(1.0f64));
+        assert!(not_nan_from_db(f64::NAN).is_err());
     }
 }
 
-#[cfg(feature = "borsh")]
-mod impl_borsh {
-    extern crate borsh;
+#[cfg(feature = "validator")]
+mod impl_validator {
     use super::{NotNan, OrderedFloat};
-    use num_traits::float::FloatCore;
+    use validator::ValidateRange;
+
+    macro_rules! impl_validate_range {
+        ($($wrapper:ident<$f:ident>),*) => {$(
+            impl ValidateRange<$f> for $wrapper<$f> {
+                fn validate_range(
+                    &self,
+                    min: Option<$f>,
+                    max: Option<$f>,
+                    exclusive_min: Option<$f>,
+                    exclusive_max: Option<$f>,
+                ) -> bool {
+                    // NaN is outside every range. Without this, the IEEE comparisons below would
+                    // let it pass any bound.
+                    if self.0.is_nan() {
+                        return min.is_none()
+                            && max.is_none()
+                            && exclusive_min.is_none()
+                            && exclusive_max.is_none();
+                    }
+                    self.0.validate_range(min, max, exclusive_min, exclusive_max)
+                }
 
-    impl<T> borsh::BorshSerialize for OrderedFloat<T>
-    where
-        T: borsh::BorshSerialize,
-    {
-        #[inline]
-        fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
-            <T as borsh::BorshSerialize>::serialize(&self.0, writer)
-        }
-    }
+                fn greater_than(&self, max: $f) -> Option<bool> {
+                    Some(self.0 > max)
+                }
 
-    impl<T> borsh::BorshDeserialize for OrderedFloat<T>
-    where
-        T: borsh::BorshDeserialize,
-    {
-        #[inline]
-        fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
-            <T as borsh::BorshDeserialize>::deserialize_reader(reader).map(Self)
-        }
+                fn less_than(&self, min: $f) -> Option<bool> {
+                    Some(self.0 < min)
+                }
+            }
+        )*};
     }
 
-    impl<T> borsh::BorshSerialize for NotNan<T>
-    where
-        T: borsh::BorshSerialize,
-    {
-        #[inline]
-        fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
-            <T as borsh::BorshSerialize>::serialize(&self.0, writer)
+    impl_validate_range! {OrderedFloat<f32>, OrderedFloat<f64>, NotNan<f32>, NotNan<f64>}
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_range() {
+            let in_unit = |x: f64| NotNan(x).validate_range(Some(0.0), Some(1.0), None, None);
+            assert!(in_unit(0.0));
+            assert!(in_unit(1.0));
+            assert!(!in_unit(1.5));
+            assert!(!in_unit(-0.1));
+
+            let positive = |x: f32| OrderedFloat(x).validate_range(None, None, Some(0.0), None);
+            assert!(positive(1e-30));
+            assert!(!positive(0.0));
+            assert!(!positive(f32::NAN));
         }
-    }
 
-    impl<T> borsh::BorshDeserialize for NotNan<T>
-    where
-        T: FloatCore + borsh::BorshDeserialize,
-    {
-        #[inline]
-        fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
-            let float = <T as borsh::BorshDeserialize>::deserialize_reader(reader)?;
-            NotNan::new(float).map_err(|_| {
-                borsh::io::Error::new(
-                    borsh::io::ErrorKind::InvalidData,
-                    "expected a non-NaN float",
-                )
-            })
+        #[test]
+        fn test_nan_fails_every_bound() {
+            let nan = OrderedFloat(f64::NAN);
+            assert!(!nan.validate_range(Some(0.0), None, None, None));
+            assert!(!nan.validate_range(None, Some(0.0), None, None));
+            assert!(!nan.validate_range(None, None, Some(0.0), None));
+            assert!(!nan.validate_range(None, None, None, Some(0.0)));
+            assert!(nan.validate_range(None, None, None, None));
         }
     }
+}
 
-    #[test]
-    fn test_ordered_float() {
-        let float = crate::OrderedFloat(1.0f64);
-        let buffer = borsh::to_vec(&float).expect("failed to serialize value");
-        let deser_float: crate::OrderedFloat<f64> =
-            borsh::from_slice(&buffer).expect("failed to deserialize value");
-        assert_eq!(deser_float, float);
+#[cfg(feature = "rdst")]
+mod impl_rdst {
+    use super::{canonicalize, OrderedFloat};
+    use rdst::RadixKey;
+
+    // The key is the usual order-preserving encoding of the bits (flip every bit of negative
+    // values, only the sign bit of positive ones), taken after canonicalizing so that `-0.0` and
+    // `+0.0` share a key and every NaN gets the positive canonical NaN's key, which is above
+    // `+inf`. Radix sorting therefore agrees with `Ord`, including NaN sorting last.
+    macro_rules! impl_radix_key {
+        ($($f:ident, $bits:ident);*) => {$(
+            impl RadixKey for OrderedFloat<$f> {
+                const LEVELS: usize = core::mem::size_of::<$f>();
+
+                #[inline]
+                fn get_level(&self, level: usize) -> u8 {
+                    let bits = canonicalize(self.0).to_bits();
+                    let key = if bits >> ($bits::BITS - 1) == 1 {
+                        !bits
+                    } else {
+                        bits | (1 << ($bits::BITS - 1))
+                    };
+                    (key >> (level * 8)) as u8
+                }
+            }
+        )*};
     }
 
-    #[test]
-    fn test_not_nan() {
-        let float = crate::NotNan(1.0f64);
-        let buffer = borsh::to_vec(&float).expect("failed to serialize value");
-        let deser_float: crate::NotNan<f64> =
-            borsh::from_slice(&buffer).expect("failed to deserialize value");
-        assert_eq!(deser_float, float);
+    impl_radix_key! {f32, u32; f64, u64}
+
+    #[cfg(all(test, feature = "std"))]
+    mod tests {
+        use super::*;
+        use rdst::RadixSort;
+        use std::vec::Vec;
+
+        fn check<T: Copy + Ord + RadixKey + Send + Sync + core::fmt::Debug>(values: &[T]) {
+            let mut radix = values.to_vec();
+            radix.radix_sort_unstable();
+            let mut sorted = values.to_vec();
+            sorted.sort();
+            assert_eq!(radix, sorted);
+        }
+
+        #[test]
+        fn test_radix_sort_matches_sort() {
+            let specials = [
+                f64::NAN,
+                -f64::NAN,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                0.0,
+                -0.0,
+                f64::MIN_POSITIVE,
+                -f64::from_bits(1),
+                f64::MAX,
+                f64::MIN,
+            ];
+            // xorshift64, so the test needs no extra dependencies.
+            let mut state = 0x2545_f491_4f6c_dd1du64;
+            let mut values: Vec<OrderedFloat<f64>> =
+                specials.iter().map(|&x| OrderedFloat(x)).collect();
+            for _ in 0..1000 {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                values.push(OrderedFloat(f64::from_bits(state)));
+                values.push(OrderedFloat((state % 2001) as f64 - 1000.0));
+            }
+            check(&values);
+
+            let values: Vec<OrderedFloat<f32>> =
+                values.iter().map(|x| OrderedFloat(x.0 as f32)).collect();
+            check(&values);
+        }
+
+        #[test]
+        fn test_nan_sorts_last() {
+            let mut values = [
+                OrderedFloat(f32::NAN),
+                OrderedFloat(f32::INFINITY),
+                OrderedFloat(-1.0),
+            ];
+            values.radix_sort_unstable();
+            assert_eq!(values[0], OrderedFloat(-1.0));
+            assert_eq!(values[1], OrderedFloat(f32::INFINITY));
+            assert!(values[2].0.is_nan());
+        }
     }
 }
 
-#[cfg(all(feature = "std", feature = "schemars"))]
-mod impl_schemars {
-    extern crate schemars;
-    use self::schemars::gen::SchemaGenerator;
-    use self::schemars::schema::{InstanceType, Schema, SchemaObject};
-    use super::{NotNan, OrderedFloat};
+#[cfg(feature = "bytes")]
+mod impl_bytes {
+    use super::{FloatIsNan, NotNan, OrderedFloat};
+    use bytes::{Buf, BufMut};
+
+    // Values are written as their IEEE 754 bits in big-endian (network) order, as in the
+    // Postgres binary format for `float4` and `float8`.
+    macro_rules! impl_bytes {
+        ($($f:ident, $put:ident, $get:ident);*) => {$(
+            impl OrderedFloat<$f> {
+                #[doc = concat!("Writes the value to `buf` as ", stringify!($f), " bits in big-endian order.")]
+                /// Every value, including each NaN payload, is written exactly.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `buf` doesn't have enough remaining capacity.
+                #[inline]
+                pub fn put_to(&self, buf: &mut impl BufMut) {
+                    buf.$put(self.0)
+                }
 
-    macro_rules! primitive_float_impl {
-        ($type:ty, $schema_name:literal) => {
-            impl schemars::JsonSchema for $type {
-                fn is_referenceable() -> bool {
-                    false
+                #[doc = concat!("Reads a value written by [`put_to`](Self::put_to), as ", stringify!($f), " bits in")]
+                /// big-endian order, and advances `buf` past it.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `buf` doesn't have enough remaining bytes.
+                #[inline]
+                pub fn get_from(buf: &mut impl Buf) -> Self {
+                    OrderedFloat(buf.$get())
                 }
+            }
 
-                fn schema_name() -> std::string::String {
-                    std::string::String::from($schema_name)
+            impl NotNan<$f> {
+                #[doc = concat!("Writes the value to `buf` as ", stringify!($f), " bits in big-endian order.")]
+                ///
+                /// # Panics
+                ///
+                /// Panics if `buf` doesn't have enough remaining capacity.
+                #[inline]
+                pub fn put_to(&self, buf: &mut impl BufMut) {
+                    buf.$put(self.0)
                 }
 
-                fn json_schema(_: &mut SchemaGenerator) -> Schema {
-                    SchemaObject {
-                        instance_type: Some(InstanceType::Number.into()),
-                        format: Some(std::string::String::from($schema_name)),
-                        ..Default::default()
-                    }
-                    .into()
+                #[doc = concat!("Reads a value written by [`put_to`](Self::put_to), as ", stringify!($f), " bits in")]
+                /// big-endian order, and advances `buf` past it. Returns `Err` if the value is
+                /// NaN, in which case its bytes are still consumed.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `buf` doesn't have enough remaining bytes.
+                #[inline]
+                pub fn get_from(buf: &mut impl Buf) -> Result<Self, FloatIsNan> {
+                    NotNan::new(buf.$get())
                 }
             }
-        };
+        )*};
     }
 
-    primitive_float_impl!(OrderedFloat<f32>, "float");
-    primitive_float_impl!(OrderedFloat<f64>, "double");
-    primitive_float_impl!(NotNan<f32>, "float");
-    primitive_float_impl!(NotNan<f64>, "double");
+    impl_bytes! {f32, put_f32, get_f32; f64, put_f64, get_f64}
 
-    #[test]
-    fn schema_generation_does_not_panic_for_common_floats() {
-        {
-            let schema = schemars::gen::SchemaGenerator::default()
-                .into_root_schema_for::<OrderedFloat<f32>>();
-            assert_eq!(
-                schema.schema.instance_type,
-                Some(schemars::schema::SingleOrVec::Single(std::boxed::Box::new(
-                    schemars::schema::InstanceType::Number
-                )))
-            );
-            assert_eq!(
-                schema.schema.metadata.unwrap().title.unwrap(),
-                std::string::String::from("float")
-            );
-        }
-        {
-            let schema = schemars::gen::SchemaGenerator::default()
-                .into_root_schema_for::<OrderedFloat<f64>>();
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use bytes::BytesMut;
+
+        #[test]
+        fn test_round_trip() {
+            let mut buf = BytesMut::new();
+            let values = [
+                0.0,
+                -0.0,
+                1.5,
+                f64::MAX,
+                f64::from_bits(1),
+                f64::NEG_INFINITY,
+            ];
+            for &x in &values {
+                NotNan::new(x).unwrap().put_to(&mut buf);
+                OrderedFloat(x).put_to(&mut buf);
+            }
+            OrderedFloat(f64::from_bits(0x7ff8_0000_dead_beef)).put_to(&mut buf);
+            NotNan::new(2.5f32).unwrap().put_to(&mut buf);
+            assert_eq!(buf.len(), values.len() * 16 + 8 + 4);
+
+            let mut buf = buf.freeze();
+            for &x in &values {
+                let n = NotNan::<f64>::get_from(&mut buf).unwrap();
+                assert_eq!(n.into_inner().to_bits(), x.to_bits());
+                assert_eq!(
+                    OrderedFloat::<f64>::get_from(&mut buf).0.to_bits(),
+                    x.to_bits()
+                );
+            }
             assert_eq!(
-                schema.schema.instance_type,
-                Some(schemars::schema::SingleOrVec::Single(std::boxed::Box::new(
-                    schemars::schema::InstanceType::Number
-                )))
+                OrderedFloat::<f64>::get_from(&mut buf).0.to_bits(),
+                0x7ff8_0000_dead_beef
             );
             assert_eq!(
-                schema.schema.metadata.unwrap().title.unwrap(),
-                std::string::String::from("double")
+                NotNan::<f32>::get_from(&mut buf),
+                Ok(NotNan::new(2.5).unwrap())
             );
+            assert!(buf.is_empty());
         }
-        {
-            let schema =
-                schemars::gen::SchemaGenerator::default().into_root_schema_for::<NotNan<f32>>();
-            assert_eq!(
-                schema.schema.instance_type,
-                Some(schemars::schema::SingleOrVec::Single(std::boxed::Box::new(
-                    schemars::schema::InstanceType::Number
-                )))
-            );
-            assert_eq!(
-                schema.schema.metadata.unwrap().title.unwrap(),
-                std::string::String::from("float")
-            );
+
+        #[test]
+        fn test_big_endian() {
+            let mut buf = BytesMut::new();
+            OrderedFloat(1.0f64).put_to(&mut buf);
+            NotNan::new(-2.0f32).unwrap().put_to(&mut buf);
+            assert_eq!(&buf[..], &[0x3f, 0xf0, 0, 0, 0, 0, 0, 0, 0xc0, 0, 0, 0]);
         }
-        {
-            let schema =
-                schemars::gen::SchemaGenerator::default().into_root_schema_for::<NotNan<f64>>();
-            assert_eq!(
-                schema.schema.instance_type,
-                Some(schemars::schema::SingleOrVec::Single(std::boxed::Box::new(
-                    schemars::schema::InstanceType::Number
-                )))
-            );
+
+        #[test]
+        fn test_nan_is_rejected() {
+            let mut buf = BytesMut::new();
+            OrderedFloat(f32::NAN).put_to(&mut buf);
+            OrderedFloat(1.0f32).put_to(&mut buf);
+            let mut buf = buf.freeze();
+            assert_eq!(NotNan::<f32>::get_from(&mut buf), Err(FloatIsNan));
             assert_eq!(
-                schema.schema.metadata.unwrap().title.unwrap(),
-                std::string::String::from("double")
+                NotNan::<f32>::get_from(&mut buf),
+                Ok(NotNan::new(1.0).unwrap())
             );
         }
     }
-    #[test]
-    fn ordered_float_schema_match_primitive_schema() {
-        {
-            let of_schema = schemars::gen::SchemaGenerator::default()
-                .into_root_schema_for::<OrderedFloat<f32>>();
-            let prim_schema =
-                schemars::gen::SchemaGenerator::default().into_root_schema_for::<f32>();
-            assert_eq!(of_schema, prim_schema);
-        }
-        {
-            let of_schema = schemars::gen::SchemaGenerator::default()
-                .into_root_schema_for::<OrderedFloat<f64>>();
-            let prim_schema =
-                schemars::gen::SchemaGenerator::default().into_root_schema_for::<f64>();
-            assert_eq!(of_schema, prim_schema);
-        }
-        {
-            let of_schema =
-                schemars::gen::SchemaGenerator::default().into_root_schema_for::<NotNan<f32>>();
-            let prim_schema =
-                schemars::gen::SchemaGenerator::default().into_root_schema_for::<f32>();
-            assert_eq!(of_schema, prim_schema);
-        }
-        {
-            let of_schema =
-                schemars::gen::SchemaGenerator::default().into_root_schema_for::<NotNan<f64>>();
-            let prim_schema =
-                schemars::gen::SchemaGenerator::default().into_root_schema_for::<f64>();
-            assert_eq!(of_schema, prim_schema);
-        }
-    }
 }
 
-#[cfg(feature = "rand")]
-mod impl_rand {
+#[cfg(feature = "half")]
+mod impl_half_convert {
     use super::{NotNan, OrderedFloat};
-    use rand::distributions::uniform::*;
-    use rand::distributions::{Distribution, Open01, OpenClosed01, Standard};
-    use rand::Rng;
-
-    macro_rules! impl_distribution {
-        ($dist:ident, $($f:ty),+) => {
-            $(
-            impl Distribution<NotNan<$f>> for $dist {
-                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NotNan<$f> {
-                    // 'rand' never generates NaN values in the Standard, Open01, or
-                    // OpenClosed01 distributions. Using 'new_unchecked' is therefore
-                    // safe.
-                    unsafe { NotNan::new_unchecked(self.sample(rng)) }
+    use half::{bf16, f16};
+
+    macro_rules! impl_half_from {
+        ($half:ty) => {
+            impl From<OrderedFloat<$half>> for $half {
+                #[inline]
+                fn from(f: OrderedFloat<$half>) -> $half {
+                    f.0
                 }
             }
 
-            impl Distribution<OrderedFloat<$f>> for $dist {
-                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> OrderedFloat<$f> {
-                    OrderedFloat(self.sample(rng))
+            impl From<NotNan<$half>> for $half {
+                #[inline]
+                fn from(f: NotNan<$half>) -> $half {
+                    f.0
                 }
             }
-            )*
-        }
+        };
     }
 
-    impl_distribution! { Standard, f32, f64 }
-    impl_distribution! { Open01, f32, f64 }
-    impl_distribution! { OpenClosed01, f32, f64 }
+    impl_half_from!(f16);
+    impl_half_from!(bf16);
 
-    /// A sampler for a uniform distribution
-    #[derive(Clone, Copy, Debug)]
-    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-    pub struct UniformNotNan<T>(UniformFloat<T>);
-    impl SampleUniform for NotNan<f32> {
-        type Sampler = UniformNotNan<f32>;
-    }
-    impl SampleUniform for NotNan<f64> {
-        type Sampler = UniformNotNan<f64>;
-    }
-    impl<T> PartialEq for UniformNotNan<T>
-    where
-        UniformFloat<T>: PartialEq,
-    {
-        fn eq(&self, other: &Self) -> bool {
-            self.0 == other.0
-        }
-    }
+    #[test]
+    fn test_half_from() {
+        let x = f16::from_f32(1.5);
+        assert_eq!(f16::from(OrderedFloat(x)), x);
+        assert_eq!(f16::from(NotNan::new(x).unwrap()), x);
 
-    /// A sampler for a uniform distribution
-    #[derive(Clone, Copy, Debug)]
-    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-    pub struct UniformOrdered<T>(UniformFloat<T>);
-    impl SampleUniform for OrderedFloat<f32> {
-        type Sampler = UniformOrdered<f32>;
-    }
-    impl SampleUniform for OrderedFloat<f64> {
-        type Sampler = UniformOrdered<f64>;
-    }
-    impl<T> PartialEq for UniformOrdered<T>
-    where
-        UniformFloat<T>: PartialEq,
-    {
-        fn eq(&self, other: &Self) -> bool {
-            self.0 == other.0
-        }
+        let y = bf16::from_f32(-2.5);
+        assert_eq!(bf16::from(OrderedFloat(y)), y);
+        assert_eq!(bf16::from(NotNan::new(y).unwrap()), y);
+
+        let inner: f16 = OrderedFloat(x).into();
+        assert_eq!(inner, OrderedFloat(x).into_inner());
     }
+}
 
-    macro_rules! impl_uniform_sampler {
-        ($f:ty) => {
-            impl UniformSampler for UniformNotNan<$f> {
-                type X = NotNan<$f>;
-                fn new<B1, B2>(low: B1, high: B2) -> Self
-                where
-                    B1: SampleBorrow<Self::X> + Sized,
-                    B2: SampleBorrow<Self::X> + Sized,
-                {
-                    UniformNotNan(UniformFloat::<$f>::new(low.borrow().0, high.borrow().0))
+/// `half::f16` and `half::bf16` implement the `core::ops` traits and `FloatCore`, so the
+/// generic arithmetic impls already apply to `OrderedFloat<f16>` and `OrderedFloat<bf16>`.
+///
+/// The `half` types have no `powf`/`powi` of their own, so these `Pow` impls compute in `f32`
+/// and round the result back to the half type. The result may therefore differ in the last
+/// place from a correctly rounded half-precision power.
+#[cfg(all(feature = "half", any(feature = "std", feature = "libm")))]
+mod impl_half {
+    use super::OrderedFloat;
+    use half::{bf16, f16};
+    use num_traits::{Float, Pow};
+
+    macro_rules! impl_half_pow {
+        ($half:ty, $rhs:ty, $op:ident, $cast:ty) => {
+            impl Pow<$rhs> for OrderedFloat<$half> {
+                type Output = OrderedFloat<$half>;
+                #[inline]
+                fn pow(self, rhs: $rhs) -> OrderedFloat<$half> {
+                    OrderedFloat(<$half>::from_f32(Float::$op(self.0.to_f32(), rhs as $cast)))
                 }
-                fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
-                where
-                    B1: SampleBorrow<Self::X> + Sized,
-                    B2: SampleBorrow<Self::X> + Sized,
-                {
-                    UniformSampler::new(low, high)
+            }
+
+            impl<'a> Pow<&'a $rhs> for OrderedFloat<$half> {
+                type Output = OrderedFloat<$half>;
+                #[inline]
+                fn pow(self, rhs: &'a $rhs) -> OrderedFloat<$half> {
+                    self.pow(*rhs)
                 }
-                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
-                    // UniformFloat.sample() will never return NaN.
-                    unsafe { NotNan::new_unchecked(self.0.sample(rng)) }
+            }
+
+            impl<'a> Pow<$rhs> for &'a OrderedFloat<$half> {
+                type Output = OrderedFloat<$half>;
+                #[inline]
+                fn pow(self, rhs: $rhs) -> OrderedFloat<$half> {
+                    (*self).pow(rhs)
+                }
+            }
+
+            impl<'a, 'b> Pow<&'a $rhs> for &'b OrderedFloat<$half> {
+                type Output = OrderedFloat<$half>;
+                #[inline]
+                fn pow(self, rhs: &'a $rhs) -> OrderedFloat<$half> {
+                    (*self).pow(*rhs)
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_half_self_pow {
+        ($half:ty) => {
+            impl Pow<OrderedFloat<$half>> for OrderedFloat<$half> {
+                type Output = OrderedFloat<$half>;
+                #[inline]
+                fn pow(self, rhs: OrderedFloat<$half>) -> OrderedFloat<$half> {
+                    self.pow(rhs.0.to_f32())
                 }
             }
 
-            impl UniformSampler for UniformOrdered<$f> {
-                type X = OrderedFloat<$f>;
-                fn new<B1, B2>(low: B1, high: B2) -> Self
-                where
-                    B1: SampleBorrow<Self::X> + Sized,
-                    B2: SampleBorrow<Self::X> + Sized,
-                {
-                    UniformOrdered(UniformFloat::<$f>::new(low.borrow().0, high.borrow().0))
+            impl<'a> Pow<&'a OrderedFloat<$half>> for OrderedFloat<$half> {
+                type Output = OrderedFloat<$half>;
+                #[inline]
+                fn pow(self, rhs: &'a OrderedFloat<$half>) -> OrderedFloat<$half> {
+                    self.pow(rhs.0.to_f32())
                 }
-                fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
-                where
-                    B1: SampleBorrow<Self::X> + Sized,
-                    B2: SampleBorrow<Self::X> + Sized,
-                {
-                    UniformSampler::new(low, high)
+            }
+
+            impl<'a> Pow<OrderedFloat<$half>> for &'a OrderedFloat<$half> {
+                type Output = OrderedFloat<$half>;
+                #[inline]
+                fn pow(self, rhs: OrderedFloat<$half>) -> OrderedFloat<$half> {
+                    (*self).pow(rhs.0.to_f32())
                 }
-                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
-                    OrderedFloat(self.0.sample(rng))
+            }
+
+            impl<'a, 'b> Pow<&'a OrderedFloat<$half>> for &'b OrderedFloat<$half> {
+                type Output = OrderedFloat<$half>;
+                #[inline]
+                fn pow(self, rhs: &'a OrderedFloat<$half>) -> OrderedFloat<$half> {
+                    (*self).pow(rhs.0.to_f32())
                 }
             }
         };
     }
 
-    impl_uniform_sampler! { f32 }
-    impl_uniform_sampler! { f64 }
+    impl_half_pow! {f16, i8, powi, i32}
+    impl_half_pow! {f16, i16, powi, i32}
+    impl_half_pow! {f16, u8, powi, i32}
+    impl_half_pow! {f16, u16, powi, i32}
+    impl_half_pow! {f16, i32, powi, i32}
+    impl_half_pow! {f16, f32, powf, f32}
+    impl_half_pow! {bf16, i8, powi, i32}
+    impl_half_pow! {bf16, i16, powi, i32}
+    impl_half_pow! {bf16, u8, powi, i32}
+    impl_half_pow! {bf16, u16, powi, i32}
+    impl_half_pow! {bf16, i32, powi, i32}
+    impl_half_pow! {bf16, f32, powf, f32}
+    impl_half_self_pow! {f16}
+    impl_half_self_pow! {bf16}
 
-    #[cfg(all(test, feature = "randtest"))]
-    mod tests {
-        use super::*;
+    #[test]
+    fn test_half_arithmetic() {
+        let a = OrderedFloat(f16::from_f32(1.5));
+        let b = OrderedFloat(f16::from_f32(0.5));
+        assert_eq!(a + b, OrderedFloat(f16::from_f32(2.0)));
+        assert_eq!(a - b, OrderedFloat(f16::from_f32(1.0)));
+        assert_eq!(a * b, OrderedFloat(f16::from_f32(0.75)));
+        assert_eq!(a / b, OrderedFloat(f16::from_f32(3.0)));
+        assert_eq!(a % b, OrderedFloat(f16::from_f32(0.0)));
+        assert!(a > b);
+
+        let c = OrderedFloat(bf16::from_f32(1.5));
+        let d = OrderedFloat(bf16::from_f32(0.5));
+        assert_eq!(c + d, OrderedFloat(bf16::from_f32(2.0)));
+        assert_eq!(c * d, OrderedFloat(bf16::from_f32(0.75)));
+    }
 
-        fn sample_fuzz<T>()
-        where
-            Standard: Distribution<NotNan<T>>,
-            Open01: Distribution<NotNan<T>>,
-            OpenClosed01: Distribution<NotNan<T>>,
-            Standard: Distribution<OrderedFloat<T>>,
-            Open01: Distribution<OrderedFloat<T>>,
-            OpenClosed01: Distribution<OrderedFloat<T>>,
-            T: crate::Float,
-        {
-            let mut rng = rand::thread_rng();
-            let f1: NotNan<T> = rng.sample(Standard);
-            let f2: NotNan<T> = rng.sample(Open01);
-            let f3: NotNan<T> = rng.sample(OpenClosed01);
-            let _: OrderedFloat<T> = rng.sample(Standard);
-            let _: OrderedFloat<T> = rng.sample(Open01);
-            let _: OrderedFloat<T> = rng.sample(OpenClosed01);
-            assert!(!f1.into_inner().is_nan());
-            assert!(!f2.into_inner().is_nan());
-            assert!(!f3.into_inner().is_nan());
+    #[test]
+    fn test_half_pow() {
+        let three = OrderedFloat(f16::from_f32(3.0));
+        assert_eq!(three.pow(2i32), OrderedFloat(f16::from_f32(9.0)));
+        assert_eq!(three.pow(2u8), OrderedFloat(f16::from_f32(9.0)));
+        assert_eq!(three.pow(2.0f32), OrderedFloat(f16::from_f32(9.0)));
+        assert_eq!(
+            three.pow(OrderedFloat(f16::from_f32(2.0))),
+            OrderedFloat(f16::from_f32(9.0))
+        );
+        let three = OrderedFloat(bf16::from_f32(3.0));
+        assert_eq!(three.pow(&2i16), OrderedFloat(bf16::from_f32(9.0)));
+        assert_eq!(
+            (&three).pow(OrderedFloat(bf16::from_f32(2.0))),
+            OrderedFloat(bf16::from_f32(9.0))
+        );
+    }
+}
+
+#[cfg(feature = "abomonation")]
+mod impl_abomonation {
+    use super::{NotNan, OrderedFloat};
+    use abomonation::Abomonation;
+    use num_traits::float::FloatCore;
+    use std::io::{self, Write};
+
+    impl<T: Abomonation> Abomonation for OrderedFloat<T> {
+        #[inline]
+        unsafe fn entomb<W: Write>(&self, write: &mut W) -> io::Result<()> {
+            self.0.entomb(write)
         }
 
-        #[test]
-        fn sampling_f32_does_not_panic() {
-            sample_fuzz::<f32>();
+        #[inline]
+        unsafe fn exhume<'b>(&mut self, bytes: &'b mut [u8]) -> Option<&'b mut [u8]> {
+            self.0.exhume(bytes)
         }
 
-        #[test]
-        fn sampling_f64_does_not_panic() {
-            sample_fuzz::<f64>();
+        #[inline]
+        fn extent(&self) -> usize {
+            self.0.extent()
         }
+    }
 
-        #[test]
-        #[should_panic]
-        fn uniform_sampling_panic_on_infinity_notnan() {
-            let (low, high) = (
-                NotNan::new(0f64).unwrap(),
-                NotNan::new(f64::INFINITY).unwrap(),
-            );
-            let uniform = Uniform::new(low, high);
-            let _ = uniform.sample(&mut rand::thread_rng());
+    impl<T: FloatCore + Abomonation> Abomonation for NotNan<T> {
+        #[inline]
+        unsafe fn entomb<W: Write>(&self, write: &mut W) -> io::Result<()> {
+            self.0.entomb(write)
         }
 
-        #[test]
-        #[should_panic]
-        fn uniform_sampling_panic_on_infinity_ordered() {
-            let (low, high) = (OrderedFloat(0f64), OrderedFloat(f64::INFINITY));
-            let uniform = Uniform::new(low, high);
-            let _ = uniform.sample(&mut rand::thread_rng());
+        /// Restores the inner value, then returns `None` if it is NaN.
+        #[inline]
+        unsafe fn exhume<'b>(&mut self, bytes: &'b mut [u8]) -> Option<&'b mut [u8]> {
+            let rest = self.0.exhume(bytes)?;
+            if self.0.is_nan() {
+                None
+            } else {
+                Some(rest)
+            }
         }
 
-        #[test]
-        #[should_panic]
-        fn uniform_sampling_panic_on_nan_ordered() {
-            let (low, high) = (OrderedFloat(0f64), OrderedFloat(f64::NAN));
-            let uniform = Uniform::new(low, high);
-            let _ = uniform.sample(&mut rand::thread_rng());
+        #[inline]
+        fn extent(&self) -> usize {
+            self.0.extent()
         }
     }
-}
-
-#[cfg(feature = "proptest")]
-mod impl_proptest {
-    use super::{NotNan, OrderedFloat};
-    use proptest::arbitrary::{Arbitrary, StrategyFor};
-    use proptest::num::{f32, f64};
-    use proptest::strategy::{FilterMap, Map, Strategy};
-    use std::convert::TryFrom;
 
-    macro_rules! impl_arbitrary {
-        ($($f:ident),+) => {
-            $(
-                impl Arbitrary for NotNan<$f> {
-                    type Strategy = FilterMap<StrategyFor<$f>, fn(_: $f) -> Option<NotNan<$f>>>;
-                    type Parameters = <$f as Arbitrary>::Parameters;
-                    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
-                        <$f>::arbitrary_with(params)
-                            .prop_filter_map("filter nan values", |f| NotNan::try_from(f).ok())
-                    }
-                }
+    #[test]
+    fn test_ordered_float() {
+        let floats = std::vec![
+            OrderedFloat(1.0f64),
+            OrderedFloat(-0.5),
+            OrderedFloat(f64::NAN)
+        ];
+        let mut bytes = std::vec::Vec::new();
+        unsafe { abomonation::encode(&floats, &mut bytes).unwrap() };
+        let (decoded, rest) =
+            unsafe { abomonation::decode::<std::vec::Vec<OrderedFloat<f64>>>(&mut bytes) }.unwrap();
+        assert_eq!(decoded, &floats);
+        assert!(rest.is_empty());
+    }
 
-                impl Arbitrary for OrderedFloat<$f> {
-                    type Strategy = Map<StrategyFor<$f>, fn(_: $f) -> OrderedFloat<$f>>;
-                    type Parameters = <$f as Arbitrary>::Parameters;
-                    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
-                        <$f>::arbitrary_with(params).prop_map(|f| OrderedFloat::from(f))
-                    }
-                }
-            )*
-        }
+    #[test]
+    fn test_not_nan() {
+        let floats = std::vec![NotNan(1.0f64), NotNan(-0.5)];
+        let mut bytes = std::vec::Vec::new();
+        unsafe { abomonation::encode(&floats, &mut bytes).unwrap() };
+        let (decoded, _) =
+            unsafe { abomonation::decode::<std::vec::Vec<NotNan<f64>>>(&mut bytes) }.unwrap();
+        assert_eq!(decoded, &floats);
     }
-    impl_arbitrary! { f32, f64 }
-}
 
-#[cfg(feature = "arbitrary")]
-mod impl_arbitrary {
-    use super::{FloatIsNan, NotNan, OrderedFloat};
-    use arbitrary::{Arbitrary, Unstructured};
-    use num_traits::FromPrimitive;
+    #[test]
+    fn test_not_nan_rejects_nan() {
+        let floats = std::vec![1.0f64, f64::NAN];
+        let mut bytes = std::vec::Vec::new();
+        unsafe { abomonation::encode(&floats, &mut bytes).unwrap() };
+        assert!(unsafe { abomonation::decode::<std::vec::Vec<NotNan<f64>>>(&mut bytes) }.is_none());
+    }
 
-    macro_rules! impl_arbitrary {
-        ($($f:ident),+) => {
-            $(
-                impl<'a> Arbitrary<'a> for NotNan<$f> {
-                    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
-                        let float: $f = u.arbitrary()?;
-                        match NotNan::new(float) {
-                            Ok(notnan_value) => Ok(notnan_value),
-                            Err(FloatIsNan) => {
-                                // If our arbitrary float input was a NaN (encoded by exponent = max
-                                // value), then replace it with a finite float, reusing the mantissa
-                                // bits.
-                                //
-                                // This means the output is not uniformly distributed among all
-                                // possible float values, but Arbitrary makes no promise that that
-                                // is true.
-                                //
-                                // An alternative implementation would be to return an
-                                // `arbitrary::Error`, but that is not as useful since it forces the
-                                // caller to retry with new random/fuzzed data; and the precendent of
-                                // `arbitrary`'s built-in implementations is to prefer the approach of
-                                // mangling the input bits to fit.
+    #[test]
+    fn test_ordered_float_delegates_to_inner() {
+        // `Vec` owns heap data, which is only written and restored if the impl delegates.
+        let value = OrderedFloat(std::vec![1u8, 2, 3]);
+        let mut bytes = std::vec::Vec::new();
+        unsafe { abomonation::encode(&value, &mut bytes).unwrap() };
+        assert_eq!(
+            abomonation::measure(&value),
+            core::mem::size_of::<std::vec::Vec<u8>>() + 3
+        );
+        let (decoded, rest) =
+            unsafe { abomonation::decode::<OrderedFloat<std::vec::Vec<u8>>>(&mut bytes) }.unwrap();
+        assert_eq!(decoded.0, value.0);
+        assert!(rest.is_empty());
+    }
+}
 
-                                let (mantissa, _exponent, sign) =
-                                    num_traits::float::FloatCore::integer_decode(float);
-                                let revised_float = <$f>::from_i64(
-                                    i64::from(sign) * mantissa as i64
-                                ).unwrap();
+#[cfg(all(test, feature = "bitcode"))]
+mod impl_bitcode {
+    // `OrderedFloat` derives `Encode` and `Decode`, delegating to the inner float. A derived
+    // `Decode` for `NotNan` couldn't reject NaN, and `bitcode` has no public API for writing a
+    // checking decoder, so `NotNan` goes through `bitcode`'s serde support instead.
+    use super::{NotNan, OrderedFloat};
 
-                                // If this unwrap() fails, then there is a bug in the above code.
-                                Ok(NotNan::new(revised_float).unwrap())
-                            }
-                        }
-                    }
+    #[test]
+    fn test_ordered_float() {
+        let floats = [
+            OrderedFloat(1.5f64),
+            OrderedFloat(-0.0),
+            OrderedFloat(f64::NAN),
+        ];
+        let mut buffer = bitcode::Buffer::new();
+        let bytes = buffer.encode(&floats).to_vec();
+        let decoded: [OrderedFloat<f64>; 3] = buffer.decode(&bytes).unwrap();
+        assert_eq!(decoded, floats);
+        assert!(decoded[1].0.is_sign_negative());
+
+        let f = OrderedFloat(2.5f32);
+        assert_eq!(
+            bitcode::decode::<OrderedFloat<f32>>(&bitcode::encode(&f)).unwrap(),
+            f
+        );
+        // Same encoding as the inner float.
+        assert_eq!(bitcode::encode(&f), bitcode::encode(&2.5f32));
+    }
 
-                    fn size_hint(depth: usize) -> (usize, Option<usize>) {
-                        <$f as Arbitrary>::size_hint(depth)
-                    }
-                }
+    #[test]
+    fn test_not_nan() {
+        let floats = [NotNan(1.5f64), NotNan(f64::INFINITY), NotNan(-0.0)];
+        let bytes = bitcode::serialize(&floats).unwrap();
+        let decoded: [NotNan<f64>; 3] = bitcode::deserialize(&bytes).unwrap();
+        assert_eq!(decoded, floats);
+        assert!(decoded[2].is_sign_negative());
+    }
 
-                impl<'a> Arbitrary<'a> for OrderedFloat<$f> {
-                    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
-                        let float: $f = u.arbitrary()?;
-                        Ok(OrderedFloat::from(float))
-                    }
+    #[test]
+    fn test_not_nan_rejects_nan() {
+        let bytes = bitcode::serialize(&[1.0f64, f64::NAN]).unwrap();
+        assert!(bitcode::deserialize::<[NotNan<f64>; 2]>(&bytes).is_err());
 
-                    fn size_hint(depth: usize) -> (usize, Option<usize>) {
-                        <$f as Arbitrary>::size_hint(depth)
-                    }
-                }
-            )*
-        }
+        let bytes = bitcode::serialize(&f32::NAN).unwrap();
+        assert!(bitcode::deserialize::<NotNan<f32>>(&bytes).is_err());
     }
-    impl_arbitrary! { f32, f64 }
 }
 
-#[cfg(feature = "bytemuck")]
-mod impl_bytemuck {
-    use super::{FloatCore, NotNan, OrderedFloat};
-    use bytemuck::{AnyBitPattern, CheckedBitPattern, NoUninit, Pod, TransparentWrapper, Zeroable};
+// Exercised by `cargo test --no-default-features`: these use only `core`, so they fail to build if
+// the canonicalization or hashing paths start depending on `std` or allocation.
+#[cfg(test)]
+mod no_std_test {
+    use super::{NotNan, OrderedFloat};
+    use core::hash::{Hash, Hasher};
 
-    unsafe impl<T: Zeroable> Zeroable for OrderedFloat<T> {}
+    /// FNV-1a, so the tests need neither `std`'s hashers nor an allocator.
+    struct Fnv(u64);
 
-    // The zero bit pattern is indeed not a NaN bit pattern.
-    unsafe impl<T: Zeroable> Zeroable for NotNan<T> {}
+    impl Hasher for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
 
-    unsafe impl<T: Pod> Pod for OrderedFloat<T> {}
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ u64::from(b)).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
 
-    // `NotNan<T>` can only implement `NoUninit` and not `Pod`, since not every bit pattern is
-    // valid (NaN bit patterns are invalid). `NoUninit` guarantees that we can read any bit pattern
-    // from the value, which is fine in this case.
-    unsafe impl<T: NoUninit> NoUninit for NotNan<T> {}
+    fn hash<T: Hash + ?Sized>(x: &T) -> u64 {
+        let mut h = Fnv(0xcbf29ce484222325);
+        x.hash(&mut h);
+        h.finish()
+    }
 
-    unsafe impl<T: FloatCore + AnyBitPattern> CheckedBitPattern for NotNan<T> {
-        type Bits = T;
+    #[test]
+    fn test_from_str() {
+        use super::ParseNotNanError;
+
+        assert_eq!("1.5".parse::<NotNan<f64>>(), Ok(NotNan(1.5)));
+        assert_eq!("NaN".parse::<NotNan<f64>>(), Err(ParseNotNanError::IsNaN));
+        assert!(matches!(
+            "one".parse::<NotNan<f32>>(),
+            Err(ParseNotNanError::ParseFloatError(_))
+        ));
+        assert_eq!(
+            "-0.25".parse::<OrderedFloat<f32>>(),
+            Ok(OrderedFloat(-0.25))
+        );
+        assert!("NaN".parse::<OrderedFloat<f64>>().unwrap().0.is_nan());
+    }
 
-        fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
-            !bits.is_nan()
+    #[test]
+    fn test_to_canonical() {
+        let nan_payload = OrderedFloat(f64::from_bits(0x7ff8_0000_dead_beef));
+        let neg_nan = OrderedFloat(f64::from_bits(0xfff0_0000_0000_0001));
+        assert_eq!(
+            nan_payload.to_canonical().0.to_bits(),
+            neg_nan.to_canonical().0.to_bits()
+        );
+        assert_eq!(OrderedFloat(-0.0f32).to_canonical().0.to_bits(), 0);
+        assert_eq!(NotNan(-0.0f64).to_canonical().0.to_bits(), 0);
+    }
+
+    #[test]
+    fn test_hash_key_follows_cmp() {
+        use super::ordered_float_hash_bits;
+        use core::cmp::Ordering;
+
+        let values = [
+            f64::NEG_INFINITY,
+            f64::MIN,
+            -1.0,
+            -f64::MIN_POSITIVE,
+            -f64::from_bits(1),
+            -0.0,
+            0.0,
+            f64::from_bits(1),
+            f64::MIN_POSITIVE,
+            1.0,
+            f64::MAX,
+            f64::INFINITY,
+            f64::NAN,
+            -f64::NAN,
+        ];
+        for &a in &values {
+            for &b in &values {
+                let (ka, kb) = (ordered_float_hash_bits(a), ordered_float_hash_bits(b));
+                let ord = OrderedFloat(a).cmp(&OrderedFloat(b));
+                assert_eq!((ka as i64).cmp(&(kb as i64)), ord, "{:?} {:?}", a, b);
+                assert_eq!(ka == kb, ord == Ordering::Equal);
+            }
         }
     }
 
-    // OrderedFloat allows any value of the contained type, so it is a TransparentWrapper.
-    // NotNan does not, so it is not.
-    unsafe impl<T> TransparentWrapper<T> for OrderedFloat<T> {}
+    #[test]
+    fn test_powi_nostd() {
+        assert_eq!(OrderedFloat(2.0f64).powi_nostd(10), OrderedFloat(1024.0));
+        assert_eq!(OrderedFloat(-3.0f32).powi_nostd(3), OrderedFloat(-27.0));
+        assert_eq!(OrderedFloat(0.5f64).powi_nostd(2), OrderedFloat(0.25));
+        assert_eq!(OrderedFloat(7.0f64).powi_nostd(0), OrderedFloat(1.0));
+        assert_eq!(OrderedFloat(f64::NAN).powi_nostd(0), OrderedFloat(1.0));
+        assert_eq!(
+            OrderedFloat(10.0f64).powi_nostd(400),
+            OrderedFloat(f64::INFINITY)
+        );
+        assert!(OrderedFloat(f64::NAN).powi_nostd(2).0.is_nan());
+    }
 
     #[test]
-    fn test_not_nan_bit_pattern() {
-        use bytemuck::checked::{try_cast, CheckedCastError};
+    fn test_compensated_sum() {
+        use crate::OrderedFloatIterExt;
 
-        let nan = f64::NAN;
+        // 0.1 is not exactly representable, so plain summation drifts.
+        let data = [0.1f64; 10];
+        assert_ne!(data.iter().copied().fold(0.0, |a, b| a + b), 1.0);
+        assert_eq!(data.iter().copied().compensated_sum(), OrderedFloat(1.0));
+
+        let data = [1.0f32, 1e10, 1.0, -1e10];
+        assert_eq!(data.iter().copied().compensated_sum(), OrderedFloat(2.0));
+
+        let data = [1.0, f64::INFINITY, 1.0];
         assert_eq!(
-            try_cast::<f64, NotNan<f64>>(nan),
-            Err(CheckedCastError::InvalidBitPattern),
+            data.iter().copied().compensated_sum(),
+            OrderedFloat(f64::INFINITY)
+        );
+        assert!([1.0, f64::NAN].iter().copied().compensated_sum().0.is_nan());
+        assert_eq!(
+            core::iter::empty::<f64>().compensated_sum(),
+            OrderedFloat(0.0)
         );
+    }
 
-        let pi = core::f64::consts::PI;
-        assert!(try_cast::<f64, NotNan<f64>>(pi).is_ok());
+    #[test]
+    fn test_hash_bits() {
+        let a = OrderedFloat(f64::from_bits(0x7ff8_0000_dead_beef));
+        let b = OrderedFloat(f64::from_bits(0xfff0_0000_0000_0001));
+        assert_eq!(hash(&a), hash(&b));
+        assert_eq!(hash(&OrderedFloat(-0.0f64)), hash(&OrderedFloat(0.0f64)));
+        assert_eq!(hash(&NotNan(-0.0f32)), hash(&NotNan(0.0f32)));
+
+        // Longer than the stack buffer used by `hash_slice`, so several chunks are written.
+        let mut xs = [OrderedFloat(0.0f64); 40];
+        let mut ys = [OrderedFloat(0.0f64); 40];
+        for i in 0..40 {
+            xs[i] = if i % 3 == 0 {
+                a
+            } else {
+                OrderedFloat(i as f64)
+            };
+            ys[i] = if i % 3 == 0 {
+                b
+            } else {
+                OrderedFloat(i as f64)
+            };
+        }
+        ys[1] = OrderedFloat(-0.0);
+        xs[1] = OrderedFloat(0.0);
+        assert_eq!(hash(&xs[..]), hash(&ys[..]));
     }
 }
@@ -535,6 +535,62 @@ fn hash_inf_and_neg_inf_to_different_hcs() {
     assert!(h1.finish() != h2.finish());
 }
 
+#[test]
+fn hash_distinguishes_edge_values() {
+    fn hash_of<H: Hash>(x: &H) -> u64 {
+        let mut h = DefaultHasher::new();
+        x.hash(&mut h);
+        h.finish()
+    }
+
+    let f64s = [
+        0.0,
+        -0.0,
+        f64::from_bits(1),
+        f64::from_bits(2),
+        f64::from_bits(0x000f_ffff_ffff_ffff),
+        f64::MIN_POSITIVE,
+        -f64::from_bits(1),
+        1e308,
+        f64::MAX,
+        f64::INFINITY,
+        -f64::MAX,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        f64::from_bits(0x7ff8_0000_dead_beef),
+        f64::from_bits(0xfff0_0000_0000_0001),
+    ];
+    for &a in &f64s {
+        for &b in &f64s {
+            let (oa, ob) = (OrderedFloat(a), OrderedFloat(b));
+            assert_eq!(oa == ob, hash_of(&oa) == hash_of(&ob), "{:?} {:?}", a, b);
+            if let (Ok(na), Ok(nb)) = (NotNan::new(a), NotNan::new(b)) {
+                assert_eq!(hash_of(&na), hash_of(&oa));
+                assert_eq!(na == nb, hash_of(&na) == hash_of(&nb), "{:?} {:?}", a, b);
+            }
+        }
+    }
+
+    let f32s = [
+        0.0,
+        -0.0,
+        f32::from_bits(1),
+        f32::from_bits(0x007f_ffff),
+        f32::MIN_POSITIVE,
+        f32::MAX,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+        f32::from_bits(0x7fc0_beef),
+    ];
+    for &a in &f32s {
+        for &b in &f32s {
+            let (oa, ob) = (OrderedFloat(a), OrderedFloat(b));
+            assert_eq!(oa == ob, hash_of(&oa) == hash_of(&ob), "{:?} {:?}", a, b);
+        }
+    }
+}
+
 #[test]
 fn hash_is_good_for_whole_numbers() {
     let state = RandomState::new();
@@ -850,6 +906,43 @@ fn test_pow_works() {
     );
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn test_pow_wide_int() {
+    assert_eq!(OrderedFloat(2.0f64).pow(10u32), OrderedFloat(1024.0));
+    assert_eq!(OrderedFloat(2.0f32).pow(&10u32), OrderedFloat(1024.0));
+    assert_eq!(OrderedFloat(2.0f64).pow(-2i64), OrderedFloat(0.25));
+    assert_eq!(
+        OrderedFloat(2.0f64).pow(u64::MAX),
+        OrderedFloat(f64::INFINITY)
+    );
+    assert_eq!(OrderedFloat(0.5f64).pow(u32::MAX), OrderedFloat(0.0));
+    assert_eq!(not_nan(1.0f64).pow(u64::MAX), not_nan(1.0));
+    assert_eq!(
+        NotNan::new(2.0f32).unwrap().pow(10u32),
+        NotNan::new(1024.0).unwrap()
+    );
+    assert_eq!(&not_nan(-2.0f64).pow(3i64), &not_nan(-8.0));
+    assert_eq!(not_nan(3.0f64).pow(i64::MIN), not_nan(0.0));
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn test_pow_wide_int_keeps_parity() {
+    assert_eq!(OrderedFloat(-1.0f64).pow(u64::MAX), OrderedFloat(-1.0));
+    assert_eq!(OrderedFloat(-1.0f64).pow(u64::MAX - 1), OrderedFloat(1.0));
+    assert_eq!(OrderedFloat(-1.0f32).pow(u32::MAX), OrderedFloat(-1.0));
+    assert_eq!(not_nan(-1.0f64).pow(i64::MAX), not_nan(-1.0));
+    assert_eq!(not_nan(-1.0f64).pow(i64::MIN + 1), not_nan(-1.0));
+    assert_eq!(not_nan(-1.0f64).pow(i64::MIN), not_nan(1.0));
+    assert_eq!(
+        OrderedFloat(-2.0f64).pow(i32::MAX as u32 + 2),
+        OrderedFloat(f64::NEG_INFINITY)
+    );
+    let zero = OrderedFloat(-0.0f64).pow(u64::MAX);
+    assert!(zero.0 == 0.0 && zero.is_sign_negative());
+}
+
 #[cfg(any(feature = "std", feature = "libm"))]
 #[test]
 #[should_panic]
@@ -888,45 +981,2374 @@ fn test_ref_ref_binop_regression() {
     assert_eq!(&x - &y, OrderedFloat(10.0));
 }
 
-#[cfg(feature = "arbitrary")]
-mod arbitrary_test {
-    use super::{NotNan, OrderedFloat};
-    use arbitrary::{Arbitrary, Unstructured};
+#[test]
+fn to_bits_from_bits_round_trip() {
+    for &f in &[
+        0.0f64,
+        -0.0,
+        1.5,
+        -2.25,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        assert_eq!(OrderedFloat(f).to_bits(), f.to_bits());
+        assert_eq!(OrderedFloat::<f64>::from_bits(f.to_bits()), OrderedFloat(f));
+        assert_eq!(not_nan(f).to_bits(), f.to_bits());
+        assert_eq!(NotNan::<f64>::from_bits(f.to_bits()), Ok(not_nan(f)));
+    }
+    for &f in &[
+        0.0f32,
+        -0.0,
+        1.5,
+        -2.25,
+        f32::MAX,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+    ] {
+        assert_eq!(OrderedFloat(f).to_bits(), f.to_bits());
+        assert_eq!(OrderedFloat::<f32>::from_bits(f.to_bits()), OrderedFloat(f));
+        assert_eq!(not_nan(f).to_bits(), f.to_bits());
+        assert_eq!(NotNan::<f32>::from_bits(f.to_bits()), Ok(not_nan(f)));
+    }
+}
 
-    #[test]
-    fn exhaustive() {
-        // Exhaustively search all patterns of sign and exponent bits plus a few mantissa bits.
-        for high_bytes in 0..=u16::MAX {
-            let [h1, h2] = high_bytes.to_be_bytes();
+#[test]
+fn from_bits_nan() {
+    assert!(OrderedFloat::<f64>::from_bits(0x7ff8000000000001)
+        .0
+        .is_nan());
+    assert_eq!(
+        NotNan::<f64>::from_bits(0x7ff8000000000001),
+        Err(FloatIsNan)
+    );
+    assert_eq!(NotNan::<f32>::from_bits(0xffc00000), Err(FloatIsNan));
+}
 
-            // Each of these should not
-            //   * panic,
-            //   * return an error, or
-            //   * need more bytes than given.
-            let n32: NotNan<f32> = Unstructured::new(&[h1, h2, h1, h2])
-                .arbitrary()
-                .expect("NotNan<f32> failure");
-            let n64: NotNan<f64> = Unstructured::new(&[h1, h2, h1, h2, h1, h2, h1, h2])
-                .arbitrary()
-                .expect("NotNan<f64> failure");
-            let _: OrderedFloat<f32> = Unstructured::new(&[h1, h2, h1, h2])
-                .arbitrary()
-                .expect("OrderedFloat<f32> failure");
-            let _: OrderedFloat<f64> = Unstructured::new(&[h1, h2, h1, h2, h1, h2, h1, h2])
-                .arbitrary()
-                .expect("OrderedFloat<f64> failure");
+#[cfg(feature = "std")]
+#[test]
+fn dedup_canonical() {
+    let mut v = vec![
+        OrderedFloat(-0.0),
+        OrderedFloat(0.0),
+        OrderedFloat(f64::NAN),
+        OrderedFloat(-f64::NAN),
+        OrderedFloat(1.0),
+    ];
+    v.dedup_canonical();
+    assert_eq!(
+        v,
+        [OrderedFloat(0.0), OrderedFloat(f64::NAN), OrderedFloat(1.0)]
+    );
+    assert_eq!(v[0].to_bits(), 0.0f64.to_bits());
+    assert_eq!(v[1].to_bits(), f64::NAN.to_bits());
+}
 
-            // Check for violation of NotNan's property of never containing a NaN.
-            assert!(!n32.into_inner().is_nan());
-            assert!(!n64.into_inner().is_nan());
+#[test]
+fn not_nan_abs_sub_infinities() {
+    let inf = not_nan(f64::INFINITY);
+    let neg_inf = not_nan(f64::NEG_INFINITY);
+
+    assert_eq!(inf.checked_abs_sub(&inf), Ok(not_nan(0.0)));
+    assert_eq!(inf.checked_abs_sub(&neg_inf), Ok(inf));
+    assert_eq!(neg_inf.checked_abs_sub(&inf), Ok(not_nan(0.0)));
+    assert_eq!(neg_inf.checked_abs_sub(&neg_inf), Ok(not_nan(0.0)));
+    assert_eq!(
+        not_nan(5.0).checked_abs_sub(&not_nan(2.0)),
+        Ok(not_nan(3.0))
+    );
+
+    assert_eq!(Signed::abs_sub(&inf, &inf), not_nan(0.0));
+    assert_eq!(Signed::abs_sub(&inf, &neg_inf), inf);
+}
+
+#[test]
+fn hash_not_nan_matches_bits() {
+    let state = RandomState::new();
+    let hash = |x: &dyn Fn(&mut DefaultHasher)| {
+        let mut h = state.build_hasher();
+        x(&mut h);
+        h.finish()
+    };
+    for &f in &[
+        1.0f64,
+        -2.5,
+        1e-310,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        // The hashed value is the key `f64::total_cmp` orders by.
+        let bits = f.to_bits() as i64;
+        let key = (bits ^ (((bits >> 63) as u64) >> 1) as i64) as u64;
+        assert_eq!(hash(&|h| not_nan(f).hash(h)), hash(&|h| key.hash(h)));
+        assert_eq!(
+            hash(&|h| not_nan(f).hash(h)),
+            hash(&|h| OrderedFloat(f).hash(h))
+        );
+    }
+    assert_eq!(
+        hash(&|h| not_nan(0.0f64).hash(h)),
+        hash(&|h| not_nan(-0.0f64).hash(h))
+    );
+    assert_eq!(
+        hash(&|h| not_nan(-0.0f64).hash(h)),
+        hash(&|h| OrderedFloat(0.0f64).hash(h))
+    );
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn test_checked_pow() {
+    assert_eq!(not_nan(-2.0).checked_powf(not_nan(0.5)), Err(FloatIsNan));
+    assert_eq!(not_nan(2.0).checked_powf(not_nan(3.0)), Ok(not_nan(8.0)));
+    assert_eq!(not_nan(-2.0f32).checked_powi(3), Ok(not_nan(-8.0)));
+    assert_eq!(not_nan(0.0).checked_powi(-1), Ok(not_nan(f64::INFINITY)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn collect_sorted_unique() {
+    let keys: Vec<OrderedFloat<f64>> = vec![1.0, -0.0, 0.0, 1.0, f64::NAN]
+        .into_iter()
+        .collect_sorted_unique();
+    assert_eq!(
+        keys,
+        [OrderedFloat(0.0), OrderedFloat(1.0), OrderedFloat(f64::NAN)]
+    );
+    assert!(keys[0].0.is_sign_positive());
+
+    let keys: Vec<OrderedFloat<f32>> = vec![OrderedFloat(3.0), OrderedFloat(-f32::NAN)]
+        .into_iter()
+        .chain(vec![OrderedFloat(f32::NAN), OrderedFloat(-1.0)])
+        .collect_sorted_unique();
+    assert_eq!(
+        keys,
+        [
+            OrderedFloat(-1.0),
+            OrderedFloat(3.0),
+            OrderedFloat(f32::NAN)
+        ]
+    );
+}
+
+#[test]
+fn not_nan_guard_mut_commits() {
+    let mut x = not_nan(1.0);
+    *x.guard_mut() += 1.0;
+    assert_eq!(x, not_nan(2.0));
+
+    {
+        let mut guard = x.guard_mut();
+        *guard *= 3.0;
+        assert_eq!(*guard, 6.0);
+    }
+    assert_eq!(x, not_nan(6.0));
+}
+
+#[test]
+#[should_panic]
+fn not_nan_guard_mut_panics_on_nan() {
+    let mut x = not_nan(f64::INFINITY);
+    *x.guard_mut() -= f64::INFINITY;
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn not_nan_guard_mut_nan_during_unwind() {
+    let mut x = not_nan(1.0);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let mut guard = x.guard_mut();
+        *guard = f64::NAN;
+        panic!("original panic");
+    }));
+    // The guard didn't panic again while unwinding, so the process wasn't aborted and the
+    // original payload is intact.
+    let payload = result.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"original panic"));
+    assert_eq!(x, not_nan(1.0));
+}
+
+#[test]
+fn not_nan_try_guard_mut() {
+    let mut x = not_nan(1.0);
+    let mut res = Ok(());
+    *x.try_guard_mut(&mut res) += 1.0;
+    assert_eq!(res, Ok(()));
+    assert_eq!(x, not_nan(2.0));
+
+    *x.try_guard_mut(&mut res) = f64::NAN;
+    assert_eq!(res, Err(FloatIsNan));
+    assert_eq!(x, not_nan(2.0));
+}
+
+#[cfg(feature = "std")]
+fn group_thousands(x: f64) -> String {
+    let digits = format!("{}", x.trunc().abs());
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push(',');
         }
+        out.push(c);
     }
+    if x < 0.0 {
+        out.insert(0, '-');
+    }
+    out
+}
 
-    #[test]
-    fn size_hints() {
-        assert_eq!(NotNan::<f32>::size_hint(0), (4, Some(4)));
-        assert_eq!(NotNan::<f64>::size_hint(0), (8, Some(8)));
-        assert_eq!(OrderedFloat::<f32>::size_hint(0), (4, Some(4)));
-        assert_eq!(OrderedFloat::<f64>::size_hint(0), (8, Some(8)));
+#[cfg(feature = "std")]
+#[test]
+fn format_with_grouping() {
+    assert_eq!(
+        OrderedFloat(1234567.0).format_with(group_thousands),
+        "1,234,567"
+    );
+    assert_eq!(not_nan(-1000.0).format_with(group_thousands), "-1,000");
+    assert_eq!(OrderedFloat(12.0).format_with(group_thousands), "12");
+}
+
+#[test]
+fn not_nan_ordered_float_cmp() {
+    assert_eq!(not_nan(1.0), OrderedFloat(1.0));
+    assert_eq!(OrderedFloat(1.0), not_nan(1.0));
+    assert_eq!(not_nan(0.0), OrderedFloat(-0.0));
+    assert!(not_nan(1.0) < OrderedFloat(2.0));
+    assert!(OrderedFloat(-1.0) < not_nan(1.0));
+
+    assert_ne!(not_nan(1.0), OrderedFloat(f64::NAN));
+    assert!(not_nan(1.0) < OrderedFloat(f64::NAN));
+    assert!(not_nan(f64::INFINITY) < OrderedFloat(f64::NAN));
+    assert!(OrderedFloat(f64::NAN) > not_nan(f64::INFINITY));
+    assert_eq!(
+        OrderedFloat(f64::NAN).partial_cmp(&not_nan(1.0)),
+        Some(Greater)
+    );
+}
+
+#[test]
+fn wrap_floats_ext() {
+    let raw = [3.0, f64::NAN, 1.0];
+
+    let mut v: Vec<OrderedFloat<f64>> = raw.iter().copied().wrap_ordered().collect();
+    v.sort();
+    assert_eq!(
+        v,
+        [OrderedFloat(1.0), OrderedFloat(3.0), OrderedFloat(f64::NAN)]
+    );
+
+    let v: Vec<Result<NotNan<f64>, FloatIsNan>> = raw.iter().copied().try_wrap_notnan().collect();
+    assert_eq!(v, [Ok(not_nan(3.0)), Err(FloatIsNan), Ok(not_nan(1.0))]);
+
+    let v: Result<Vec<NotNan<f32>>, FloatIsNan> =
+        vec![2.0f32, 1.0].into_iter().try_wrap_notnan().collect();
+    assert_eq!(
+        v,
+        Ok(vec![NotNan::new(2.0).unwrap(), NotNan::new(1.0).unwrap()])
+    );
+}
+
+#[test]
+fn binary_heap_max_order_with_nan() {
+    use std::collections::BinaryHeap;
+
+    let mut heap: BinaryHeap<OrderedFloat<f64>> = [1.0, f64::NAN, -3.0, 2.0]
+        .iter()
+        .map(|&x| OrderedFloat(x))
+        .collect();
+    assert!(heap.pop().unwrap().0.is_nan());
+    assert_eq!(heap.pop(), Some(OrderedFloat(2.0)));
+    assert_eq!(heap.pop(), Some(OrderedFloat(1.0)));
+    assert_eq!(heap.pop(), Some(OrderedFloat(-3.0)));
+    assert_eq!(heap.pop(), None);
+}
+
+#[test]
+fn binary_heap_min_order_with_nan() {
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    let mut heap: BinaryHeap<Reverse<OrderedFloat<f64>>> = [1.0, f64::NAN, -3.0, 2.0]
+        .iter()
+        .map(|&x| Reverse(OrderedFloat(x)))
+        .collect();
+    assert_eq!(heap.pop(), Some(Reverse(OrderedFloat(-3.0))));
+    assert_eq!(heap.pop(), Some(Reverse(OrderedFloat(1.0))));
+    assert_eq!(heap.pop(), Some(Reverse(OrderedFloat(2.0))));
+    assert!(heap.pop().unwrap().0 .0.is_nan());
+    assert_eq!(heap.pop(), None);
+
+    let mut heap: BinaryHeap<Reverse<NotNan<f64>>> = [1.0, -3.0, 2.0]
+        .iter()
+        .map(|&x| Reverse(not_nan(x)))
+        .collect();
+    assert_eq!(heap.pop(), Some(Reverse(not_nan(-3.0))));
+    assert_eq!(heap.peek(), Some(&Reverse(not_nan(1.0))));
+}
+
+#[test]
+fn not_nan_rem_permutations() {
+    let a = not_nan(7.5);
+    let b = not_nan(2.0);
+    let expected = not_nan(1.5);
+    assert_eq!(a % b, expected);
+    assert_eq!(a % &b, expected);
+    assert_eq!(&a % b, expected);
+    assert_eq!(&a % &b, expected);
+    assert_eq!(a % 2.0, expected);
+    assert_eq!(a % &2.0, expected);
+    assert_eq!(&a % 2.0, expected);
+    assert_eq!(&a % &2.0, expected);
+
+    let mut c = a;
+    c %= &b;
+    assert_eq!(c, expected);
+    let mut c = a;
+    c %= b;
+    assert_eq!(c, expected);
+    let mut c = a;
+    c %= &2.0;
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn not_nan_checked_rem_euclid() {
+    assert_eq!(
+        not_nan(-7.0).checked_rem_euclid(not_nan(4.0)),
+        Ok(not_nan(1.0))
+    );
+    assert_eq!(
+        not_nan(7.0).checked_rem_euclid(not_nan(-4.0)),
+        Ok(not_nan(3.0))
+    );
+    assert_eq!(
+        not_nan(-7.0).checked_rem_euclid(not_nan(-4.0)),
+        Ok(not_nan(1.0))
+    );
+    assert_eq!(
+        not_nan(-1.0).checked_rem_euclid(not_nan(f64::INFINITY)),
+        Ok(not_nan(f64::INFINITY))
+    );
+    assert_eq!(
+        not_nan(1.0).checked_rem_euclid(not_nan(0.0)),
+        Err(FloatIsNan)
+    );
+    assert_eq!(
+        not_nan(f64::INFINITY).checked_rem_euclid(not_nan(1.0)),
+        Err(FloatIsNan)
+    );
+}
+
+#[test]
+fn not_nan_rem_nan_panics_consistently() {
+    let a = not_nan(1.0);
+    let zero = not_nan(0.0);
+    let results = [
+        panic::catch_unwind(|| a % zero),
+        panic::catch_unwind(|| a % &zero),
+        panic::catch_unwind(|| &a % zero),
+        panic::catch_unwind(|| &a % &zero),
+        panic::catch_unwind(|| a % 0.0),
+        panic::catch_unwind(|| &a % &0.0),
+        panic::catch_unwind(|| {
+            let mut c = a;
+            c %= &zero;
+            c
+        }),
+        panic::catch_unwind(|| {
+            let mut c = a;
+            c %= 0.0;
+            c
+        }),
+    ];
+    assert!(results.iter().all(|r| r.is_err()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn float_consistency() {
+    verify_float_consistency::<f32>();
+    verify_float_consistency::<f64>();
+}
+
+#[test]
+fn to_canonical() {
+    let pos = OrderedFloat(0.0f64).to_canonical();
+    let neg = OrderedFloat(-0.0f64).to_canonical();
+    assert_eq!(pos.to_bits(), neg.to_bits());
+    assert_eq!(neg.to_bits(), 0);
+
+    let pos = not_nan(0.0f64).to_canonical();
+    let neg = not_nan(-0.0f64).to_canonical();
+    assert_eq!(pos.to_bits(), neg.to_bits());
+    assert_eq!(
+        not_nan(-1.5f64).to_canonical().to_bits(),
+        (-1.5f64).to_bits()
+    );
+
+    let nan1 = OrderedFloat::<f64>::from_bits(0x7ff8_0000_0000_0001);
+    let nan2 = OrderedFloat::<f64>::from_bits(0xfff0_0000_dead_beef);
+    assert_ne!(nan1.to_bits(), nan2.to_bits());
+    assert_eq!(nan1.to_canonical().to_bits(), nan2.to_canonical().to_bits());
+
+    let nan1 = OrderedFloat::<f32>::from_bits(0x7fc0_0001);
+    let nan2 = OrderedFloat::<f32>::from_bits(0xffc0_1234);
+    assert_eq!(nan1.to_canonical().to_bits(), nan2.to_canonical().to_bits());
+}
+
+#[test]
+fn ord_matches_canonical_total_cmp() {
+    fn key(x: f64) -> f64 {
+        if x.is_nan() {
+            f64::NAN
+        } else {
+            x + 0.0
+        }
+    }
+    let samples = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f64::MIN_POSITIVE,
+        -f64::MIN_POSITIVE,
+        5e-324,
+        -5e-324,
+        f64::MAX,
+        f64::MIN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        -f64::NAN,
+        f64::from_bits(0x7ff0_0000_0000_0001),
+    ];
+    for &a in &samples {
+        for &b in &samples {
+            let expected = key(a).total_cmp(&key(b));
+            assert_eq!(
+                OrderedFloat(a).cmp(&OrderedFloat(b)),
+                expected,
+                "{} {}",
+                a,
+                b
+            );
+            assert_eq!(
+                OrderedFloat(a as f32).cmp(&OrderedFloat(b as f32)),
+                key(a as f32 as f64).total_cmp(&key(b as f32 as f64)),
+                "{} {}",
+                a,
+                b
+            );
+        }
+    }
+}
+
+#[test]
+fn checked_sum_and_product() {
+    let big = vec![1e307; 100];
+    assert_eq!(big.iter().copied().checked_sum::<f64>(), Err(FloatOverflow));
+    assert_eq!(
+        big.iter().map(|&x| OrderedFloat(x)).checked_sum::<f64>(),
+        Err(FloatOverflow)
+    );
+    assert!(big[..10]
+        .iter()
+        .copied()
+        .checked_sum::<f64>()
+        .unwrap()
+        .0
+        .is_finite());
+    assert_eq!(
+        [f32::MAX, f32::MAX].iter().copied().checked_sum::<f32>(),
+        Err(FloatOverflow)
+    );
+
+    // Infinite inputs pass through.
+    assert_eq!(
+        [1.0, f64::NEG_INFINITY, 2.0].iter().copied().checked_sum(),
+        Ok(OrderedFloat(f64::NEG_INFINITY))
+    );
+    assert!([f64::INFINITY, f64::NEG_INFINITY]
+        .iter()
+        .copied()
+        .checked_sum::<f64>()
+        .unwrap()
+        .0
+        .is_nan());
+    assert_eq!(
+        std::iter::empty::<f64>().checked_sum(),
+        Ok(OrderedFloat(0.0))
+    );
+
+    assert_eq!(
+        [1e300, 1e10].iter().copied().checked_product::<f64>(),
+        Err(FloatOverflow)
+    );
+    assert_eq!(
+        [1e300, -1e10].iter().copied().checked_product::<f64>(),
+        Err(FloatOverflow)
+    );
+    assert_eq!(
+        [f64::INFINITY, 2.0].iter().copied().checked_product(),
+        Ok(OrderedFloat(f64::INFINITY))
+    );
+    assert_eq!(
+        std::iter::empty::<f64>().checked_product(),
+        Ok(OrderedFloat(1.0))
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn boxed_reinterpretation() {
+    let b = Box::new(2.5f64);
+    let ptr = &*b as *const f64 as usize;
+    let ordered = OrderedFloat::from_boxed(b);
+    assert_eq!(&*ordered as *const OrderedFloat<f64> as usize, ptr);
+    assert_eq!(*ordered, OrderedFloat(2.5));
+    let back = OrderedFloat::into_boxed_inner(ordered);
+    assert_eq!(&*back as *const f64 as usize, ptr);
+    assert_eq!(*back, 2.5);
+
+    let not_nan = NotNan::try_from_boxed(back).unwrap();
+    assert_eq!(&*not_nan as *const NotNan<f64> as usize, ptr);
+    assert_eq!(*not_nan, 2.5);
+    let back = NotNan::into_boxed_inner(not_nan);
+    assert_eq!(&*back as *const f64 as usize, ptr);
+
+    assert_eq!(
+        NotNan::try_from_boxed(Box::new(f32::NAN)).map(|_| ()),
+        Err(FloatIsNan)
+    );
+}
+
+#[test]
+fn sort_descending() {
+    let mut v = [
+        OrderedFloat(1.0),
+        OrderedFloat(f64::NAN),
+        OrderedFloat(-2.0),
+        OrderedFloat(f64::INFINITY),
+        OrderedFloat(0.5),
+    ];
+    v.sort_descending();
+    assert!(v[0].0.is_nan());
+    assert_eq!(
+        v[1..],
+        [
+            OrderedFloat(f64::INFINITY),
+            OrderedFloat(1.0),
+            OrderedFloat(0.5),
+            OrderedFloat(-2.0)
+        ]
+    );
+
+    let mut v = vec![not_nan(1.0), not_nan(-2.0), not_nan(3.0)];
+    v.sort_descending();
+    assert_eq!(v, [not_nan(3.0), not_nan(1.0), not_nan(-2.0)]);
+}
+
+#[test]
+fn wrap_angles() {
+    use std::f64::consts::PI;
+
+    fn same_angle(a: f64, b: f64) -> bool {
+        let d = (a - b).abs() % (2.0 * PI);
+        d < 1e-12 || (2.0 * PI - d) < 1e-12
+    }
+
+    for &x in &[
+        3.0 * PI,
+        -3.0 * PI,
+        0.0,
+        1.0,
+        -1.0,
+        PI - 1e-9,
+        -PI,
+        100.0,
+        -100.0,
+    ] {
+        let w = OrderedFloat(x).wrap_to_pi().0;
+        assert!((-PI..PI).contains(&w), "{} -> {}", x, w);
+        assert!(same_angle(w, x), "{} -> {}", x, w);
+        assert_eq!(not_nan(x).wrap_to_pi(), w);
+
+        let w = OrderedFloat(x).wrap_to_2pi().0;
+        assert!((0.0..2.0 * PI).contains(&w), "{} -> {}", x, w);
+        assert!(same_angle(w, x), "{} -> {}", x, w);
+        assert_eq!(not_nan(x).wrap_to_2pi(), w);
+    }
+
+    // Values already in range are unchanged.
+    assert_eq!(OrderedFloat(1e-20).wrap_to_pi(), OrderedFloat(1e-20));
+    assert_eq!(OrderedFloat(-PI).wrap_to_pi(), OrderedFloat(-PI));
+    assert_eq!(not_nan(1.5).wrap_to_2pi(), not_nan(1.5));
+    assert_eq!(OrderedFloat(PI).wrap_to_pi(), OrderedFloat(-PI));
+
+    let w = OrderedFloat(3.0f32 * std::f32::consts::PI).wrap_to_pi().0;
+    assert!((-std::f32::consts::PI..std::f32::consts::PI).contains(&w));
+
+    assert!(OrderedFloat(f64::INFINITY).wrap_to_pi().0.is_nan());
+    assert!(panic::catch_unwind(|| not_nan(f64::INFINITY).wrap_to_2pi()).is_err());
+}
+
+#[test]
+fn ordered_float_eq_raw_nan() {
+    // Mixed comparison with a raw float uses IEEE equality.
+    assert!(OrderedFloat(f64::NAN) != f64::NAN);
+    assert!(OrderedFloat(1.0) != f64::NAN);
+    assert!(OrderedFloat(-0.0) == 0.0);
+    // Wrapper equality treats all NaNs as equal.
+    assert!(OrderedFloat(f64::NAN) == OrderedFloat(-f64::NAN));
+
+    assert!(OrderedFloat(f64::NAN).eq_total(&f64::NAN));
+    assert!(OrderedFloat(f32::NAN).eq_total(&-f32::NAN));
+    assert!(OrderedFloat(-0.0).eq_total(&0.0));
+    assert!(OrderedFloat(2.0).eq_total(&2.0));
+    assert!(!OrderedFloat(2.0).eq_total(&f64::NAN));
+    assert!(!OrderedFloat(f64::NAN).eq_total(&2.0));
+}
+
+#[test]
+fn ordered_float_widen_and_narrow() {
+    let x = OrderedFloat(1.1f32);
+    let wide: OrderedFloat<f64> = x.into();
+    assert_eq!(wide.0, 1.1f32 as f64);
+    assert_eq!(wide.as_f32(), x);
+    assert_eq!(
+        OrderedFloat::<f64>::from(OrderedFloat(f32::MAX)).as_f32(),
+        OrderedFloat(f32::MAX)
+    );
+
+    let nan: OrderedFloat<f64> = OrderedFloat(f32::NAN).into();
+    assert!(nan.0.is_nan());
+    assert!(nan.as_f32().0.is_nan());
+
+    assert_eq!(OrderedFloat(1e300f64).as_f32(), OrderedFloat(f32::INFINITY));
+    assert_eq!(OrderedFloat(1.1f64).as_f32(), OrderedFloat(1.1f32));
+}
+
+#[test]
+fn not_nan_sum_product_into_result() {
+    let clean = [not_nan(1.0), not_nan(2.5), not_nan(-0.5)];
+    assert_eq!(
+        clean
+            .iter()
+            .copied()
+            .sum::<Result<NotNan<f64>, FloatIsNan>>(),
+        Ok(not_nan(3.0))
+    );
+    assert_eq!(
+        clean.iter().sum::<Result<NotNan<f64>, FloatIsNan>>(),
+        Ok(not_nan(3.0))
+    );
+    assert_eq!(
+        clean.iter().product::<Result<NotNan<f64>, FloatIsNan>>(),
+        Ok(not_nan(-1.25))
+    );
+
+    let canceling = [
+        not_nan(f64::INFINITY),
+        not_nan(1.0),
+        not_nan(f64::NEG_INFINITY),
+    ];
+    assert_eq!(
+        canceling
+            .iter()
+            .copied()
+            .sum::<Result<NotNan<f64>, FloatIsNan>>(),
+        Err(FloatIsNan)
+    );
+    assert_eq!(
+        canceling.iter().sum::<Result<NotNan<f64>, FloatIsNan>>(),
+        Err(FloatIsNan)
+    );
+
+    let zero_times_inf = [not_nan(0.0), not_nan(f64::INFINITY)];
+    assert_eq!(
+        zero_times_inf
+            .iter()
+            .copied()
+            .product::<Result<NotNan<f64>, FloatIsNan>>(),
+        Err(FloatIsNan)
+    );
+
+    // Short-circuits on the first NaN.
+    let mut seen = 0;
+    let result: Result<NotNan<f64>, FloatIsNan> = [f64::INFINITY, f64::NEG_INFINITY, 1.0, 2.0]
+        .iter()
+        .map(|&x| {
+            seen += 1;
+            not_nan(x)
+        })
+        .sum();
+    assert_eq!(result, Err(FloatIsNan));
+    assert_eq!(seen, 2);
+
+    assert_eq!(
+        std::iter::empty::<NotNan<f32>>().sum::<Result<NotNan<f32>, FloatIsNan>>(),
+        Ok(NotNan::new(0.0).unwrap())
+    );
+}
+
+#[test]
+fn write_shortest_round_trips() {
+    let values = [
+        0.1,
+        -0.0,
+        1.0 / 3.0,
+        1e300,
+        5e-324,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ];
+    let mut s = String::new();
+    for &x in &values {
+        s.clear();
+        OrderedFloat(x).write_shortest(&mut s).unwrap();
+        let back: f64 = s.parse().unwrap();
+        assert_eq!(back.to_bits(), x.to_bits(), "{}", s);
+
+        s.clear();
+        not_nan(x).write_shortest(&mut s).unwrap();
+        assert_eq!(s.parse::<f64>().unwrap().to_bits(), x.to_bits(), "{}", s);
+    }
+
+    s.clear();
+    OrderedFloat(f64::NAN).write_shortest(&mut s).unwrap();
+    assert!(s.parse::<f64>().unwrap().is_nan());
+
+    for &x in &[0.1f32, 1.0 / 3.0, f32::MAX, f32::MIN_POSITIVE] {
+        s.clear();
+        OrderedFloat(x).write_shortest(&mut s).unwrap();
+        assert_eq!(s.parse::<f32>().unwrap(), x, "{}", s);
+    }
+}
+
+#[test]
+fn not_nan_saturating() {
+    use num_traits::Saturating;
+
+    let max = not_nan(f64::MAX);
+    let min = not_nan(f64::MIN);
+    let inf = not_nan(f64::INFINITY);
+    assert_eq!(max.saturating_add(not_nan(f64::MAX / 2.0)), max);
+    assert_eq!(max.saturating_add(not_nan(1.0)), max);
+    assert_eq!(min.saturating_sub(max), min);
+    assert_eq!(min.saturating_add(min), min);
+    assert_eq!(not_nan(1.0).saturating_add(not_nan(2.0)), not_nan(3.0));
+    assert_eq!(not_nan(1.0).saturating_sub(not_nan(2.0)), not_nan(-1.0));
+
+    // Infinities clamp to the finite range.
+    assert_eq!(inf.saturating_add(not_nan(1.0)), max);
+    assert_eq!((-inf).saturating_sub(not_nan(1.0)), min);
+    assert_eq!(inf.saturating_add(-inf), not_nan(0.0));
+    assert_eq!(inf.saturating_sub(inf), not_nan(0.0));
+
+    let max32 = NotNan::new(f32::MAX).unwrap();
+    assert_eq!(max32.saturating_add(max32), max32);
+}
+
+#[test]
+fn is_close() {
+    let a = OrderedFloat(100.0);
+    assert!(a.is_close(a, 0.0, 0.0));
+    assert!(a.is_close(OrderedFloat(100.0 + 1e-7), 1e-9, 0.0));
+    assert!(!a.is_close(OrderedFloat(100.0 + 1e-6), 1e-9, 0.0));
+    assert!(a.is_close(OrderedFloat(100.5), 0.01, 0.0));
+    assert!(!a.is_close(OrderedFloat(102.0), 0.01, 0.0));
+    assert!(a.is_close(OrderedFloat(102.0), 0.01, 2.0));
+
+    // Near zero only the absolute tolerance helps.
+    assert!(!OrderedFloat(0.0).is_close(OrderedFloat(1e-12), 1e-9, 0.0));
+    assert!(OrderedFloat(0.0).is_close(OrderedFloat(1e-12), 1e-9, 1e-10));
+    assert!(OrderedFloat(-0.0).is_close(OrderedFloat(0.0), 0.0, 0.0));
+
+    let inf = OrderedFloat(f64::INFINITY);
+    assert!(inf.is_close(inf, 1e-9, 0.0));
+    assert!(!inf.is_close(-inf, 1e-9, 0.0));
+    assert!(!inf.is_close(OrderedFloat(f64::MAX), 1.0, f64::INFINITY));
+    assert!(!OrderedFloat(f64::NAN).is_close(OrderedFloat(f64::NAN), 1.0, 1.0));
+
+    assert!(not_nan(1.0).is_close(not_nan(1.0 + 1e-12), 1e-9, 0.0));
+    assert!(!not_nan(1.0).is_close(not_nan(-1.0), 1e-9, 1e-9));
+    assert!(not_nan(f64::NEG_INFINITY).is_close(not_nan(f64::NEG_INFINITY), 0.0, 0.0));
+}
+
+#[test]
+fn mixed_not_nan_ordered_float_arithmetic() {
+    let n = not_nan(6.0);
+    let o = OrderedFloat(4.0);
+    assert_eq!(n + o, OrderedFloat(10.0));
+    assert_eq!(n - o, OrderedFloat(2.0));
+    assert_eq!(n * o, OrderedFloat(24.0));
+    assert_eq!(n / o, OrderedFloat(1.5));
+    assert_eq!(n % o, OrderedFloat(2.0));
+    assert_eq!(o - n, OrderedFloat(-2.0));
+    assert_eq!(o / n, OrderedFloat(4.0 / 6.0));
+    assert_eq!(&n + o, OrderedFloat(10.0));
+    assert_eq!(n + &o, OrderedFloat(10.0));
+    assert_eq!(&n + &o, OrderedFloat(10.0));
+    assert_eq!(&o * n, OrderedFloat(24.0));
+    assert_eq!(o * &n, OrderedFloat(24.0));
+    assert_eq!(&o * &n, OrderedFloat(24.0));
+
+    // NaN results land in OrderedFloat instead of panicking.
+    let inf = not_nan(f64::INFINITY);
+    assert!((inf - OrderedFloat(f64::INFINITY)).0.is_nan());
+    assert!((OrderedFloat(0.0) * inf).0.is_nan());
+    assert!((not_nan(1.0f64) % OrderedFloat(0.0)).0.is_nan());
+    assert!((not_nan(1.0f64) + OrderedFloat(f64::NAN)).0.is_nan());
+}
+
+#[test]
+fn hash_slice_matches_eq() {
+    let state = RandomState::new();
+    let hash = |x: &dyn Fn(&mut DefaultHasher)| {
+        let mut h = state.build_hasher();
+        x(&mut h);
+        h.finish()
+    };
+    let a: Vec<OrderedFloat<f64>> = (0..100)
+        .map(|i| OrderedFloat(i as f64))
+        .chain([OrderedFloat(-0.0), OrderedFloat(f64::NAN)])
+        .collect();
+    let mut b = a.clone();
+    b[0] = OrderedFloat(-0.0);
+    b[100] = OrderedFloat(0.0);
+    b[101] = OrderedFloat(-f64::NAN);
+    assert_eq!(a, b);
+    assert_eq!(hash(&|h| a.hash(h)), hash(&|h| b.hash(h)));
+    assert_eq!(hash(&|h| a[..33].hash(h)), hash(&|h| b[..33].hash(h)));
+
+    b[50] = OrderedFloat(50.5);
+    assert_ne!(hash(&|h| a.hash(h)), hash(&|h| b.hash(h)));
+
+    let c: Vec<NotNan<f32>> = vec![NotNan::new(-0.0).unwrap(), NotNan::new(1.0).unwrap()];
+    let d: Vec<NotNan<f32>> = vec![NotNan::new(0.0).unwrap(), NotNan::new(1.0).unwrap()];
+    assert_eq!(hash(&|h| c.hash(h)), hash(&|h| d.hash(h)));
+
+    let mut set = HashSet::new();
+    set.insert(a.clone());
+    assert!(set.contains(&a));
+}
+
+#[test]
+fn test_into_inner_via_from() {
+    assert_eq!(f32::from(OrderedFloat(1.5f32)), 1.5);
+    assert_eq!(f64::from(OrderedFloat(-2.5f64)), -2.5);
+    assert_eq!(f32::from(NotNan::new(1.5f32).unwrap()), 1.5);
+    assert_eq!(f64::from(NotNan::new(-2.5f64).unwrap()), -2.5);
+
+    let x: f64 = OrderedFloat(f64::NAN).into();
+    assert!(x.is_nan());
+}
+
+#[test]
+fn test_nan_to_zero() {
+    assert_eq!(OrderedFloat(f64::NAN).nan_to_zero(), not_nan(0.0f64));
+    assert_eq!(OrderedFloat(-3.0f64).nan_to_zero(), not_nan(-3.0f64));
+    assert_eq!(
+        OrderedFloat(f32::INFINITY).nan_to_zero(),
+        not_nan(f32::INFINITY)
+    );
+    assert_eq!(
+        OrderedFloat(f32::NEG_INFINITY).nan_to_zero(),
+        not_nan(f32::NEG_INFINITY)
+    );
+}
+
+#[test]
+fn test_nan_to() {
+    assert_eq!(OrderedFloat(f64::NAN).nan_to(7.0), Ok(not_nan(7.0f64)));
+    assert_eq!(OrderedFloat(1.0f64).nan_to(7.0), Ok(not_nan(1.0f64)));
+    assert_eq!(
+        OrderedFloat(f64::INFINITY).nan_to(7.0),
+        Ok(not_nan(f64::INFINITY))
+    );
+    assert_eq!(OrderedFloat(f64::NAN).nan_to(f64::NAN), Err(FloatIsNan));
+    assert_eq!(OrderedFloat(1.0f64).nan_to(f64::NAN), Err(FloatIsNan));
+}
+
+#[test]
+fn test_try_from_f64_for_not_nan_f32() {
+    assert_eq!(NotNan::<f32>::try_from(f64::NAN), Err(FloatIsNan));
+    assert_eq!(NotNan::<f32>::try_from(1.5f64), Ok(not_nan(1.5f32)));
+    assert_eq!(
+        NotNan::<f32>::try_from(1e300f64),
+        Ok(not_nan(f32::INFINITY))
+    );
+    assert_eq!(
+        NotNan::<f32>::try_from(-1e300f64),
+        Ok(not_nan(f32::NEG_INFINITY))
+    );
+    assert_eq!(
+        NotNan::<f32>::try_from(f64::INFINITY),
+        Ok(not_nan(f32::INFINITY))
+    );
+}
+
+#[test]
+fn test_next_up_down() {
+    let one = OrderedFloat(1.0f64);
+    assert_eq!(one.next_up(), OrderedFloat(1.0 + f64::EPSILON));
+    assert_eq!(one.next_down(), OrderedFloat(1.0 - f64::EPSILON / 2.0));
+    assert_eq!(one.next_up().next_down(), one);
+    assert_eq!(
+        OrderedFloat(1.0f32).next_up(),
+        OrderedFloat(1.0 + f32::EPSILON)
+    );
+
+    assert_eq!(
+        OrderedFloat(f64::MAX).next_up(),
+        OrderedFloat(f64::INFINITY)
+    );
+    assert_eq!(not_nan(f32::MAX).next_up(), not_nan(f32::INFINITY));
+    assert_eq!(not_nan(f32::INFINITY).next_up(), not_nan(f32::INFINITY));
+    assert_eq!(not_nan(f64::INFINITY).next_down(), not_nan(f64::MAX));
+    assert_eq!(
+        not_nan(f64::NEG_INFINITY).next_down(),
+        not_nan(f64::NEG_INFINITY)
+    );
+
+    let tiny = f64::from_bits(1);
+    assert_eq!(not_nan(0.0f64).next_up(), not_nan(tiny));
+    assert_eq!(not_nan(-0.0f64).next_up(), not_nan(tiny));
+    assert_eq!(not_nan(0.0f64).next_down(), not_nan(-tiny));
+    assert_eq!(not_nan(tiny).next_down().to_bits(), 0);
+    assert_eq!(
+        not_nan(-1.0f64).next_up(),
+        not_nan(-1.0 + f64::EPSILON / 2.0)
+    );
+
+    assert!(OrderedFloat(f64::NAN).next_up().0.is_nan());
+    assert!(OrderedFloat(f64::NAN).next_down().0.is_nan());
+
+    let steps: Vec<_> = std::iter::successors(Some(not_nan(1.0f64)), |x| Some(x.next_up()))
+        .take(3)
+        .collect();
+    assert!(steps.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_partial_cmp_ieee() {
+    let nan = OrderedFloat(f64::NAN);
+    let one = OrderedFloat(1.0f64);
+    assert_eq!(nan.partial_cmp_ieee(&one), None);
+    assert_eq!(one.partial_cmp_ieee(&nan), None);
+    assert_eq!(nan.partial_cmp_ieee(&nan), None);
+    assert_eq!(nan.partial_cmp(&nan), Some(Equal));
+
+    assert_eq!(one.partial_cmp_ieee(&OrderedFloat(2.0)), Some(Less));
+    assert_eq!(
+        OrderedFloat(-0.0f64).partial_cmp_ieee(&OrderedFloat(0.0)),
+        Some(Equal)
+    );
+}
+
+#[test]
+fn test_canonical_key() {
+    assert_eq!(canonical_key(-0.0f64).0.to_bits(), 0.0f64.to_bits());
+    assert_eq!(canonical_key(1.5f32), OrderedFloat(1.5));
+    assert_eq!(
+        canonical_key(f64::from_bits(0xfff8_0000_dead_beef))
+            .0
+            .to_bits(),
+        f64::NAN.to_bits()
+    );
+
+    let mut map = std::collections::BTreeMap::new();
+    map.insert(canonical_key(-0.0f32), 1);
+    map.insert(canonical_key(0.0f32), 2);
+    assert_eq!(map.len(), 1);
+    assert!(map.keys().next().unwrap().0.is_sign_positive());
+}
+
+#[test]
+fn test_neg_canonical() -> Result<(), FloatIsNan> {
+    let zero = NotNan::new(0.0f64)?;
+    let neg_zero = NotNan::new(-0.0f64)?;
+
+    assert!((-zero).is_sign_negative());
+    assert!((-&zero).is_sign_negative());
+    assert!((-neg_zero).is_sign_positive());
+    assert!((-&neg_zero).is_sign_positive());
+
+    assert!(zero.neg_canonical().is_sign_positive());
+    let zero_ref = &zero;
+    assert!(zero_ref.neg_canonical().is_sign_positive());
+    assert!(neg_zero.neg_canonical().is_sign_positive());
+
+    let x = NotNan::new(2.5f32)?;
+    assert_eq!(x.neg_canonical(), -x);
+    assert_eq!(
+        NotNan::new(f64::INFINITY)?.neg_canonical(),
+        NotNan::new(f64::NEG_INFINITY)?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_saturate() {
+    assert_eq!(OrderedFloat(-3.0f64).saturate(), OrderedFloat(0.0));
+    assert_eq!(OrderedFloat(3.0f64).saturate(), OrderedFloat(1.0));
+    assert_eq!(OrderedFloat(0.5f32).saturate(), OrderedFloat(0.5));
+    assert_eq!(OrderedFloat(1.0f32).saturate(), OrderedFloat(1.0));
+    assert_eq!(
+        OrderedFloat(f64::NEG_INFINITY).saturate(),
+        OrderedFloat(0.0)
+    );
+    assert_eq!(OrderedFloat(f64::INFINITY).saturate(), OrderedFloat(1.0));
+    assert_eq!(OrderedFloat(f64::NAN).saturate().0.to_bits(), 0);
+    assert_eq!(OrderedFloat(-0.0f64).saturate().0.to_bits(), 0);
+
+    assert_eq!(not_nan(-3.0f64).saturate(), 0.0);
+    assert_eq!(not_nan(3.0f64).saturate(), 1.0);
+    assert_eq!(not_nan(0.75f32).saturate(), 0.75);
+    assert_eq!(not_nan(f32::INFINITY).saturate(), 1.0);
+    assert!(not_nan(-0.0f64).saturate().is_sign_positive());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_duration_conversions() {
+    use std::time::Duration;
+
+    assert_eq!(
+        not_nan(1.5f64).to_duration(),
+        Some(Duration::from_millis(1500))
+    );
+    assert_eq!(not_nan(0.0f64).to_duration(), Some(Duration::ZERO));
+    assert_eq!(not_nan(-0.0f64).to_duration(), Some(Duration::ZERO));
+    assert_eq!(not_nan(-1.5f64).to_duration(), None);
+    assert_eq!(not_nan(f64::INFINITY).to_duration(), None);
+    assert_eq!(not_nan(1e20f64).to_duration(), None);
+
+    assert_eq!(
+        OrderedFloat(1.5f64).to_duration(),
+        Some(Duration::from_millis(1500))
+    );
+    assert_eq!(OrderedFloat(f64::NAN).to_duration(), None);
+    assert_eq!(OrderedFloat(f64::NEG_INFINITY).to_duration(), None);
+
+    assert_eq!(
+        NotNan::from_duration(Duration::from_millis(1500)),
+        not_nan(1.5f64)
+    );
+    assert_eq!(NotNan::from_duration(Duration::MAX).to_duration(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_duration_nanosecond_precision_bound() {
+    use std::time::Duration;
+
+    // Every nanosecond just below 2^23 seconds still has its own `f64`...
+    let below = Duration::new((1 << 23) - 1, 999_999_999);
+    for ns in 0..4 {
+        let d = below - Duration::from_nanos(ns);
+        assert_ne!(
+            NotNan::from_duration(d),
+            NotNan::from_duration(d - Duration::from_nanos(1))
+        );
+    }
+    // ...but from 2^23 seconds on, `f64`s are about 1.9 ns apart and adjacent nanoseconds can
+    // round to the same value.
+    let bound = Duration::from_secs(1 << 23);
+    let secs = |d: Duration| NotNan::from_duration(d).into_inner();
+    assert_eq!(
+        secs(bound + Duration::from_nanos(1)),
+        secs(bound + Duration::from_nanos(2))
+    );
+    assert_eq!(secs(bound) - secs(below), 2f64.powi(-30));
+    assert_eq!(
+        secs(bound + Duration::from_nanos(1)) - secs(bound),
+        2f64.powi(-29)
+    );
+}
+
+#[test]
+fn test_scale() {
+    assert_eq!(not_nan(2.0f64).scale(1.5), Ok(not_nan(3.0f64)));
+    assert_eq!(
+        not_nan(2.0f64).scale(f64::INFINITY),
+        Ok(not_nan(f64::INFINITY))
+    );
+    assert_eq!(not_nan(0.0f64).scale(f64::INFINITY), Err(FloatIsNan));
+    assert_eq!(not_nan(f32::INFINITY).scale(0.0), Err(FloatIsNan));
+    assert_eq!(not_nan(1.0f64).scale(f64::NAN), Err(FloatIsNan));
+    assert_eq!(unsafe { not_nan(2.0f64).scale_unchecked(0.25) }, 0.5);
+
+    assert_eq!(OrderedFloat(2.0f64).scale(1.5), Ok(OrderedFloat(3.0)));
+    assert_eq!(OrderedFloat(0.0f64).scale(f64::INFINITY), Err(FloatIsNan));
+    assert_eq!(OrderedFloat(f64::NAN).scale(1.0), Err(FloatIsNan));
+    assert_eq!(
+        OrderedFloat(2.0f32).scale_unchecked(0.25),
+        OrderedFloat(0.5)
+    );
+    assert!(OrderedFloat(0.0f64)
+        .scale_unchecked(f64::INFINITY)
+        .0
+        .is_nan());
+}
+
+#[test]
+fn test_hash_consistent_with_cmp_randomized() {
+    fn hash<T: Hash>(x: &T) -> u64 {
+        let mut s = DefaultHasher::new();
+        x.hash(&mut s);
+        s.finish()
+    }
+
+    // xorshift64, so the test needs no extra dependencies.
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut values: Vec<OrderedFloat<f64>> = (0..2000)
+        .map(|i| match i % 10 {
+            // Random NaN payloads and signed zeros, which must collapse.
+            0 => OrderedFloat(f64::from_bits(0x7ff0_0000_0000_0001 | next())),
+            1 => OrderedFloat(if next() & 1 == 0 { 0.0 } else { -0.0 }),
+            // Nearby values, which must stay distinct.
+            2 => OrderedFloat((next() % 16) as f64),
+            _ => OrderedFloat(f64::from_bits(next())),
+        })
+        .collect();
+    values.push(OrderedFloat(f64::INFINITY));
+    values.push(OrderedFloat(f64::NEG_INFINITY));
+
+    let hashes: Vec<u64> = values.iter().map(hash).collect();
+    let mut collisions = 0;
+    for i in 0..values.len() {
+        for j in 0..values.len() {
+            if values[i].cmp(&values[j]) == Equal {
+                assert_eq!(hashes[i], hashes[j], "{:?} {:?}", values[i], values[j]);
+            } else if hashes[i] == hashes[j] {
+                collisions += 1;
+            }
+        }
+    }
+    assert_eq!(collisions, 0);
+
+    for x in &values {
+        if !x.0.is_nan() {
+            assert_eq!(hash(x), hash(&NotNan::new(x.0).unwrap()));
+        }
+    }
+}
+
+#[test]
+fn test_ordered_float_assign_ref_forms() {
+    macro_rules! check_assign {
+        ($op:tt, $assign:tt) => {{
+            let x = OrderedFloat(7.0f64);
+            let y = OrderedFloat(2.0f64);
+            let expected = OrderedFloat(7.0f64 $op 2.0);
+
+            let mut a = x;
+            a $assign y;
+            assert_eq!(a, expected);
+            let mut a = x;
+            a $assign &y;
+            assert_eq!(a, expected);
+            let mut a = x;
+            a $assign 2.0;
+            assert_eq!(a, expected);
+            let mut a = x;
+            a $assign &2.0;
+            assert_eq!(a, expected);
+
+            let mut a = OrderedFloat(7.0f32);
+            a $assign &OrderedFloat(2.0f32);
+            a $assign &1.0f32;
+            assert_eq!(a, OrderedFloat(7.0f32 $op 2.0 $op 1.0));
+        }};
+    }
+
+    check_assign!(+, +=);
+    check_assign!(-, -=);
+    check_assign!(*, *=);
+    check_assign!(/, /=);
+    check_assign!(%, %=);
+}
+
+#[test]
+fn test_const_cmp() {
+    const TABLE: [OrderedFloat<f64>; 5] = [
+        OrderedFloat(f64::NEG_INFINITY),
+        OrderedFloat(-1.0),
+        OrderedFloat(0.0),
+        OrderedFloat(2.5),
+        OrderedFloat(f64::NAN),
+    ];
+    const fn is_sorted(t: &[OrderedFloat<f64>]) -> bool {
+        let mut i = 1;
+        while i < t.len() {
+            if let Greater = t[i - 1].const_cmp(t[i]) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+    // Fails to compile if the table is not sorted.
+    const _: () = assert!(is_sorted(&TABLE));
+    assert!(!is_sorted(&[OrderedFloat(f64::NAN), OrderedFloat(1.0)]));
+
+    const ZEROS: std::cmp::Ordering = OrderedFloat(-0.0f32).const_cmp(OrderedFloat(0.0));
+    assert_eq!(ZEROS, Equal);
+
+    let values = [
+        f64::NEG_INFINITY,
+        f64::MIN,
+        -1.0,
+        -f64::from_bits(1),
+        -0.0,
+        0.0,
+        f64::from_bits(1),
+        1.0,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NAN,
+        -f64::NAN,
+    ];
+    for &a in &values {
+        for &b in &values {
+            let (a, b) = (OrderedFloat(a), OrderedFloat(b));
+            assert_eq!(a.const_cmp(b), a.cmp(&b), "{:?} {:?}", a, b);
+            let (a, b) = (OrderedFloat(a.0 as f32), OrderedFloat(b.0 as f32));
+            assert_eq!(a.const_cmp(b), a.cmp(&b), "{:?} {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn test_finite_not_nan() {
+    let data = vec![
+        f64::NAN,
+        1.5,
+        f64::INFINITY,
+        -0.0,
+        f64::NEG_INFINITY,
+        f64::MAX,
+        -f64::NAN,
+        -3.0,
+    ];
+    let v: Vec<NotNan<f64>> = data.into_iter().finite_not_nan().collect();
+    assert_eq!(v, [1.5, -0.0, f64::MAX, -3.0]);
+
+    assert_eq!(
+        std::iter::repeat(f32::NAN).take(3).finite_not_nan().count(),
+        0
+    );
+}
+
+#[test]
+fn test_byte_conversions() {
+    let x = OrderedFloat(1.5f64);
+    assert_eq!(x.to_le_bytes(), 1.5f64.to_le_bytes());
+    assert_eq!(OrderedFloat::<f64>::from_le_bytes(x.to_le_bytes()), x);
+    assert_eq!(OrderedFloat::<f64>::from_be_bytes(x.to_be_bytes()), x);
+    assert_eq!(OrderedFloat::<f64>::from_ne_bytes(x.to_ne_bytes()), x);
+    assert_eq!(
+        OrderedFloat(-2.0f32).to_be_bytes(),
+        [0xc0, 0x00, 0x00, 0x00]
+    );
+    assert!(OrderedFloat::<f32>::from_le_bytes(f32::NAN.to_le_bytes())
+        .0
+        .is_nan());
+
+    let y = not_nan(-0.25f32);
+    assert_eq!(NotNan::<f32>::from_le_bytes(y.to_le_bytes()), Ok(y));
+    assert_eq!(NotNan::<f32>::from_be_bytes(y.to_be_bytes()), Ok(y));
+    assert_eq!(NotNan::<f32>::from_ne_bytes(y.to_ne_bytes()), Ok(y));
+    assert_eq!(
+        not_nan(1.0f64).to_le_bytes(),
+        [0, 0, 0, 0, 0, 0, 0xf0, 0x3f]
+    );
+
+    assert_eq!(
+        NotNan::<f64>::from_le_bytes(f64::NAN.to_le_bytes()),
+        Err(FloatIsNan)
+    );
+    assert_eq!(
+        NotNan::<f32>::from_be_bytes([0x7f, 0xc0, 0x00, 0x01]),
+        Err(FloatIsNan)
+    );
+    assert_eq!(
+        NotNan::<f64>::from_le_bytes(f64::INFINITY.to_le_bytes()),
+        Ok(not_nan(f64::INFINITY))
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sortable_string() {
+    let mut values: Vec<OrderedFloat<f64>> = [
+        f64::NAN,
+        1.0,
+        -0.0,
+        f64::INFINITY,
+        -1e300,
+        f64::NEG_INFINITY,
+        0.0,
+        -f64::from_bits(1),
+        f64::MIN_POSITIVE,
+        -2.5,
+        f64::MAX,
+        f64::from_bits(1),
+        -f64::NAN,
+    ]
+    .iter()
+    .map(|&x| OrderedFloat(x))
+    .collect();
+
+    let mut strings: Vec<String> = values.iter().map(|x| x.sortable_string()).collect();
+    strings.sort();
+    values.sort();
+    let decoded: Vec<_> = strings
+        .iter()
+        .map(|s| OrderedFloat::from_sortable_str(s).unwrap())
+        .collect();
+    assert_eq!(decoded, values);
+
+    assert!(strings.iter().all(|s| s.len() == 16));
+    assert_eq!(
+        OrderedFloat(-0.0).sortable_string(),
+        OrderedFloat(0.0).sortable_string()
+    );
+    assert_eq!(
+        OrderedFloat(f64::NAN).sortable_string(),
+        OrderedFloat(-f64::NAN).sortable_string()
+    );
+    let zero = OrderedFloat::from_sortable_str(&OrderedFloat(-0.0).sortable_string()).unwrap();
+    assert_eq!(zero.0.to_bits(), 0);
+
+    assert_eq!(OrderedFloat::from_sortable_str(""), None);
+    assert_eq!(OrderedFloat::from_sortable_str("+fffffffffffffff"), None);
+    assert_eq!(OrderedFloat::from_sortable_str("0123456789abcdefg"), None);
+    assert_eq!(OrderedFloat::from_sortable_str("xyz0000000000000"), None);
+}
+
+#[test]
+fn test_arithmetic_inference_with_annotations() {
+    let offset = |x: OrderedFloat<f64>| x + 1.0;
+    let scale = |x: &OrderedFloat<f32>, k: f32| x * k;
+    assert_eq!(offset(OrderedFloat(1.0)), OrderedFloat(2.0));
+    assert_eq!(scale(&OrderedFloat(1.5), 2.0), OrderedFloat(3.0));
+
+    let total: OrderedFloat<f64> = [1.0, 2.0]
+        .iter()
+        .map(|&x| OrderedFloat(x))
+        .fold(OrderedFloat(0.0), |acc, x| acc + x);
+    assert_eq!(total, OrderedFloat(3.0));
+}
+
+#[test]
+fn test_lerp() {
+    let (a, b) = (OrderedFloat(2.0f64), OrderedFloat(6.0f64));
+    assert_eq!(a.lerp(b, 0.0), a);
+    assert_eq!(a.lerp(b, 1.0), b);
+    assert_eq!(a.lerp(b, 0.5), OrderedFloat(4.0));
+    assert_eq!(a.lerp(b, 2.0), OrderedFloat(10.0));
+    assert_eq!(b.lerp(a, 0.25), OrderedFloat(5.0));
+    assert!(OrderedFloat(f64::INFINITY)
+        .lerp(OrderedFloat(f64::INFINITY), 0.5)
+        .0
+        .is_nan());
+    assert!(OrderedFloat(1.0f64)
+        .lerp(OrderedFloat(f64::INFINITY), 0.0)
+        .0
+        .is_nan());
+
+    let (a, b) = (not_nan(2.0f32), not_nan(6.0f32));
+    assert_eq!(a.lerp(b, 0.0), a);
+    assert_eq!(a.lerp(b, 1.0), b);
+    assert_eq!(a.lerp(b, 0.5), 4.0);
+    assert_eq!(a.lerp(b, 2.0), 10.0);
+    assert_eq!(a.lerp(b, -0.5), 0.0);
+}
+
+#[test]
+#[should_panic(expected = "Interpolation resulted in NaN")]
+fn test_lerp_not_nan_panics_on_nan() {
+    let inf = not_nan(f64::INFINITY);
+    let _ = inf.lerp(inf, 0.5);
+}
+
+#[test]
+#[should_panic(expected = "Interpolation resulted in NaN")]
+fn test_lerp_not_nan_panics_at_zero_with_infinite_endpoint() {
+    let _ = not_nan(1.0f64).lerp(not_nan(f64::INFINITY), 0.0);
+}
+
+#[test]
+fn test_default_const() {
+    const ORDERED: [OrderedFloat<f64>; 3] = [OrderedFloat::<f64>::default_const(); 3];
+    const NOT_NAN: NotNan<f32> = NotNan::<f32>::default_const();
+
+    assert_eq!(ORDERED, [OrderedFloat::<f64>::default(); 3]);
+    assert!(ORDERED[0].0.is_sign_positive());
+    assert_eq!(NOT_NAN, NotNan::<f32>::default());
+    assert_eq!(NOT_NAN.to_bits(), 0);
+    assert_eq!(OrderedFloat::<f32>::default_const(), OrderedFloat(0.0));
+    assert_eq!(NotNan::<f64>::default_const(), NotNan::<f64>::default());
+}
+
+#[test]
+fn test_max_with_nan_flag() {
+    let empty: [OrderedFloat<f64>; 0] = [];
+    assert_eq!(empty.max_with_nan_flag(), (None, false));
+
+    let v = [
+        OrderedFloat(-1.0f64),
+        OrderedFloat(f64::INFINITY),
+        OrderedFloat(2.0),
+    ];
+    assert_eq!(
+        v.max_with_nan_flag(),
+        (Some(OrderedFloat(f64::INFINITY)), false)
+    );
+
+    let v = [
+        OrderedFloat(5.0f32),
+        OrderedFloat(-f32::NAN),
+        OrderedFloat(2.0),
+    ];
+    let (max, has_nan) = v.max_with_nan_flag();
+    assert!(has_nan);
+    assert!(max.unwrap().0.is_nan());
+
+    let v = [not_nan(1.0f64), not_nan(4.0), not_nan(-2.0)];
+    assert_eq!(v.max_with_nan_flag(), (Some(not_nan(4.0)), false));
+    let empty: [NotNan<f64>; 0] = [];
+    assert_eq!(empty.max_with_nan_flag(), (None, false));
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+#[allow(clippy::approx_constant)]
+fn test_round_to() {
+    assert_eq!(OrderedFloat(3.14159f64).round_to(2), OrderedFloat(3.14));
+    assert_eq!(OrderedFloat(12345.0f64).round_to(-2), OrderedFloat(12300.0));
+    assert_eq!(OrderedFloat(-2.5f64).round_to(0), OrderedFloat(-3.0));
+    assert_eq!(OrderedFloat(1.5f32).round_to(400), OrderedFloat(1.5));
+    assert_eq!(OrderedFloat(1e300f64).round_to(20), OrderedFloat(1e300));
+    assert!(OrderedFloat(f64::NAN).round_to(2).0.is_nan());
+    assert_eq!(
+        OrderedFloat(f64::INFINITY).round_to(-2),
+        OrderedFloat(f64::INFINITY)
+    );
+
+    assert_eq!(not_nan(3.14159f64).round_to(2), not_nan(3.14));
+    assert_eq!(not_nan(12345.0f64).round_to(-2), not_nan(12300.0));
+    assert_eq!(not_nan(12345.0f32).round_to(-3), not_nan(12000.0));
+    let tiny = not_nan(-123.0f64).round_to(-400);
+    assert_eq!(tiny, 0.0);
+    assert!(tiny.is_sign_negative());
+}
+
+#[test]
+fn test_reverse_operands() {
+    let three = not_nan(3.0f64);
+    assert_eq!(5.0 + three, 8.0);
+    assert_eq!(5.0 - three, 2.0);
+    assert_eq!(5.0 * three, 15.0);
+    assert_eq!(6.0 / three, 2.0);
+    assert_eq!(5.0 % three, 2.0);
+    assert_eq!(5.0 % &three, 2.0);
+    assert_eq!(5.0f32 % not_nan(3.0f32), 2.0);
+    assert!((f64::NAN + three).is_nan());
+
+    let three = OrderedFloat(3.0f64);
+    assert_eq!(5.0 + three, OrderedFloat(8.0));
+    assert_eq!(5.0 - three, OrderedFloat(2.0));
+    assert_eq!(5.0 * three, OrderedFloat(15.0));
+    assert_eq!(6.0 / three, OrderedFloat(2.0));
+    assert_eq!(5.0 % three, OrderedFloat(2.0));
+    assert_eq!(5.0 % &three, OrderedFloat(2.0));
+    assert_eq!(5.0f32 - OrderedFloat(3.0f32), OrderedFloat(2.0));
+    assert_eq!(0.0 / OrderedFloat(0.0f64), OrderedFloat(f64::NAN));
+}
+
+#[test]
+fn test_classify_predicates() {
+    use std::num::FpCategory;
+
+    let cases: [(OrderedFloat<f64>, FpCategory); 7] = [
+        (OrderedFloat(1.0), FpCategory::Normal),
+        (OrderedFloat(-0.0), FpCategory::Zero),
+        (OrderedFloat(f64::MIN_POSITIVE / 2.0), FpCategory::Subnormal),
+        (OrderedFloat(f64::INFINITY), FpCategory::Infinite),
+        (OrderedFloat(f64::NEG_INFINITY), FpCategory::Infinite),
+        (OrderedFloat(f64::NAN), FpCategory::Nan),
+        (OrderedFloat(-f64::NAN), FpCategory::Nan),
+    ];
+    for (x, category) in cases {
+        assert_eq!(x.classify(), category);
+        assert_eq!(x.is_finite(), x.0.is_finite());
+        assert_eq!(x.is_infinite(), x.0.is_infinite());
+        assert_eq!(x.is_normal(), x.0.is_normal());
+        assert_eq!(x.is_subnormal(), category == FpCategory::Subnormal);
+        assert_eq!(x.is_sign_positive(), x.0.is_sign_positive());
+        assert_eq!(x.is_sign_negative(), x.0.is_sign_negative());
+    }
+    assert!(OrderedFloat(f32::MIN_POSITIVE / 2.0).is_subnormal());
+    assert!(!OrderedFloat(f32::MIN_POSITIVE).is_subnormal());
+    assert!(OrderedFloat(-0.0f32).is_sign_negative());
+
+    let x = not_nan(-0.0f64);
+    assert_eq!(x.classify(), FpCategory::Zero);
+    assert!(x.is_finite() && !x.is_infinite() && !x.is_normal() && !x.is_subnormal());
+    assert!(x.is_sign_negative() && !x.is_sign_positive());
+    assert!(not_nan(f64::INFINITY).is_infinite());
+    assert!(!not_nan(1.0f32).is_nan());
+    const _: () = assert!(!NotNan::<f64>::default_const().is_nan());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_arc_slice_conversions() {
+    use std::sync::Arc;
+
+    let floats: Arc<[f64]> = Arc::from([3.0, 1.0, 2.0]);
+    let keep = Arc::clone(&floats);
+    let ptr = floats.as_ptr() as usize;
+
+    let ordered = OrderedFloat::from_arc_slice(floats);
+    assert_eq!(ordered.as_ptr() as usize, ptr);
+    assert_eq!(Arc::strong_count(&ordered), 2);
+    assert_eq!(ordered.iter().max(), Some(&OrderedFloat(3.0)));
+
+    let back = OrderedFloat::into_arc_slice_inner(ordered);
+    assert_eq!(back.as_ptr() as usize, ptr);
+    assert_eq!(Arc::strong_count(&back), 2);
+
+    let not_nans = NotNan::try_from_arc_slice(back).unwrap();
+    assert_eq!(not_nans.as_ptr() as usize, ptr);
+    assert_eq!(Arc::strong_count(&not_nans), 2);
+    let back = NotNan::into_arc_slice_inner(not_nans);
+    assert_eq!(back.as_ptr() as usize, ptr);
+    drop(back);
+    assert_eq!(Arc::strong_count(&keep), 1);
+
+    let with_nan: Arc<[f32]> = Arc::from([1.0, f32::NAN]);
+    assert_eq!(NotNan::try_from_arc_slice(with_nan), Err(FloatIsNan));
+    let empty: Arc<[f64]> = Arc::from([]);
+    assert!(NotNan::try_from_arc_slice(empty).unwrap().is_empty());
+}
+
+#[test]
+fn test_as_not_nan() {
+    let x = OrderedFloat(2.5f64);
+    let y = x.as_not_nan().unwrap();
+    assert_eq!(*y, not_nan(2.5));
+    assert!(std::ptr::eq(
+        y as *const NotNan<f64> as *const f64,
+        &x.0 as *const f64
+    ));
+    assert_eq!(
+        OrderedFloat(f32::INFINITY).as_not_nan(),
+        Some(&not_nan(f32::INFINITY))
+    );
+    assert_eq!(OrderedFloat(f64::NAN).as_not_nan(), None);
+    assert_eq!(OrderedFloat(-f32::NAN).as_not_nan(), None);
+}
+
+#[test]
+fn test_rem_euclid_int() {
+    let of = |a: f64, b: f64| OrderedFloat(a).rem_euclid_int(OrderedFloat(b)).map(|r| r.0);
+    assert_eq!(of(7.0, 4.0), Some(3.0));
+    assert_eq!(of(-7.0, 4.0), Some(1.0));
+    assert_eq!(of(7.0, -4.0), Some(3.0));
+    assert_eq!(of(-7.0, -4.0), Some(1.0));
+    assert_eq!(of(-8.0, 4.0).map(f64::to_bits), Some(0.0f64.to_bits()));
+    assert_eq!(of(-0.0, 4.0).map(f64::to_bits), Some(0.0f64.to_bits()));
+    assert_eq!(of(3.0, 1e300), Some(3.0));
+
+    assert_eq!(of(7.5, 4.0), None);
+    assert_eq!(of(7.0, 4.5), None);
+    assert_eq!(of(7.0, 0.0), None);
+    assert_eq!(of(7.0, -0.0), None);
+    assert_eq!(of(f64::NAN, 4.0), None);
+    assert_eq!(of(7.0, f64::NAN), None);
+    assert_eq!(of(f64::INFINITY, 4.0), None);
+    assert_eq!(of(7.0, f64::INFINITY), None);
+    // -1 + 2^60 isn't representable.
+    assert_eq!(of(-1.0, (1u64 << 60) as f64), None);
+
+    assert_eq!(
+        OrderedFloat(-1.0f32).rem_euclid_int(OrderedFloat(3.0)),
+        Some(OrderedFloat(2.0))
+    );
+    assert_eq!(
+        not_nan(-7.0f64).rem_euclid_int(not_nan(4.0)),
+        Some(not_nan(1.0))
+    );
+    assert_eq!(not_nan(-7.25f32).rem_euclid_int(not_nan(4.0)), None);
+    assert_eq!(not_nan(-7.0f64).rem_euclid_int(not_nan(0.0)), None);
+}
+
+#[test]
+fn test_not_nan_cmp_total() {
+    let (neg, pos) = (not_nan(-0.0f64), not_nan(0.0f64));
+    assert_eq!(neg.cmp_total(&pos), Less);
+    assert_eq!(pos.cmp_total(&neg), Greater);
+    assert_eq!(neg.cmp(&pos), Equal);
+    assert_eq!(not_nan(-0.0f32).cmp_total(&not_nan(0.0)), Less);
+    assert_eq!(not_nan(-0.0f32).cmp(&not_nan(0.0)), Equal);
+
+    let mut v = [
+        not_nan(1.0f64),
+        pos,
+        not_nan(f64::NEG_INFINITY),
+        neg,
+        not_nan(-1.0),
+    ];
+    v.sort_by(|a, b| a.cmp_total(b));
+    let bits: Vec<u64> = v.iter().map(|x| x.to_bits()).collect();
+    let expected: Vec<u64> = [f64::NEG_INFINITY, -1.0, -0.0, 0.0, 1.0]
+        .iter()
+        .map(|x| x.to_bits())
+        .collect();
+    assert_eq!(bits, expected);
+}
+
+#[test]
+fn test_not_nan_and_ordered_float_hash_alike() {
+    fn hash<T: Hash + ?Sized>(x: &T) -> u64 {
+        let mut s = DefaultHasher::new();
+        x.hash(&mut s);
+        s.finish()
+    }
+
+    let mut values = vec![0.0f64, -0.0, f64::MIN_POSITIVE, f64::MAX, f64::MIN, 5e-324];
+    for i in -20..=20 {
+        values.push(f64::from(i) * 0.375);
+        values.push(2f64.powi(i * 50));
+    }
+    for &f in &values {
+        assert_eq!(hash(&not_nan(f)), hash(&OrderedFloat(f)), "{}", f);
+        let f = f as f32;
+        assert_eq!(hash(&not_nan(f)), hash(&OrderedFloat(f)), "{}", f);
+    }
+    assert_eq!(hash(&not_nan(-0.0f64)), hash(&OrderedFloat(0.0f64)));
+    assert_eq!(hash(&not_nan(0.0f32)), hash(&OrderedFloat(-0.0f32)));
+
+    let not_nans: Vec<NotNan<f64>> = values.iter().map(|&f| not_nan(f)).collect();
+    let ordered: Vec<OrderedFloat<f64>> = values.iter().map(|&f| OrderedFloat(f)).collect();
+    assert_eq!(hash(&not_nans[..]), hash(&ordered[..]));
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn test_atan2_hypot_mul_add() {
+    let values = [
+        0.0f64,
+        -0.0,
+        1.0,
+        -2.5,
+        1e300,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ];
+    for &a in &values {
+        for &b in &values {
+            let (oa, ob) = (OrderedFloat(a), OrderedFloat(b));
+            assert_eq!(oa.atan2(ob), OrderedFloat(Float::atan2(a, b)));
+            assert_eq!(oa.hypot(ob), OrderedFloat(Float::hypot(a, b)));
+            assert_eq!(
+                oa.mul_add(ob, OrderedFloat(1.0)),
+                OrderedFloat(Float::mul_add(a, b, 1.0))
+            );
+
+            let (na, nb) = (not_nan(a), not_nan(b));
+            assert_eq!(na.atan2(nb), Float::atan2(a, b));
+            assert_eq!(na.hypot(nb), Float::hypot(a, b));
+        }
+    }
+    assert_eq!(not_nan(0.0f64).atan2(not_nan(0.0)), 0.0);
+    assert_eq!(not_nan(3.0f32).hypot(not_nan(4.0)), 5.0);
+    assert_eq!(not_nan(2.0f32).mul_add(not_nan(3.0), not_nan(1.0)), 7.0);
+    assert_eq!(
+        OrderedFloat(f64::NAN).atan2(OrderedFloat(1.0)),
+        OrderedFloat(f64::NAN)
+    );
+    assert_eq!(
+        OrderedFloat(f64::INFINITY).mul_add(OrderedFloat(0.0), OrderedFloat(1.0)),
+        OrderedFloat(f64::NAN)
+    );
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+#[should_panic(expected = "Multiply-add resulted in NaN")]
+fn test_not_nan_mul_add_nan_panics() {
+    let inf = not_nan(f64::INFINITY);
+    let _ = inf.mul_add(not_nan(0.0), -inf);
+}
+
+#[test]
+fn test_ldexp() {
+    assert_eq!(OrderedFloat(1.5f64).ldexp(3), OrderedFloat(12.0));
+    assert_eq!(OrderedFloat(1.5f32).ldexp(3), OrderedFloat(12.0));
+    assert_eq!(not_nan(1.5f64).ldexp(3), 12.0);
+    assert_eq!(not_nan(1.5f32).ldexp(-1), 0.75);
+
+    assert_eq!(
+        OrderedFloat(1.5f64).ldexp(1024),
+        OrderedFloat(f64::INFINITY)
+    );
+    assert_eq!(not_nan(-1.0f32).ldexp(i32::MAX), f32::NEG_INFINITY);
+    assert_eq!(
+        OrderedFloat(f64::MIN_POSITIVE).ldexp(2000).ldexp(-2000),
+        OrderedFloat(f64::MIN_POSITIVE)
+    );
+    assert_eq!(
+        OrderedFloat(1.0f64).ldexp(-1074),
+        OrderedFloat(f64::from_bits(1))
+    );
+    assert_eq!(OrderedFloat(1.0f64).ldexp(-1075), OrderedFloat(0.0));
+    assert_eq!(
+        OrderedFloat(f64::MAX).ldexp(-2097),
+        OrderedFloat(f64::from_bits(2))
+    );
+    assert_eq!(not_nan(1.0f32).ldexp(i32::MIN), 0.0);
+    assert_eq!(
+        OrderedFloat(f32::from_bits(1)).ldexp(149),
+        OrderedFloat(1.0)
+    );
+    assert_eq!(
+        OrderedFloat(3.0f64).ldexp(-1023).ldexp(1023),
+        OrderedFloat(3.0)
+    );
+
+    assert_eq!(not_nan(0.0f64).ldexp(5000), 0.0);
+    assert_eq!(not_nan(f64::INFINITY).ldexp(-5000), f64::INFINITY);
+    assert_eq!(OrderedFloat(f64::NAN).ldexp(3), OrderedFloat(f64::NAN));
+
+    for e in -1022..1024 {
+        assert_eq!(OrderedFloat(1.0f64).ldexp(e).0, 2f64.powi(e), "{}", e);
+    }
+}
+
+#[test]
+fn test_to_f32_exact() {
+    assert_eq!(not_nan(1.0f64).to_f32_exact(), Some(1.0));
+    assert_eq!(not_nan(0.1f64).to_f32_exact(), None);
+    assert_eq!(not_nan(1e300f64).to_f32_exact(), None);
+    assert_eq!(not_nan(-1e-300f64).to_f32_exact(), None);
+    assert_eq!(not_nan(f64::from(f32::MAX)).to_f32_exact(), Some(f32::MAX));
+    assert_eq!(
+        not_nan(f64::NEG_INFINITY).to_f32_exact(),
+        Some(f32::NEG_INFINITY)
+    );
+    let neg_zero = not_nan(-0.0f64).to_f32_exact().unwrap();
+    assert!(neg_zero == 0.0 && neg_zero.is_sign_negative());
+
+    assert_eq!(OrderedFloat(1.0f64).to_f32_exact(), Some(1.0));
+    assert_eq!(OrderedFloat(0.1f64).to_f32_exact(), None);
+    assert_eq!(OrderedFloat(1e300f64).to_f32_exact(), None);
+    assert_eq!(OrderedFloat(-2.5f64).to_f32_exact(), Some(-2.5));
+    assert!(OrderedFloat(f64::NAN).to_f32_exact().unwrap().is_nan());
+}
+
+#[test]
+fn test_signum_zero() {
+    for (x, expected) in [
+        (0.0f64, 0.0f64),
+        (-0.0, 0.0),
+        (2.5, 1.0),
+        (f64::MIN_POSITIVE / 4.0, 1.0),
+        (f64::INFINITY, 1.0),
+        (-7.0, -1.0),
+        (f64::NEG_INFINITY, -1.0),
+    ] {
+        let of = OrderedFloat(x).signum_zero();
+        assert_eq!(of.0.to_bits(), expected.to_bits(), "{}", x);
+        let nn = not_nan(x).signum_zero();
+        assert_eq!(nn.to_bits(), expected.to_bits(), "{}", x);
+    }
+    assert_eq!(OrderedFloat(-0.0f32).signum_zero().0.to_bits(), 0);
+    assert_eq!(not_nan(-4.0f32).signum_zero(), -1.0);
+    assert_eq!(OrderedFloat(f64::NAN).signum_zero(), OrderedFloat(f64::NAN));
+}
+
+#[test]
+fn test_not_nan_checked_assign() {
+    let mut x = not_nan(1.5f64);
+    assert_eq!(x.checked_add_assign(2.0), Ok(()));
+    assert_eq!(x, 3.5);
+    assert_eq!(x.checked_sub_assign(0.5), Ok(()));
+    assert_eq!(x, 3.0);
+    assert_eq!(x.checked_mul_assign(4.0), Ok(()));
+    assert_eq!(x, 12.0);
+    assert_eq!(x.checked_div_assign(3.0), Ok(()));
+    assert_eq!(x, 4.0);
+    assert_eq!(x.checked_rem_assign(3.0), Ok(()));
+    assert_eq!(x, 1.0);
+
+    assert_eq!(x.checked_add_assign(f64::NAN), Err(FloatIsNan));
+    assert_eq!(x.checked_rem_assign(0.0), Err(FloatIsNan));
+    assert_eq!(x, 1.0);
+
+    let mut inf = not_nan(f64::INFINITY);
+    assert_eq!(inf.checked_add_assign(f64::NEG_INFINITY), Err(FloatIsNan));
+    assert_eq!(inf.checked_sub_assign(f64::INFINITY), Err(FloatIsNan));
+    assert_eq!(inf.checked_div_assign(f64::INFINITY), Err(FloatIsNan));
+    assert_eq!(inf.checked_rem_assign(1.0), Err(FloatIsNan));
+    assert_eq!(inf, f64::INFINITY);
+
+    let mut zero = not_nan(0.0f32);
+    assert_eq!(zero.checked_mul_assign(f32::INFINITY), Err(FloatIsNan));
+    assert_eq!(zero.checked_div_assign(0.0), Err(FloatIsNan));
+    assert_eq!(zero, 0.0);
+
+    // Through a `&mut` into a larger structure.
+    let mut slots = [not_nan(1.0f64), not_nan(2.0)];
+    let slot = &mut slots[1];
+    assert_eq!(slot.checked_mul_assign(f64::NAN), Err(FloatIsNan));
+    assert_eq!(slot.checked_add_assign(1.0), Ok(()));
+    assert_eq!(slots, [not_nan(1.0), not_nan(3.0)]);
+}
+
+#[test]
+fn test_not_nan_macro() {
+    const ONE: NotNan<f64> = not_nan!(1.0);
+    const NEG_ZERO: NotNan<f32> = not_nan!(f32, -0.0);
+    static INF: NotNan<f64> = not_nan!(f64::INFINITY);
+    const SUM: f64 = 2.0 + 0.5;
+    assert_eq!(ONE, 1.0);
+    assert!(NEG_ZERO.is_sign_negative());
+    assert_eq!(INF, f64::INFINITY);
+    assert_eq!(not_nan!(SUM), 2.5);
+    assert_eq!(not_nan!(f32, 1e30 * 1e30), f32::INFINITY);
+}
+
+#[test]
+fn test_bucket_index() {
+    let (lo, width) = (not_nan(10.0f64), not_nan(2.5f64));
+    let bucket = |x: f64| not_nan(x).bucket_index(lo, width, 4);
+    assert_eq!(bucket(10.0), Some(0));
+    assert_eq!(bucket(12.49), Some(0));
+    assert_eq!(bucket(12.5), Some(1));
+    assert_eq!(bucket(17.0), Some(2));
+    assert_eq!(bucket(19.99), Some(3));
+
+    assert_eq!(bucket(9.99), Some(0));
+    assert_eq!(bucket(-1e300), Some(0));
+    assert_eq!(bucket(f64::NEG_INFINITY), Some(0));
+    assert_eq!(bucket(20.0), Some(3));
+    assert_eq!(bucket(1e300), Some(3));
+    assert_eq!(bucket(f64::INFINITY), Some(3));
+
+    assert_eq!(not_nan(1.0f64).bucket_index(lo, not_nan(0.0), 4), None);
+    assert_eq!(not_nan(1.0f64).bucket_index(lo, not_nan(-1.0), 4), None);
+    assert_eq!(not_nan(1.0f64).bucket_index(lo, width, 0), None);
+    let inf = not_nan(f64::INFINITY);
+    assert_eq!(inf.bucket_index(inf, width, 4), None);
+    assert_eq!(not_nan(5.0f64).bucket_index(not_nan(0.0), inf, 4), Some(0));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_to_string_radix() {
+    assert_eq!(OrderedFloat(255.0f64).to_string_radix(16), "ff");
+    assert_eq!(not_nan(255.0f32).to_string_radix(16), "ff");
+    assert_eq!(OrderedFloat(255.0f64).to_string_radix(2), "11111111");
+    assert_eq!(OrderedFloat(35.0f64).to_string_radix(36), "z");
+    assert_eq!(OrderedFloat(0.0f64).to_string_radix(7), "0");
+    assert_eq!(OrderedFloat(-0.0f64).to_string_radix(7), "-0");
+    assert_eq!(not_nan(-4.5f64).to_string_radix(16), "-4.8");
+    assert_eq!(OrderedFloat(0.1f64).to_string_radix(16), "0.1999999999999a");
+    assert_eq!(OrderedFloat(1.0f64 / 3.0).to_string_radix(3), "0.1");
+    assert_eq!(not_nan(0.1f32).to_string_radix(10), "0.1");
+    assert_eq!(OrderedFloat(f64::NAN).to_string_radix(16), "NaN");
+    assert_eq!(OrderedFloat(f64::NEG_INFINITY).to_string_radix(16), "-inf");
+    assert_eq!(
+        OrderedFloat(2f64.powi(100)).to_string_radix(10),
+        "1267650600228229401496703205376"
+    );
+    // The integer part is exact rather than shortest.
+    let max = OrderedFloat(f64::MAX).to_string_radix(10);
+    assert!(max.starts_with("1797693134862315708145274237317"));
+    assert_eq!(max.len(), 309);
+    assert_eq!(
+        OrderedFloat(f64::MAX).to_string_radix(16),
+        "fffffffffffff8".to_owned() + &"0".repeat(242)
+    );
+    assert_eq!(
+        OrderedFloat(f64::from_bits(1)).to_string_radix(2),
+        format!("0.{}1", "0".repeat(1073))
+    );
+
+    for radix in [2, 8, 10, 16, 36] {
+        let s = OrderedFloat(1e20f64).to_string_radix(radix);
+        assert_eq!(u128::from_str_radix(&s, radix), Ok(10u128.pow(20)));
+    }
+    for &x in &[255.0f64, -4.5, 0.1, 1.0 / 3.0, 1e-5, 123456.789] {
+        assert_eq!(OrderedFloat(x).to_string_radix(10), x.to_string());
+        for radix in [2, 8, 10, 16, 36] {
+            let s = OrderedFloat(x).to_string_radix(radix);
+            let back = <OrderedFloat<f64> as num_traits::Num>::from_str_radix(&s, radix).unwrap();
+            assert!(
+                (back.0 - x).abs() <= x.abs() * 1e-12,
+                "{} {} {}",
+                x,
+                radix,
+                s
+            );
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = "radix must be in 2..=36")]
+fn test_to_string_radix_invalid_radix() {
+    let _ = OrderedFloat(1.0f64).to_string_radix(37);
+}
+
+#[test]
+fn test_expect_not_nan() {
+    assert_eq!(
+        OrderedFloat(1.5f64).expect_not_nan("finite"),
+        not_nan(1.5f64)
+    );
+    assert_eq!(
+        OrderedFloat(f32::INFINITY).unwrap_not_nan(),
+        not_nan(f32::INFINITY)
+    );
+    let zero = OrderedFloat(-0.0f64).unwrap_not_nan();
+    assert!(zero.is_sign_negative());
+}
+
+#[test]
+#[should_panic(expected = "ratio must be finite")]
+fn test_expect_not_nan_panics() {
+    let _ = OrderedFloat(f64::NAN).expect_not_nan("ratio must be finite");
+}
+
+#[test]
+#[should_panic(expected = "called `OrderedFloat::unwrap_not_nan()` on a NaN value")]
+fn test_unwrap_not_nan_panics() {
+    let _ = OrderedFloat(f32::NAN).unwrap_not_nan();
+}
+
+#[test]
+fn test_minimum_maximum() {
+    let of = OrderedFloat::<f64>;
+    let nan = of(f64::NAN);
+    for &x in &[
+        f64::NEG_INFINITY,
+        -1.0,
+        -0.0,
+        0.0,
+        2.5,
+        f64::INFINITY,
+        f64::NAN,
+    ] {
+        assert!(of(x).minimum(nan).0.is_nan());
+        assert!(nan.minimum(of(x)).0.is_nan());
+        assert!(of(x).maximum(nan).0.is_nan());
+        assert!(nan.maximum(of(x)).0.is_nan());
+    }
+    // `Ord::min` treats NaN as greatest instead of propagating it.
+    assert_eq!(Ord::min(of(1.0), nan), of(1.0));
+    assert!(of(1.0).minimum(nan).0.is_nan());
+
+    for &(a, b) in &[(0.0, -0.0), (-0.0, 0.0)] {
+        assert_eq!(of(a).minimum(of(b)).0.to_bits(), (-0.0f64).to_bits());
+        assert_eq!(of(a).maximum(of(b)).0.to_bits(), 0.0f64.to_bits());
+        assert_eq!(
+            not_nan(a).minimum(not_nan(b)).into_inner().to_bits(),
+            (-0.0f64).to_bits()
+        );
+        assert_eq!(
+            not_nan(a).maximum(not_nan(b)).into_inner().to_bits(),
+            0.0f64.to_bits()
+        );
+    }
+
+    assert_eq!(of(-1.0).minimum(of(2.5)), of(-1.0));
+    assert_eq!(of(2.5).minimum(of(-1.0)), of(-1.0));
+    assert_eq!(of(-1.0).maximum(of(2.5)), of(2.5));
+    assert_eq!(of(f64::NEG_INFINITY).maximum(of(-1e300)), of(-1e300));
+    assert_eq!(not_nan(3.0f32).minimum(not_nan(-3.0)), not_nan(-3.0));
+    assert_eq!(not_nan(3.0f32).maximum(not_nan(-3.0)), not_nan(3.0));
+}
+
+#[test]
+fn test_ordered_float_non_float_inner() {
+    // A minimal Q16.16 fixed-point number, which is not `FloatCore`.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Fixed(i32);
+
+    impl core::ops::Add for Fixed {
+        type Output = Fixed;
+        fn add(self, other: Fixed) -> Fixed {
+            Fixed(self.0 + other.0)
+        }
+    }
+
+    impl core::ops::Neg for Fixed {
+        type Output = Fixed;
+        fn neg(self) -> Fixed {
+            Fixed(-self.0)
+        }
+    }
+
+    impl core::ops::AddAssign for Fixed {
+        fn add_assign(&mut self, other: Fixed) {
+            self.0 += other.0;
+        }
+    }
+
+    impl core::iter::Sum for Fixed {
+        fn sum<I: Iterator<Item = Fixed>>(iter: I) -> Fixed {
+            iter.fold(Fixed(0), |a, b| a + b)
+        }
+    }
+
+    impl std::fmt::Display for Fixed {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "{}", self.0 as f64 / 65536.0)
+        }
+    }
+
+    let half = OrderedFloat::from(Fixed(1 << 15));
+    assert_eq!((-half).0, Fixed(-(1 << 15)));
+    assert_eq!((half + half).0, Fixed(1 << 16));
+    assert_eq!((half + Fixed(1 << 16)).0, Fixed(3 << 15));
+
+    let mut x = half;
+    x += half;
+    x += Fixed(1 << 16);
+    assert_eq!(*x, Fixed(2 << 16));
+    assert_eq!(x.to_string(), "2");
+
+    let total: OrderedFloat<Fixed> = vec![half, half, -half].into_iter().sum();
+    assert_eq!(total.as_ref(), &Fixed(1 << 15));
+    let total: OrderedFloat<Fixed> = [half, half].iter().sum();
+    assert_eq!(total.0, Fixed(1 << 16));
+
+    let mut raw = Fixed(7);
+    let wrapped: &mut OrderedFloat<Fixed> = (&mut raw).into();
+    *wrapped = -*wrapped;
+    assert_eq!(raw, Fixed(-7));
+
+    // Integers work too.
+    assert_eq!((-OrderedFloat(3i64) + OrderedFloat(5)).0, 2);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_range_float() {
+    use std::collections::BTreeMap;
+
+    let mut map = BTreeMap::new();
+    for (i, &t) in [
+        -1.5,
+        -0.0,
+        0.25,
+        1.0,
+        1.75,
+        2.0,
+        3.5,
+        f64::INFINITY,
+        f64::NAN,
+    ]
+    .iter()
+    .enumerate()
+    {
+        map.insert(OrderedFloat(t), i);
+    }
+    let values =
+        |lo: f64, hi: f64| -> Vec<usize> { map.range_float(lo, hi).map(|(_, &i)| i).collect() };
+
+    assert_eq!(values(1.0, 2.0), [3, 4, 5]);
+    assert_eq!(values(0.5, 0.75), [] as [usize; 0]);
+    assert_eq!(values(f64::NEG_INFINITY, 0.25), [0, 1, 2]);
+    // Either zero matches the `-0.0` key.
+    assert_eq!(values(0.0, 0.0), [1]);
+    assert_eq!(values(3.0, f64::INFINITY), [6, 7]);
+    // NaN is the greatest key.
+    assert_eq!(values(3.0, f64::NAN), [6, 7, 8]);
+    assert_eq!(values(f64::NAN, f64::NAN), [8]);
+    // Reversed bounds give an empty range instead of panicking.
+    assert_eq!(values(2.0, 1.0), [] as [usize; 0]);
+    assert_eq!(values(f64::NAN, 1.0), [] as [usize; 0]);
+
+    let same: Vec<usize> = map
+        .range(ordered_range(1.0, 2.0))
+        .map(|(_, &i)| i)
+        .collect();
+    assert_eq!(same, values(1.0, 2.0));
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn test_powf_or() {
+    let fallback = not_nan(0.0f64);
+    assert_eq!(not_nan(-2.0f64).powf_or(not_nan(0.5), fallback), fallback);
+    assert_eq!(
+        not_nan(-2.0f64).powf_or(not_nan(0.5), not_nan(-1.0)),
+        not_nan(-1.0)
+    );
+    assert_eq!(
+        not_nan(-2.0f64).powf_or(not_nan(3.0), fallback),
+        not_nan(-8.0)
+    );
+    assert_eq!(
+        not_nan(4.0f32).powf_or(not_nan(0.5), not_nan(0.0)),
+        not_nan(2.0)
+    );
+    assert_eq!(
+        not_nan(0.0f64).powf_or(not_nan(-1.0), fallback),
+        not_nan(f64::INFINITY)
+    );
+
+    let of = OrderedFloat::<f64>;
+    assert_eq!(of(-2.0).powf_or(of(0.5), of(0.0)), of(0.0));
+    assert_eq!(of(f64::NAN).powf_or(of(2.0), of(-1.0)), of(-1.0));
+    assert_eq!(of(9.0).powf_or(of(0.5), of(0.0)), of(3.0));
+    // `powf` returns 1 for a zero exponent, even for a NaN base.
+    assert_eq!(of(f64::NAN).powf_or(of(0.0), of(-1.0)), of(1.0));
+}
+
+#[test]
+fn test_min_max_collect() {
+    assert_eq!(core::iter::empty::<NotNan<f64>>().min_max_collect(), None);
+    assert_eq!(
+        core::iter::once(not_nan(1.5f64)).min_max_collect(),
+        Some((not_nan(1.5), not_nan(1.5)))
+    );
+
+    let v = [2.0f64, -3.0, 7.5, 0.0, -3.0, 7.5].map(not_nan);
+    assert_eq!(
+        v.iter().copied().min_max_collect(),
+        Some((not_nan(-3.0), not_nan(7.5)))
+    );
+
+    let v = [1.0f32, f32::NAN, f32::NEG_INFINITY].map(OrderedFloat);
+    let (min, max) = v.iter().copied().min_max_collect().unwrap();
+    assert_eq!(min, OrderedFloat(f32::NEG_INFINITY));
+    assert!(max.0.is_nan());
+
+    // Ties go to the first minimum and the last maximum, like `min` and `max`.
+    let zeros = [0.0f64, -0.0].map(not_nan);
+    let (min, max) = zeros.iter().copied().min_max_collect().unwrap();
+    assert_eq!(
+        min.into_inner().to_bits(),
+        zeros.iter().copied().min().unwrap().into_inner().to_bits()
+    );
+    assert_eq!(
+        max.into_inner().to_bits(),
+        zeros.iter().copied().max().unwrap().into_inner().to_bits()
+    );
+    assert!(min.is_sign_positive() && max.is_sign_negative());
+
+    let (min, max) = [4.0f64, 1.0, 9.0, 2.0]
+        .iter()
+        .copied()
+        .map(OrderedFloat)
+        .min_max_collect()
+        .unwrap();
+    assert_eq!((min.0, max.0), (1.0, 9.0));
+}
+
+#[test]
+fn test_ulps_between() {
+    let of = OrderedFloat::<f64>;
+    let next_up = |x: f64| f64::from_bits(x.to_bits() + 1);
+
+    for &x in &[0.0f64, 1e-310, f64::MIN_POSITIVE, 0.1, 1.0, 1e300] {
+        assert_eq!(of(x).ulps_between(of(x)), Some(0));
+        assert_eq!(of(x).ulps_between(of(next_up(x))), Some(1));
+        assert_eq!(of(next_up(x)).ulps_between(of(x)), Some(1));
+        assert_eq!(of(-x).ulps_between(of(-next_up(x))), Some(1));
+        assert_eq!(not_nan(x).ulps_between(not_nan(next_up(x))), Some(1));
+    }
+    assert_eq!(of(1.0).ulps_between(of(2.0)), Some(1 << 52));
+    assert_eq!(of(f64::MAX).ulps_between(of(f64::INFINITY)), Some(1));
+    assert_eq!(
+        of(0.0).ulps_between(of(f64::INFINITY)),
+        Some(f64::INFINITY.to_bits())
+    );
+
+    // Zeros are equal and count as either sign.
+    assert_eq!(of(-0.0).ulps_between(of(0.0)), Some(0));
+    assert_eq!(of(-0.0).ulps_between(of(f64::from_bits(1))), Some(1));
+    assert_eq!(of(0.0).ulps_between(of(-f64::from_bits(1))), Some(1));
+    assert_eq!(of(-1.0).ulps_between(of(1.0)), None);
+    assert_eq!(
+        of(-f64::from_bits(1)).ulps_between(of(f64::from_bits(1))),
+        None
+    );
+
+    assert_eq!(of(f64::NAN).ulps_between(of(1.0)), None);
+    assert_eq!(of(1.0).ulps_between(of(f64::NAN)), None);
+    assert_eq!(of(f64::NAN).ulps_between(of(f64::NAN)), None);
+
+    let one = OrderedFloat(1.0f32);
+    let next = OrderedFloat(f32::from_bits(1.0f32.to_bits() + 1));
+    assert_eq!(one.ulps_between(next), Some(1));
+    assert_eq!(not_nan(1.0f32).ulps_between(not_nan(2.0)), Some(1 << 23));
+    assert_eq!(not_nan(-1.0f32).ulps_between(not_nan(1.0)), None);
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_test {
+    use super::{NotNan, OrderedFloat};
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn exhaustive() {
+        // Exhaustively search all patterns of sign and exponent bits plus a few mantissa bits.
+        for high_bytes in 0..=u16::MAX {
+            let [h1, h2] = high_bytes.to_be_bytes();
+
+            // Each of these should not
+            //   * panic,
+            //   * return an error, or
+            //   * need more bytes than given.
+            let n32: NotNan<f32> = Unstructured::new(&[h1, h2, h1, h2])
+                .arbitrary()
+                .expect("NotNan<f32> failure");
+            let n64: NotNan<f64> = Unstructured::new(&[h1, h2, h1, h2, h1, h2, h1, h2])
+                .arbitrary()
+                .expect("NotNan<f64> failure");
+            let _: OrderedFloat<f32> = Unstructured::new(&[h1, h2, h1, h2])
+                .arbitrary()
+                .expect("OrderedFloat<f32> failure");
+            let _: OrderedFloat<f64> = Unstructured::new(&[h1, h2, h1, h2, h1, h2, h1, h2])
+                .arbitrary()
+                .expect("OrderedFloat<f64> failure");
+
+            // Check for violation of NotNan's property of never containing a NaN.
+            assert!(!n32.into_inner().is_nan());
+            assert!(!n64.into_inner().is_nan());
+        }
+    }
+
+    #[test]
+    fn size_hints() {
+        assert_eq!(NotNan::<f32>::size_hint(0), (4, Some(4)));
+        assert_eq!(NotNan::<f64>::size_hint(0), (8, Some(8)));
+        assert_eq!(OrderedFloat::<f32>::size_hint(0), (4, Some(4)));
+        assert_eq!(OrderedFloat::<f64>::size_hint(0), (8, Some(8)));
+    }
+}
+
+#[cfg(feature = "derive")]
+mod derive_test {
+    use super::*;
+
+    #[derive(OrdFloat, Debug, Clone, Copy)]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(OrdFloat, Debug)]
+    struct Labeled<L> {
+        label: L,
+        weight: f32,
+    }
+
+    #[derive(OrdFloat, Debug)]
+    struct Pair(f32, u8);
+
+    #[derive(OrdFloat, Debug)]
+    struct Unit;
+
+    fn hash<T: Hash>(x: &T) -> u64 {
+        let mut s = DefaultHasher::new();
+        x.hash(&mut s);
+        s.finish()
+    }
+
+    #[test]
+    fn test_sort_points() {
+        let mut points = [
+            Point { x: 2.0, y: 0.0 },
+            Point {
+                x: 1.0,
+                y: f64::NAN,
+            },
+            Point {
+                x: f64::NAN,
+                y: 0.0,
+            },
+            Point { x: 1.0, y: -1.0 },
+            Point { x: -0.0, y: 5.0 },
+        ]
+        .to_vec();
+        points.sort();
+        let xs: Vec<_> = points.iter().map(|p| OrderedFloat(p.x)).collect();
+        let ys: Vec<_> = points.iter().map(|p| OrderedFloat(p.y)).collect();
+        assert_eq!(
+            xs,
+            [
+                OrderedFloat(0.0),
+                OrderedFloat(1.0),
+                OrderedFloat(1.0),
+                OrderedFloat(2.0),
+                OrderedFloat(f64::NAN)
+            ]
+        );
+        assert_eq!(ys[1], OrderedFloat(-1.0));
+        assert_eq!(ys[2], OrderedFloat(f64::NAN));
+    }
+
+    #[test]
+    fn test_eq_and_hash() {
+        let a = Point {
+            x: f64::NAN,
+            y: -0.0,
+        };
+        let b = Point {
+            x: -f64::NAN,
+            y: 0.0,
+        };
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+        assert_ne!(a, Point { x: 1.0, y: 0.0 });
+
+        let mut set = HashSet::new();
+        set.insert(a);
+        assert!(set.contains(&b));
+    }
+
+    #[test]
+    fn test_field_order_and_generics() {
+        let a = Labeled {
+            label: "a",
+            weight: 2.0,
+        };
+        let b = Labeled {
+            label: "b",
+            weight: 1.0,
+        };
+        assert!(a < b);
+        assert_eq!(
+            a.cmp(&Labeled {
+                label: "a",
+                weight: f32::NAN
+            }),
+            Less
+        );
+
+        assert!(Pair(1.0, 9) < Pair(f32::NAN, 0));
+        assert!(Pair(1.0, 0) < Pair(1.0, 1));
+        assert_eq!(Unit.cmp(&Unit), Equal);
+        assert_eq!(hash(&Unit), hash(&Unit));
     }
 }